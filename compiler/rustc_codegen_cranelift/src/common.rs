@@ -35,7 +35,17 @@ pub(crate) fn scalar_to_clif_type(tcx: TyCtxt<'_>, scalar: Scalar) -> Type {
         },
         Primitive::F32 => types::F32,
         Primitive::F64 => types::F64,
-        Primitive::Pointer => pointer_ty(tcx),
+        // Cranelift has no capability types, so hybrid code using `#[cheri_capability]` types
+        // can't be compiled either. Purecap targets are already rejected in `init`.
+        Primitive::Pointer(address_space)
+            if tcx.data_layout.ptr_layout(Some(address_space)).is_fat_ty =>
+        {
+            tcx.sess.fatal(
+                "CHERI capability types are not supported by the Cranelift backend; \
+                 use the LLVM backend instead",
+            )
+        }
+        Primitive::Pointer(_) => pointer_ty(tcx),
     }
 }
 
@@ -817,6 +817,42 @@ fn swap(bcx: &mut FunctionBuilder<'_>, v: Value) -> Value {
             ret.write_cvalue(fx, CValue::by_val(val, fx.layout_of(fx.tcx.types.u8)));
         }
 
+        sym::ptr_addr => {
+            intrinsic_args!(fx, args => (ptr); intrinsic);
+
+            let addr = ptr.load_scalar(fx);
+            ret.write_cvalue(fx, CValue::by_val(addr, fx.layout_of(fx.tcx.types.usize)));
+        }
+
+        sym::ptr_with_addr => {
+            intrinsic_args!(fx, args => (ptr, addr); intrinsic);
+
+            let addr = addr.load_scalar(fx);
+            ret.write_cvalue(fx, CValue::by_val(addr, ptr.layout()));
+        }
+
+        sym::ptr_eq_exact => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+
+            let val = crate::num::codegen_ptr_binop(fx, BinOp::Eq, a, b);
+            ret.write_cvalue(fx, val);
+        }
+
+        // Capabilities are not supported, so pointers are unbounded and every length is
+        // representable.
+        sym::cheri_cap_length_get | sym::cheri_representable_alignment_mask => {
+            intrinsic_args!(fx, args => (_arg); intrinsic);
+
+            let val = fx.bcx.ins().iconst(fx.pointer_type, -1);
+            ret.write_cvalue(fx, CValue::by_val(val, fx.layout_of(fx.tcx.types.usize)));
+        }
+
+        sym::cheri_representable_length => {
+            intrinsic_args!(fx, args => (len); intrinsic);
+
+            ret.write_cvalue(fx, len);
+        }
+
         sym::caller_location => {
             intrinsic_args!(fx, args => (); intrinsic);
 
@@ -172,6 +172,16 @@ pub struct CraneliftCodegenBackend {
 impl CodegenBackend for CraneliftCodegenBackend {
     fn init(&self, sess: &Session) {
         use rustc_session::config::Lto;
+        // Cranelift has no capability types, so it can't represent a single pointer on these.
+        // Capability pointer types, address spaces and the CHERI operations are not implemented
+        // in this backend; rejecting these targets is the whole of its CHERI support.
+        if sess.target.pointer_type_width.is_some() {
+            sess.fatal(&format!(
+                "CHERI purecap targets like `{}` are not supported by the Cranelift backend, \
+                 as Cranelift has no capability types; use the LLVM backend instead",
+                sess.opts.target_triple,
+            ));
+        }
         match sess.lto() {
             Lto::No | Lto::ThinLocal => {}
             Lto::Thin | Lto::Fat => sess.warn("LTO is not supported. You may get a linker error."),
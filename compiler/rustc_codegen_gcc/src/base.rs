@@ -76,16 +76,24 @@ fn module_codegen(tcx: TyCtxt<'_>, (cgu_name, supports_128bit_integers): (Symbol
         // Instantiate monomorphizations without filling out definitions yet...
         //let llvm_module = ModuleLlvm::new(tcx, &cgu_name.as_str());
         let context = Context::default();
-        // TODO(antoyo): only set on x86 platforms.
-        context.add_command_line_option("-masm=intel");
-        // TODO(antoyo): only add the following cli argument if the feature is supported.
-        context.add_command_line_option("-msse2");
-        context.add_command_line_option("-mavx2");
-        context.add_command_line_option("-msha");
-        context.add_command_line_option("-mpclmul");
-        // FIXME(antoyo): the following causes an illegal instruction on vmovdqu64 in std_example on my CPU.
-        // Only add if the CPU supports it.
-        //context.add_command_line_option("-mavx512f");
+        if tcx.sess.target.arch == "morello+c64" {
+            // NOTE: like clang, GCC for Morello only makes pointers capabilities with these, and
+            // the layout of every pointer type depends on it.
+            context.add_command_line_option("-march=morello+c64");
+            context.add_command_line_option("-mabi=purecap");
+        }
+        else {
+            // TODO(antoyo): only set on x86 platforms.
+            context.add_command_line_option("-masm=intel");
+            // TODO(antoyo): only add the following cli argument if the feature is supported.
+            context.add_command_line_option("-msse2");
+            context.add_command_line_option("-mavx2");
+            context.add_command_line_option("-msha");
+            context.add_command_line_option("-mpclmul");
+            // FIXME(antoyo): the following causes an illegal instruction on vmovdqu64 in std_example on my CPU.
+            // Only add if the CPU supports it.
+            //context.add_command_line_option("-mavx512f");
+        }
         for arg in &tcx.sess.opts.cg.llvm_args {
             context.add_command_line_option(arg);
         }
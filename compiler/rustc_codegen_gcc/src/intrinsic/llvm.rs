@@ -239,6 +239,21 @@ pub fn intrinsic<'gcc, 'tcx>(name: &str, cx: &CodegenCx<'gcc, 'tcx>) -> Function
         "llvm.x86.avx2.gather.q.q.256" => "__builtin_ia32_gatherdiv4di",
         "llvm.x86.avx2.gather.q.pd" => "__builtin_ia32_gatherdiv2df",
         "llvm.x86.avx2.gather.q.pd.256" => "__builtin_ia32_gatherdiv4df",
+        // Used by `core::arch::aarch64::cheri` on Morello. GCC for Morello provides the same
+        // CHERI builtins as clang.
+        "llvm.cheri.cap.base.get.i64" => "__builtin_cheri_base_get",
+        "llvm.cheri.cap.offset.get.i64" => "__builtin_cheri_offset_get",
+        "llvm.cheri.cap.perms.get.i64" => "__builtin_cheri_perms_get",
+        "llvm.cheri.cap.perms.and.i64" => "__builtin_cheri_perms_and",
+        "llvm.cheri.cap.type.get.i64" => "__builtin_cheri_type_get",
+        "llvm.cheri.cap.tag.get" => "__builtin_cheri_tag_get",
+        "llvm.cheri.cap.tag.clear" => "__builtin_cheri_tag_clear",
+        "llvm.cheri.cap.sealed.get" => "__builtin_cheri_sealed_get",
+        "llvm.cheri.cap.bounds.set.i64" => "__builtin_cheri_bounds_set",
+        "llvm.cheri.cap.bounds.set.exact.i64" => "__builtin_cheri_bounds_set_exact",
+        "llvm.cheri.cap.seal" => "__builtin_cheri_seal",
+        "llvm.cheri.cap.unseal" => "__builtin_cheri_unseal",
+        "llvm.cheri.cap.seal.entry" => "__builtin_cheri_seal_entry",
         "" => "",
         // NOTE: this file is generated by https://github.com/GuillaumeGomez/llvmint/blob/master/generate_list.py
         _ => include!("archs.rs"),
@@ -302,6 +302,42 @@ fn codegen_intrinsic_call(&mut self, instance: Instance<'tcx>, fn_abi: &FnAbi<'t
                     return;
                 }
 
+                sym::ptr_eq_exact => {
+                    let a = args[0].immediate();
+                    let b = args[1].immediate();
+                    if self.data_layout().is_cheri_purecap {
+                        // Compare the whole capability, not just its address.
+                        let builtin = self.context.get_builtin_function("__builtin_cheri_equal_exact");
+                        let equal = self.context.new_call(None, builtin, &[a, b]);
+                        self.context.new_cast(None, equal, self.bool_type)
+                    }
+                    else {
+                        self.icmp(IntPredicate::IntEQ, a, b)
+                    }
+                },
+
+                // With a GCC built for a purecap target, these map onto the CHERI builtins it
+                // shares with clang.
+                sym::cheri_cap_length_get | sym::cheri_representable_length | sym::cheri_representable_alignment_mask
+                    if self.data_layout().is_cheri_purecap =>
+                {
+                    let builtin = match name {
+                        sym::cheri_cap_length_get => "__builtin_cheri_length_get",
+                        sym::cheri_representable_length => "__builtin_cheri_round_representable_length",
+                        _ => "__builtin_cheri_representable_alignment_mask",
+                    };
+                    let builtin = self.context.get_builtin_function(builtin);
+                    let value = self.context.new_call(None, builtin, &[args[0].immediate()]);
+                    self.context.new_cast(None, value, self.usize_type)
+                },
+
+                // Without capabilities pointers are unbounded and every length is representable.
+                sym::cheri_cap_length_get | sym::cheri_representable_alignment_mask => {
+                    self.const_usize(self.data_layout().pointer_size.unsigned_int_max() as u64)
+                },
+
+                sym::cheri_representable_length => args[0].immediate(),
+
                 sym::ptr_mask => {
                     let usize_type = self.context.new_type::<usize>();
                     let void_ptr_type = self.context.new_type::<*const ()>();
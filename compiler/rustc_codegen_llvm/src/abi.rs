@@ -134,6 +134,10 @@ fn llvm_type<'ll>(&self, cx: &CodegenCx<'ll, '_>) -> &'ll Type {
                 _ => bug!("unsupported float: {:?}", self),
             },
             RegKind::Vector => cx.type_vector(cx.type_i8(), self.size.bytes()),
+            RegKind::Capability => match cx.data_layout().capability_address_space() {
+                Some(addr_space) => cx.type_i8p_ext(addr_space),
+                None => bug!("capability register on a target without capabilities"),
+            },
         }
     }
 }
@@ -349,9 +353,10 @@ fn llvm_type(&self, cx: &CodegenCx<'ll, 'tcx>) -> &'ll Type {
             PassMode::Direct(_) | PassMode::Pair(..) => self.ret.layout.immediate_llvm_type(cx),
             PassMode::Cast(cast, _) => cast.llvm_type(cx),
             PassMode::Indirect { .. } => {
-                // TODO: Get the correct address space. Probably alloca.
+                // The return slot is usually in the caller's stack frame, so the hidden `sret`
+                // argument uses the alloca address space, which is a capability on purecap.
                 llargument_tys
-                    .push(cx.type_ptr_to_ext(self.ret.memory_ty(cx), dl.default_address_space));
+                    .push(cx.type_ptr_to_ext(self.ret.memory_ty(cx), dl.alloca_address_space));
                 cx.type_void()
             }
         };
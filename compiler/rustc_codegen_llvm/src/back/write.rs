@@ -349,15 +349,19 @@ fn report_inline_asm(
         }
 
         llvm::diagnostic::Optimization(opt) => {
-            let enabled = match cgcx.remark {
-                Passes::All => true,
-                Passes::Some(ref v) => v.iter().any(|s| *s == opt.pass_name),
-            };
-
-            if enabled {
+            if cgcx.remark.contains(&opt.pass_name) {
+                // Remarks about compiler-generated code without a debug location, like the copies
+                // the CHERI passes turn into integer copies, can only say which function they're
+                // in.
+                let location = if opt.line == 0 {
+                    let function = llvm::get_value_name(opt.function);
+                    format!("in `{}`", String::from_utf8_lossy(function))
+                } else {
+                    format!("{}:{}:{}", opt.filename, opt.line, opt.column)
+                };
                 diag_handler.note_without_error(&format!(
-                    "{}:{}:{}: {}: {}",
-                    opt.filename, opt.line, opt.column, opt.pass_name, opt.message,
+                    "{}: {}: {}",
+                    location, opt.pass_name, opt.message,
                 ));
             }
         }
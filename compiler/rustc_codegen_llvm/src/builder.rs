@@ -20,7 +20,7 @@
 };
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::Span;
-use rustc_target::abi::{self, call::FnAbi, Align, Size, WrappingRange};
+use rustc_target::abi::{self, call::FnAbi, AddressSpace, Align, Size, WrappingRange};
 use rustc_target::spec::{HasTargetSpec, Target};
 use std::borrow::Cow;
 use std::ffi::CStr;
@@ -493,7 +493,7 @@ fn scalar_load_metadata<'a, 'll, 'tcx>(
                         bx.range_metadata(load, scalar.valid_range(bx));
                     }
                 }
-                abi::Pointer => {
+                abi::Pointer(_) => {
                     if !scalar.valid_range(bx).contains(0) {
                         bx.nonnull_metadata(load);
                     }
@@ -827,6 +827,9 @@ fn intcast(&mut self, val: &'ll Value, dest_ty: &'ll Type, is_signed: bool) -> &
     }
 
     fn pointercast(&mut self, val: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
+        if self.changes_address_space(val, dest_ty) {
+            return self.addrspacecast(val, dest_ty);
+        }
         unsafe { llvm::LLVMBuildPointerCast(self.llbuilder, val, dest_ty, UNNAMED) }
     }
 
@@ -847,9 +850,8 @@ fn get_pointer_address(&mut self, ptr: &'ll Value) -> &'ll Value {
 
         assert_eq!(self.cx.type_kind(ptr_ty), TypeKind::Pointer);
 
-        // Check if we are dealing with a CHERI fat pointer or not. TODO: We can do better than
-        // this.
-        if self.cx.address_space(ptr_ty).0 != 200 {
+        // Only pointers in the capability address space are CHERI capabilities.
+        if Some(self.cx.address_space(ptr_ty)) != self.tcx.data_layout.capability_address_space() {
             return self.ptrtoint(ptr, self.type_isize());
         }
 
@@ -861,10 +863,16 @@ fn set_pointer_address(&mut self, ptr: &'ll Value, addr: &'ll Value) -> &'ll Val
 
         assert_eq!(self.cx.type_kind(ptr_ty), TypeKind::Pointer);
 
-        // Check if we are dealing with a CHERI fat pointer or not. TODO: We can do better than
-        // this.
-        if self.cx.address_space(ptr_ty).0 != 200 {
-            return self.inttoptr(addr, ptr_ty);
+        // Only pointers in the capability address space are CHERI capabilities.
+        if Some(self.cx.address_space(ptr_ty)) != self.tcx.data_layout.capability_address_space() {
+            // Offset the pointer by the difference between the addresses, like `wrapping_offset`,
+            // rather than casting the address back to a pointer, which would lose its provenance.
+            let i8p = self.type_i8p_ext(self.cx.address_space(ptr_ty));
+            let old_addr = self.ptrtoint(ptr, self.type_isize());
+            let diff = self.sub(addr, old_addr);
+            let ptr = self.pointercast(ptr, i8p);
+            let ptr = self.gep(self.type_i8(), ptr, &[diff]);
+            return self.pointercast(ptr, ptr_ty);
         }
 
         // TODO: Check if we are setting the address of a null capability. If so it's not going to
@@ -873,6 +881,27 @@ fn set_pointer_address(&mut self, ptr: &'ll Value, addr: &'ll Value) -> &'ll Val
         self.call_intrinsic("llvm.cheri.cap.address.set", &[ptr, addr])
     }
 
+    fn seal_vtable_pointer(&mut self, vtable: &'ll Value) -> &'ll Value {
+        let key = self.load_cheri_vtable_seal_key();
+        let sealed = self.call_intrinsic("llvm.cheri.cap.seal", &[vtable, key]);
+        self.pointercast(sealed, self.val_ty(vtable))
+    }
+
+    fn unseal_vtable_pointer(&mut self, vtable: &'ll Value) -> &'ll Value {
+        // Unsealing a pointer that isn't sealed with the key clears its tag, so a forged vtable
+        // pointer traps when the vtable is read.
+        let key = self.load_cheri_vtable_seal_key();
+        let unsealed = self.call_intrinsic("llvm.cheri.cap.unseal", &[vtable, key]);
+        self.pointercast(unsealed, self.val_ty(vtable))
+    }
+
+    fn narrow_pointer_bounds(&mut self, ptr: &'ll Value, size: &'ll Value) -> &'ll Value {
+        let isize_bits = self.tcx.data_layout.ptr_layout(None).val_size.bits();
+        let bounded =
+            self.call_intrinsic(&format!("llvm.cheri.cap.bounds.set.i{isize_bits}"), &[ptr, size]);
+        self.pointercast(bounded, self.val_ty(ptr))
+    }
+
     /* Miscellaneous instructions */
     fn memcpy(
         &mut self,
@@ -884,15 +913,11 @@ fn memcpy(
         flags: MemFlags,
     ) {
         assert!(!flags.contains(MemFlags::NONTEMPORAL), "non-temporal memcpy not supported");
-        let dl = &self.tcx.data_layout;
         let size = self.intcast(size, self.type_isize(), false);
-        // TODO: This should be based on whether the underlying element being copied is a pointer,
-        // not just the alignment.
-        let is_cap_move = dl.is_cheri_purecap && src_align.bytes() == 16;
+        let preserve_tags = self.preserve_cheri_tags(dst_align.min(src_align), flags);
         let is_volatile = flags.contains(MemFlags::VOLATILE);
-        // TODO: Get correct address space. Should come from the original pointers.
-        let dst = self.pointercast(dst, self.type_i8p_ext(dl.default_address_space));
-        let src = self.pointercast(src, self.type_i8p_ext(dl.default_address_space));
+        let dst = self.pointercast(dst, self.type_i8p_ext(self.pointer_address_space(dst)));
+        let src = self.pointercast(src, self.type_i8p_ext(self.pointer_address_space(src)));
         unsafe {
             llvm::LLVMRustBuildMemCpy(
                 self.llbuilder,
@@ -901,7 +926,7 @@ fn memcpy(
                 src,
                 src_align.bytes() as c_uint,
                 size,
-                if is_cap_move { llvm::PreserveCheriTags::Required } else { llvm::PreserveCheriTags::Unnecessary },
+                preserve_tags,
                 is_volatile,
             );
         }
@@ -917,15 +942,11 @@ fn memmove(
         flags: MemFlags,
     ) {
         assert!(!flags.contains(MemFlags::NONTEMPORAL), "non-temporal memmove not supported");
-        let dl = &self.tcx.data_layout;
         let size = self.intcast(size, self.type_isize(), false);
-        // TODO: This should be based on whether the underlying element being copied is a pointer,
-        // not just the alignment.
-        let is_cap_move = dl.is_cheri_purecap && src_align.bytes() == 16;
+        let preserve_tags = self.preserve_cheri_tags(dst_align.min(src_align), flags);
         let is_volatile = flags.contains(MemFlags::VOLATILE);
-        // TODO: Get correct address space. Should come from the original pointers.
-        let dst = self.pointercast(dst, self.type_i8p_ext(dl.default_address_space));
-        let src = self.pointercast(src, self.type_i8p_ext(dl.default_address_space));
+        let dst = self.pointercast(dst, self.type_i8p_ext(self.pointer_address_space(dst)));
+        let src = self.pointercast(src, self.type_i8p_ext(self.pointer_address_space(src)));
         unsafe {
             llvm::LLVMRustBuildMemMove(
                 self.llbuilder,
@@ -934,7 +955,7 @@ fn memmove(
                 src,
                 src_align.bytes() as c_uint,
                 size,
-                if is_cap_move { llvm::PreserveCheriTags::Required } else { llvm::PreserveCheriTags::Unnecessary },
+                preserve_tags,
                 is_volatile,
             );
         }
@@ -949,9 +970,7 @@ fn memset(
         flags: MemFlags,
     ) {
         let is_volatile = flags.contains(MemFlags::VOLATILE);
-        // TODO: Get correct address space. Should come from the original pointer.
-        let ptr =
-            self.pointercast(ptr, self.type_i8p_ext(self.tcx.data_layout.default_address_space));
+        let ptr = self.pointercast(ptr, self.type_i8p_ext(self.pointer_address_space(ptr)));
         unsafe {
             llvm::LLVMRustBuildMemSet(
                 self.llbuilder,
@@ -1103,6 +1122,13 @@ fn atomic_cmpxchg(
                 llvm::False, // SingleThreaded
             );
             llvm::LLVMSetWeak(value, weak);
+            // Capabilities that only share an address are different values, so on purecap
+            // targets the exchange must only happen if the whole capability matches.
+            if self.tcx.data_layout.is_cheri_purecap
+                && self.cx.type_kind(self.val_ty(cmp)) == TypeKind::Pointer
+            {
+                llvm::LLVMRustSetCmpXchgExact(value, true);
+            }
             value
         }
     }
@@ -1113,6 +1139,11 @@ fn atomic_rmw(
         src: &'ll Value,
         order: rustc_codegen_ssa::common::AtomicOrdering,
     ) -> &'ll Value {
+        if self.is_cheri_riscv_wide_int(self.val_ty(src))
+            && !matches!(op, rustc_codegen_ssa::common::AtomicRmwBinOp::AtomicXchg)
+        {
+            return self.cheri_riscv_wide_atomic_rmw(op, dst, src, order);
+        }
         unsafe {
             llvm::LLVMBuildAtomicRMW(
                 self.llbuilder,
@@ -1155,11 +1186,11 @@ fn set_invariant_load(&mut self, load: &'ll Value) {
     }
 
     fn lifetime_start(&mut self, ptr: &'ll Value, size: Size) {
-        self.call_lifetime_intrinsic("llvm.lifetime.start.p0i8", ptr, size);
+        self.call_lifetime_intrinsic("llvm.lifetime.start", ptr, size);
     }
 
     fn lifetime_end(&mut self, ptr: &'ll Value, size: Size) {
-        self.call_lifetime_intrinsic("llvm.lifetime.end.p0i8", ptr, size);
+        self.call_lifetime_intrinsic("llvm.lifetime.end", ptr, size);
     }
 
     fn instrprof_increment(
@@ -1174,11 +1205,19 @@ fn instrprof_increment(
             fn_name, hash, num_counters, index
         );
 
+        // The function name variable is a global, so on CHERI targets it lives in the capability
+        // address space and has to go through the matching overload of the intrinsic.
+        let addr_space = self.cx.address_space(self.cx.val_ty(fn_name));
+        if addr_space != AddressSpace::DATA {
+            let name = format!("llvm.instrprof.increment.p{}i8", addr_space.0);
+            self.call_intrinsic(&name, &[fn_name, hash, num_counters, index]);
+            return;
+        }
+
         let llfn = unsafe { llvm::LLVMRustGetInstrProfIncrementIntrinsic(self.cx().llmod) };
-        // TODO: Get the correct address space.
         let llty = self.cx.type_func(
             &[
-                self.cx.type_i8p_ext(self.tcx.data_layout.default_address_space),
+                self.cx.type_i8p_ext(AddressSpace::DATA),
                 self.cx.type_i64(),
                 self.cx.type_i32(),
                 self.cx.type_i32(),
@@ -1258,6 +1297,11 @@ pub fn llfn(&self) -> &'ll Value {
         unsafe { llvm::LLVMGetBasicBlockParent(self.llbb()) }
     }
 
+    /// Returns the address space of the pointer `ptr`.
+    pub(crate) fn pointer_address_space(&self, ptr: &'ll Value) -> AddressSpace {
+        self.cx.address_space(self.val_ty(ptr))
+    }
+
     fn position_at_start(&mut self, llbb: &'ll BasicBlock) {
         unsafe {
             llvm::LLVMRustPositionBuilderAtStart(self.llbuilder, llbb);
@@ -1286,6 +1330,33 @@ fn noundef_metadata(&mut self, load: &'ll Value) {
         }
     }
 
+    /// Decides whether a memory transfer with the given alignment must keep CHERI tags.
+    ///
+    /// On purecap targets capability-aligned transfers may hold capabilities, so they must
+    /// preserve tags. Transfers flagged with `PRESERVE_CHERI_TAGS` (values that hold capabilities
+    /// and the copy intrinsics) must preserve them on hybrid targets too. Smaller alignments
+    /// cannot hold a capability at a statically known offset, but the copy intrinsics may still be
+    /// relocating capabilities, so for those LLVM is left to preserve tags wherever the runtime
+    /// alignment allows it.
+    fn preserve_cheri_tags(&self, align: Align, flags: MemFlags) -> llvm::PreserveCheriTags {
+        let dl = &self.tcx.data_layout;
+        let Some(address_space) = dl.capability_address_space() else {
+            return llvm::PreserveCheriTags::Unnecessary;
+        };
+        let capability_aligned = align >= dl.ptr_layout(Some(address_space)).align.abi;
+        if flags.contains(MemFlags::PRESERVE_CHERI_TAGS) {
+            if capability_aligned {
+                llvm::PreserveCheriTags::Required
+            } else {
+                llvm::PreserveCheriTags::Unknown
+            }
+        } else if dl.is_cheri_purecap && capability_aligned {
+            llvm::PreserveCheriTags::Required
+        } else {
+            llvm::PreserveCheriTags::Unnecessary
+        }
+    }
+
     pub fn minnum(&mut self, lhs: &'ll Value, rhs: &'ll Value) -> &'ll Value {
         unsafe { llvm::LLVMRustBuildMinNum(self.llbuilder, lhs, rhs) }
     }
@@ -1395,12 +1466,10 @@ pub fn catch_ret(&mut self, funclet: &Funclet<'ll>, unwind: &'ll BasicBlock) ->
     fn check_store(&mut self, val: &'ll Value, ptr: &'ll Value) -> &'ll Value {
         let dest_ptr_ty = self.cx.val_ty(ptr);
         let stored_ty = self.cx.val_ty(val);
-        // TODO: Get correct address space from dest_ptr_ty.
-        let stored_ptr_ty =
-            self.cx.type_ptr_to_ext(stored_ty, self.tcx.data_layout.default_address_space);
-
         assert_eq!(self.cx.type_kind(dest_ptr_ty), TypeKind::Pointer);
 
+        let stored_ptr_ty = self.cx.type_ptr_to_ext(stored_ty, self.cx.address_space(dest_ptr_ty));
+
         if dest_ptr_ty == stored_ptr_ty {
             ptr
         } else {
@@ -1446,7 +1515,7 @@ fn check_call<'b>(
                             Expected {:?} for param {}, got {:?}; injecting bitcast",
                         llfn, expected_ty, i, actual_ty
                     );
-                    self.bitcast(actual_val, expected_ty)
+                    self.bitcast_or_addrspacecast(actual_val, expected_ty)
                 } else {
                     actual_val
                 }
@@ -1456,6 +1525,38 @@ fn check_call<'b>(
         Cow::Owned(casted_args)
     }
 
+    pub fn addrspacecast(&mut self, val: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
+        unsafe { llvm::LLVMBuildAddrSpaceCast(self.llbuilder, val, dest_ty, UNNAMED) }
+    }
+
+    fn load_cheri_vtable_seal_key(&mut self) -> &'ll Value {
+        let dl = &self.tcx.data_layout;
+        let key_ty = self.type_i8p_ext(dl.globals_address_space);
+        let align = dl.ptr_layout(None).align.abi;
+        self.load(key_ty, self.cx.cheri_vtable_seal_key(), align)
+    }
+
+    /// Casts `val` to `dest_ty`, using an `addrspacecast` rather than a `bitcast` when both are
+    /// pointers in different address spaces. In CHERI hybrid code this is how a plain pointer
+    /// becomes a capability (derived from DDC) and vice versa, e.g. when the same foreign
+    /// function is declared once with `*mut T` and once with a `#[cheri_capability]` parameter.
+    fn bitcast_or_addrspacecast(&mut self, val: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
+        if self.changes_address_space(val, dest_ty) {
+            self.addrspacecast(val, dest_ty)
+        } else {
+            self.bitcast(val, dest_ty)
+        }
+    }
+
+    /// Returns whether `val` and `dest_ty` are pointers in different address spaces, which a
+    /// `bitcast` can't cast between.
+    fn changes_address_space(&self, val: &'ll Value, dest_ty: &'ll Type) -> bool {
+        let src_ty = self.val_ty(val);
+        self.cx.type_kind(src_ty) == TypeKind::Pointer
+            && self.cx.type_kind(dest_ty) == TypeKind::Pointer
+            && self.cx.address_space(src_ty) != self.cx.address_space(dest_ty)
+    }
+
     pub fn va_arg(&mut self, list: &'ll Value, ty: &'ll Type) -> &'ll Value {
         unsafe { llvm::LLVMBuildVAArg(self.llbuilder, list, ty, UNNAMED) }
     }
@@ -1475,10 +1576,83 @@ fn call_lifetime_intrinsic(&mut self, intrinsic: &str, ptr: &'ll Value, size: Si
             return;
         }
 
-        // TODO: Get the correct address space. Should get it from the original pointer.
-        let ptr =
-            self.pointercast(ptr, self.cx.type_i8p_ext(self.tcx.data_layout.default_address_space));
-        self.call_intrinsic(intrinsic, &[self.cx.const_u64(size), ptr]);
+        // The lifetime intrinsics are overloaded on the address space of the pointer.
+        let address_space = self.pointer_address_space(ptr);
+        let ptr = self.pointercast(ptr, self.cx.type_i8p_ext(address_space));
+        let intrinsic = format!("{intrinsic}.p{}i8", address_space.0);
+        self.call_intrinsic(&intrinsic, &[self.cx.const_u64(size), ptr]);
+    }
+
+    /// Whether `ty` is an integer as wide as a capability but wider than a general-purpose register
+    /// on CHERI-RISC-V. Atomics on these can only be done with the capability-width `lr.c` and
+    /// `sc.c`, which the backend uses for loads, stores, exchanges and compare-exchanges.
+    fn is_cheri_riscv_wide_int(&self, ty: &'ll Type) -> bool {
+        let target = &self.sess().target;
+        target.arch.starts_with("riscv")
+            && self.cx.type_kind(ty) == TypeKind::Integer
+            && target.pointer_type_width.map_or(false, |width| {
+                self.cx.int_width(ty) == u64::from(width) && width > target.pointer_width
+            })
+    }
+
+    /// Expands an atomic read-modify-write of a capability-width integer on CHERI-RISC-V into a
+    /// compare-exchange loop, which the backend lowers to `lr.c`/`sc.c`. Returns the old value.
+    fn cheri_riscv_wide_atomic_rmw(
+        &mut self,
+        op: rustc_codegen_ssa::common::AtomicRmwBinOp,
+        dst: &'ll Value,
+        src: &'ll Value,
+        order: rustc_codegen_ssa::common::AtomicOrdering,
+    ) -> &'ll Value {
+        use rustc_codegen_ssa::common::AtomicOrdering::*;
+        use rustc_codegen_ssa::common::AtomicRmwBinOp::*;
+
+        let ty = self.val_ty(src);
+        let size = Size::from_bits(self.cx.int_width(ty));
+        let failure_order = match order {
+            Release | Relaxed | Unordered => Relaxed,
+            Acquire | AcquireRelease => Acquire,
+            SequentiallyConsistent => SequentiallyConsistent,
+        };
+
+        let entry = self.llbb();
+        let init = self.atomic_load(ty, dst, Relaxed, size);
+        let loop_bb = self.append_sibling_block("atomicrmw.loop");
+        let done_bb = self.append_sibling_block("atomicrmw.done");
+        self.br(loop_bb);
+
+        self.switch_to_block(loop_bb);
+        let old = self.phi(ty, &[init], &[entry]);
+        let new = match op {
+            AtomicXchg => src,
+            AtomicAdd => self.add(old, src),
+            AtomicSub => self.sub(old, src),
+            AtomicAnd => self.and(old, src),
+            AtomicNand => {
+                let and = self.and(old, src);
+                self.not(and)
+            }
+            AtomicOr => self.or(old, src),
+            AtomicXor => self.xor(old, src),
+            AtomicMax | AtomicMin | AtomicUMax | AtomicUMin => {
+                let pred = match op {
+                    AtomicMax => IntPredicate::IntSGT,
+                    AtomicMin => IntPredicate::IntSLT,
+                    AtomicUMax => IntPredicate::IntUGT,
+                    _ => IntPredicate::IntULT,
+                };
+                let keep_old = self.icmp(pred, old, src);
+                self.select(keep_old, old, src)
+            }
+        };
+        let pair = self.atomic_cmpxchg(dst, old, new, order, failure_order, false);
+        let current = self.extract_value(pair, 0);
+        let success = self.extract_value(pair, 1);
+        self.add_incoming_to_phi(old, current, loop_bb);
+        self.cond_br(success, done_bb, loop_bb);
+
+        self.switch_to_block(done_bb);
+        old
     }
 
     pub(crate) fn phi(
@@ -165,7 +165,6 @@ fn const_u64(&self, i: u64) -> &'ll Value {
     }
 
     fn const_usize(&self, i: u64) -> &'ll Value {
-        // TODO: More complexity needed here.
         let bit_size = self.data_layout().ptr_layout(None).val_size.bits();
         if bit_size < 64 {
             // make sure it doesn't overflow
@@ -240,7 +239,7 @@ fn scalar_to_backend(&self, cv: Scalar, layout: abi::Scalar, llty: &'ll Type) ->
             Scalar::Int(int) => {
                 let data = int.assert_bits(layout.ty_size(self));
                 let llval = self.const_uint_big(self.type_ix(bitsize), data);
-                if layout.primitive() == Pointer {
+                if matches!(layout.primitive(), Pointer(_)) {
                     unsafe { llvm::LLVMConstIntToPtr(llval, llty) }
                 } else {
                     self.const_bitcast(llval, llty)
@@ -280,15 +279,24 @@ fn scalar_to_backend(&self, cv: Scalar, layout: abi::Scalar, llty: &'ll Type) ->
                         (self.get_static(def_id), self.data_layout().globals_address_space)
                     }
                 };
-                let llval = unsafe {
-                    llvm::LLVMRustConstInBoundsGEP2(
-                        self.type_i8(),
-                        self.const_bitcast(base_addr, self.type_i8p_ext(base_addr_space)),
-                        &self.const_usize(offset.bytes()),
-                        1,
-                    )
+                let base_addr = self.const_bitcast(base_addr, self.type_i8p_ext(base_addr_space));
+                // Pointers to the start of an allocation use its symbol directly, so that the
+                // capability relocation for a function pointer in a static names the function.
+                // The linker then marks it as a function, which the runtime seals as a sentry on
+                // targets that do so.
+                let llval = if offset.bytes() == 0 {
+                    base_addr
+                } else {
+                    unsafe {
+                        llvm::LLVMRustConstInBoundsGEP2(
+                            self.type_i8(),
+                            base_addr,
+                            &self.const_usize(offset.bytes()),
+                            1,
+                        )
+                    }
                 };
-                if layout.primitive() != Pointer {
+                if !matches!(layout.primitive(), Pointer(_)) {
                     unsafe { llvm::LLVMConstPtrToInt(llval, llty) }
                 } else {
                     self.const_bitcast(llval, llty)
@@ -27,7 +27,10 @@ pub fn const_alloc_to_llvm<'ll>(cx: &CodegenCx<'ll, '_>, alloc: ConstAllocation<
     let mut llvals = Vec::with_capacity(alloc.provenance().len() + 1);
     let dl = cx.data_layout();
 
-    // TODO: More complexity needed here. val_size vs ty_size.
+    // Pointers take up their full type size in memory, which on CHERI purecap targets is that of
+    // a capability. The interpreter stores the offset of the pointer in all of those bytes, and
+    // each pointer becomes its own LLVM constant, so that every function pointer, vtable pointer
+    // and reference in the allocation gets its own capability relocation.
     let pointer_size = dl.ptr_layout(None).ty_size.bytes() as usize;
 
     // Note: this function may call `inspect_with_uninit_and_ptr_outside_interpreter`, so `range`
@@ -111,8 +114,7 @@ fn append_chunks_of_init_and_uninit_bytes<'ll, 'a, 'b>(
                 &cx.tcx,
             ),
             Scalar::Initialized {
-                value: Primitive::Pointer,
-                // TODO: More complexity needed here.
+                value: Primitive::Pointer(dl.default_address_space),
                 valid_range: WrappingRange::full(dl.ptr_layout(None).val_size),
             },
             cx.type_i8p_ext(address_space),
@@ -271,7 +273,7 @@ pub(crate) fn get_static(&self, def_id: DefId) -> &'ll Value {
         let g = if def_id.is_local() && !self.tcx.is_foreign_item(def_id) {
             let llty = self.layout_of(ty).llvm_type(self);
             if let Some(g) = self.get_declared_value(sym) {
-                // TODO: Pretty sure this should be global.
+                // Statics are declared in the globals address space.
                 if self.val_ty(g)
                     != self.type_ptr_to_ext(llty, self.tcx.data_layout.globals_address_space)
                 {
@@ -388,6 +390,19 @@ fn codegen_static(&self, def_id: DefId, is_mutable: bool) {
             };
             let alloc = alloc.inner();
 
+            // The vtable pointers of trait objects in statics can't be sealed at compile time, and
+            // unsealing them at dispatch would trap.
+            if self.sess().cheri_sealed_vtables()
+                && alloc.provenance().iter().any(|&(_, alloc_id)| {
+                    matches!(self.tcx.global_alloc(alloc_id), GlobalAlloc::VTable(..))
+                })
+            {
+                self.sess().span_err(
+                    self.tcx.def_span(def_id),
+                    "statics can't hold trait objects with `-Z cheri-sealed-vtables`",
+                );
+            }
+
             let g = self.get_static(def_id);
 
             // boolean SSA values are i1, but they have to be stored in i8 slots,
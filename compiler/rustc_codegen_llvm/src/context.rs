@@ -269,7 +269,10 @@ pub unsafe fn create_module<'ll>(
         }
     }
 
-    if let Some(BranchProtection { bti, pac_ret }) = sess.opts.unstable_opts.branch_protection {
+    // Morello purecap targets reject `-Zbranch-protection` when the session is validated.
+    if let Some(BranchProtection { bti, pac_ret }) = sess.opts.unstable_opts.branch_protection
+        && sess.target.arch != "morello+c64"
+    {
         if sess.target.arch != "aarch64" {
             sess.err("-Zbranch-protection is only supported on aarch64");
         } else {
@@ -328,6 +331,20 @@ pub unsafe fn create_module<'ll>(
         );
     }
 
+    // Record the purecap ABI in the module the way CHERI clang does, so that LTO refuses to link
+    // purecap bitcode with hybrid or non-CHERI bitcode, and so that a linker-plugin LTO backend
+    // that only sees the bitcode still generates purecap code.
+    if sess.target.pointer_type_width.is_some() && !sess.target.llvm_abiname.is_empty() {
+        let abi = &sess.target.llvm_abiname;
+        llvm::LLVMRustAddModuleFlagString(
+            llmod,
+            llvm::LLVMModFlagBehavior::Error,
+            "target-abi\0".as_ptr().cast(),
+            abi.as_ptr().cast(),
+            abi.len(),
+        );
+    }
+
     llmod
 }
 
@@ -416,7 +433,7 @@ pub(crate) fn new(
             None
         };
 
-        // TODO: More complexity needed here.
+        // `isize` is as wide as an address, not as a capability.
         let isize_ty = Type::ix_llcx(llcx, tcx.data_layout.ptr_layout(None).val_size.bits());
 
         CodegenCx {
@@ -968,14 +985,50 @@ macro_rules! mk_struct {
             "llvm.cheri.cap.address.get".to_string(),
             fn(self.type_i8p_ext(AddressSpace(200))) -> t_isize
         );
+        ifn!(
+            "llvm.cheri.cap.equal.exact".to_string(),
+            fn(self.type_i8p_ext(AddressSpace(200)), self.type_i8p_ext(AddressSpace(200))) -> i1
+        );
         ifn!(
             "llvm.cheri.cap.address.set".to_string(),
             fn(self.type_i8p_ext(AddressSpace(200)), t_isize) -> self.type_i8p_ext(AddressSpace(200))
         );
+        // These are overloaded on the integer type, so their names carry its width.
+        let isize_bits = self.data_layout().ptr_layout(None).val_size.bits();
+        ifn!(
+            format!("llvm.cheri.cap.length.get.i{isize_bits}"),
+            fn(self.type_i8p_ext(AddressSpace(200))) -> t_isize
+        );
+        ifn!(format!("llvm.cheri.round.representable.length.i{isize_bits}"), fn(t_isize) -> t_isize);
+        ifn!(
+            format!("llvm.cheri.representable.alignment.mask.i{isize_bits}"),
+            fn(t_isize) -> t_isize
+        );
+        ifn!(
+            format!("llvm.cheri.cap.bounds.set.i{isize_bits}"),
+            fn(self.type_i8p_ext(AddressSpace(200)), t_isize) -> self.type_i8p_ext(AddressSpace(200))
+        );
+        ifn!("llvm.cheri.ddc.get".to_string(), fn() -> self.type_i8p_ext(AddressSpace(200)));
+        ifn!(
+            "llvm.cheri.cap.seal".to_string(),
+            fn(self.type_i8p_ext(AddressSpace(200)), self.type_i8p_ext(AddressSpace(200))) -> self.type_i8p_ext(AddressSpace(200))
+        );
+        ifn!(
+            "llvm.cheri.cap.unseal".to_string(),
+            fn(self.type_i8p_ext(AddressSpace(200)), self.type_i8p_ext(AddressSpace(200))) -> self.type_i8p_ext(AddressSpace(200))
+        );
 
         None
     }
 
+    /// Returns the global holding the capability that seals vtable pointers under
+    /// `-Z cheri-sealed-vtables`. The runtime has to store a capability with the seal and unseal
+    /// permissions in it, whose address is the object type that vtable pointers are sealed with.
+    pub(crate) fn cheri_vtable_seal_key(&self) -> &'ll Value {
+        let ty = self.type_i8p_ext(self.tcx.data_layout.globals_address_space);
+        self.declare_global("__rust_cheri_vtable_seal_key", ty)
+    }
+
     pub(crate) fn eh_catch_typeinfo(&self) -> &'ll Value {
         let dl = &self.tcx.data_layout;
         if let Some(eh_catch_typeinfo) = self.eh_catch_typeinfo.get() {
@@ -40,7 +40,7 @@
 use rustc_span::FileName;
 use rustc_span::{self, FileNameDisplayPreference, SourceFile};
 use rustc_symbol_mangling::typeid_for_trait_ref;
-use rustc_target::abi::{Align, Size};
+use rustc_target::abi::{AddressSpace, Align, Size};
 use smallvec::smallvec;
 
 use libc::{c_char, c_longlong, c_uint};
@@ -153,6 +153,14 @@ fn build_fixed_size_array_di_node<'ll, 'tcx>(
     DINodeCreationResult::new(di_node, false)
 }
 
+/// Returns the DWARF address space of pointers in `addr_space`. Capabilities are described with
+/// their LLVM address space, so that the backend gives them capability-sized location
+/// descriptions and debuggers read them as capabilities, tag and bounds included. Other pointers
+/// have no DWARF address space.
+fn dwarf_address_space(cx: &CodegenCx<'_, '_>, addr_space: AddressSpace) -> c_uint {
+    if cx.tcx.data_layout.ptr_layout(Some(addr_space)).is_fat_ty { addr_space.0 } else { 0 }
+}
+
 /// Creates debuginfo for built-in pointer-like things:
 ///
 ///  - ty::Ref
@@ -193,13 +201,26 @@ fn build_pointer_or_reference_di_node<'ll, 'tcx>(
                 pointee_type,
             );
 
+            if cx.sess().opts.unstable_opts.cheri_debuginfo_members
+                && cx.sess().target.arch == "morello+c64"
+                && cx.sess().target.pointer_type_width.is_some()
+            {
+                return build_morello_capability_di_node(
+                    cx,
+                    unique_type_id,
+                    &ptr_type_debuginfo_name,
+                    pointee_type_di_node,
+                    (thin_pointer_size, thin_pointer_align),
+                );
+            }
+
             let di_node = unsafe {
                 llvm::LLVMRustDIBuilderCreatePointerType(
                     DIB(cx),
                     pointee_type_di_node,
                     thin_pointer_size.bits(),
                     thin_pointer_align.bits() as u32,
-                    0, // Ignore DWARF address space.
+                    dwarf_address_space(cx, cx.tcx.data_layout.default_address_space),
                     ptr_type_debuginfo_name.as_ptr().cast(),
                     ptr_type_debuginfo_name.len(),
                 )
@@ -250,7 +271,7 @@ fn build_pointer_or_reference_di_node<'ll, 'tcx>(
                             pointee_type_di_node,
                             addr_field.ty_size.bits(),
                             addr_field.align.abi.bits() as u32,
-                            0, // Ignore DWARF address space.
+                            dwarf_address_space(cx, cx.tcx.data_layout.default_address_space),
                             std::ptr::null(),
                             0,
                         )
@@ -283,6 +304,79 @@ fn build_pointer_or_reference_di_node<'ll, 'tcx>(
     }
 }
 
+/// Describes a thin pointer on Morello purecap as a struct wrapping the pointer itself, with
+/// synthetic members for the fields of the capability's metadata that are stored uncompressed:
+/// its permissions, flags and object type. This is what `-Z cheri-debuginfo-members` asks for.
+///
+/// The bounds are compressed relative to the address, so they can't be described as plain
+/// members, and the tag isn't stored in memory at all; debuggers have to decode those themselves.
+fn build_morello_capability_di_node<'ll, 'tcx>(
+    cx: &CodegenCx<'ll, 'tcx>,
+    unique_type_id: UniqueTypeId<'tcx>,
+    name: &str,
+    pointee_type_di_node: &'ll DIType,
+    size_and_align: (Size, Align),
+) -> DINodeCreationResult<'ll> {
+    // (name, offset in bits, size in bits) of each field in the Morello capability format. The
+    // flags share the top byte of the address.
+    const FIELDS: [(&str, u64, u64); 3] = [("flags", 56, 8), ("otype", 95, 15), ("perms", 110, 18)];
+
+    type_map::build_type_with_children(
+        cx,
+        type_map::stub(
+            cx,
+            Stub::Struct,
+            unique_type_id,
+            name,
+            size_and_align,
+            NO_SCOPE_METADATA,
+            DIFlags::FlagZero,
+        ),
+        |cx, owner| {
+            let pointer_di_node = unsafe {
+                llvm::LLVMRustDIBuilderCreatePointerType(
+                    DIB(cx),
+                    pointee_type_di_node,
+                    size_and_align.0.bits(),
+                    size_and_align.1.bits() as u32,
+                    dwarf_address_space(cx, cx.tcx.data_layout.default_address_space),
+                    ptr::null(),
+                    0,
+                )
+            };
+            let u64_di_node = type_di_node(cx, cx.tcx.types.u64);
+
+            let mut members = smallvec![build_field_di_node(
+                cx,
+                owner,
+                "pointer",
+                size_and_align,
+                Size::ZERO,
+                DIFlags::FlagZero,
+                pointer_di_node,
+            )];
+            members.extend(FIELDS.iter().map(|&(field_name, offset, size)| unsafe {
+                llvm::LLVMRustDIBuilderCreateBitFieldMemberType(
+                    DIB(cx),
+                    owner,
+                    field_name.as_ptr().cast(),
+                    field_name.len(),
+                    unknown_file_metadata(cx),
+                    UNKNOWN_LINE_NUMBER,
+                    size,
+                    offset,
+                    // The storage unit is the 64-bit half of the capability the field is in.
+                    offset / 64 * 64,
+                    DIFlags::FlagArtificial,
+                    u64_di_node,
+                )
+            }));
+            members
+        },
+        NO_GENERICS,
+    )
+}
+
 fn build_subroutine_type_di_node<'ll, 'tcx>(
     cx: &CodegenCx<'ll, 'tcx>,
     unique_type_id: UniqueTypeId<'tcx>,
@@ -344,10 +438,9 @@ fn build_subroutine_type_di_node<'ll, 'tcx>(
         llvm::LLVMRustDIBuilderCreatePointerType(
             DIB(cx),
             fn_di_node,
-            // TODO: Double check this is correct. val_size vs ty_size.
             dl.ptr_layout(Some(dl.instruction_address_space)).ty_size.bits(),
             dl.ptr_layout(Some(dl.instruction_address_space)).align.abi.bits() as u32,
-            0, // Ignore DWARF address space.
+            dwarf_address_space(cx, dl.instruction_address_space),
             name.as_ptr().cast(),
             name.len(),
         )
@@ -1405,11 +1498,10 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
     let void_pointer_type_di_node = type_di_node(cx, void_pointer_ty);
     let usize_di_node = type_di_node(cx, tcx.types.usize);
     let (pointer_size, pointer_align) = cx.size_and_align_of(void_pointer_ty);
-    // If `usize` is not pointer-sized and -aligned then the size and alignment computations
-    // for the vtable as a whole would be wrong. Let's make sure this holds even on weird
-    // platforms.
-    // FIXME: Nope, not for CHERI.
-    // assert_eq!(cx.size_and_align_of(tcx.types.usize), (pointer_size, pointer_align));
+    // Every vtable entry takes up a pointer-sized slot, even the `usize` ones. On CHERI those are
+    // smaller than a capability, so they are described with their own size to keep debuggers
+    // from reading a whole capability's worth of bytes into them.
+    let usize_size_and_align = cx.size_and_align_of(tcx.types.usize);
 
     let vtable_type_name =
         compute_debuginfo_vtable_name(cx.tcx, ty, poly_trait_ref, VTableNameKind::Type);
@@ -1436,23 +1528,37 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
                 .iter()
                 .enumerate()
                 .filter_map(|(index, vtable_entry)| {
-                    let (field_name, field_type_di_node) = match vtable_entry {
-                        ty::VtblEntry::MetadataDropInPlace => {
-                            ("drop_in_place".to_string(), void_pointer_type_di_node)
-                        }
-                        ty::VtblEntry::Method(_) => {
-                            // Note: This code does not try to give a proper name to each method
-                            //       because their might be multiple methods with the same name
-                            //       (coming from different traits).
-                            (format!("__method{}", index), void_pointer_type_di_node)
-                        }
-                        ty::VtblEntry::TraitVPtr(_) => {
-                            (format!("__super_trait_ptr{}", index), void_pointer_type_di_node)
-                        }
-                        ty::VtblEntry::MetadataAlign => ("align".to_string(), usize_di_node),
-                        ty::VtblEntry::MetadataSize => ("size".to_string(), usize_di_node),
-                        ty::VtblEntry::Vacant => return None,
-                    };
+                    let pointer_size_and_align = (pointer_size, pointer_align);
+                    let (field_name, field_size_and_align, field_type_di_node) =
+                        match vtable_entry {
+                            ty::VtblEntry::MetadataDropInPlace => (
+                                "drop_in_place".to_string(),
+                                pointer_size_and_align,
+                                void_pointer_type_di_node,
+                            ),
+                            ty::VtblEntry::Method(_) => {
+                                // Note: This code does not try to give a proper name to each
+                                //       method because their might be multiple methods with the
+                                //       same name (coming from different traits).
+                                (
+                                    format!("__method{}", index),
+                                    pointer_size_and_align,
+                                    void_pointer_type_di_node,
+                                )
+                            }
+                            ty::VtblEntry::TraitVPtr(_) => (
+                                format!("__super_trait_ptr{}", index),
+                                pointer_size_and_align,
+                                void_pointer_type_di_node,
+                            ),
+                            ty::VtblEntry::MetadataAlign => {
+                                ("align".to_string(), usize_size_and_align, usize_di_node)
+                            }
+                            ty::VtblEntry::MetadataSize => {
+                                ("size".to_string(), usize_size_and_align, usize_di_node)
+                            }
+                            ty::VtblEntry::Vacant => return None,
+                        };
 
                     let field_offset = pointer_size * index as u64;
 
@@ -1460,7 +1566,7 @@ fn build_vtable_type_di_node<'ll, 'tcx>(
                         cx,
                         vtable_type_di_node,
                         &field_name,
-                        (pointer_size, pointer_align),
+                        field_size_and_align,
                         field_offset,
                         DIFlags::FlagZero,
                         field_type_di_node,
@@ -13,12 +13,13 @@
 use rustc_codegen_ssa::mir::operand::OperandRef;
 use rustc_codegen_ssa::mir::place::PlaceRef;
 use rustc_codegen_ssa::traits::*;
+use rustc_codegen_ssa::MemFlags;
 use rustc_hir as hir;
 use rustc_middle::ty::layout::{FnAbiOf, HasTyCtxt, LayoutOf};
 use rustc_middle::ty::{self, Ty};
 use rustc_middle::{bug, span_bug};
 use rustc_span::{sym, symbol::kw, Span, Symbol};
-use rustc_target::abi::{self, Align, HasDataLayout, Primitive};
+use rustc_target::abi::{self, AddressSpace, Align, HasDataLayout, Primitive};
 use rustc_target::spec::{HasTargetSpec, PanicStrategy};
 
 use std::cmp::Ordering;
@@ -132,7 +133,9 @@ fn codegen_intrinsic_call(
             }
             sym::breakpoint => self.call_intrinsic("llvm.debugtrap", &[]),
             sym::va_copy => {
-                self.call_intrinsic("llvm.va_copy", &[args[0].immediate(), args[1].immediate()])
+                let (dest, src) = (args[0].immediate(), args[1].immediate());
+                let name = va_intrinsic_name(self, "llvm.va_copy", dest);
+                self.call_intrinsic(&name, &[dest, src])
             }
             sym::va_arg => {
                 match fn_abi.ret.layout.abi {
@@ -150,7 +153,7 @@ fn codegen_intrinsic_call(
                                     emit_va_arg(self, args[0], ret_ty)
                                 }
                             }
-                            Primitive::F64 | Primitive::Pointer => {
+                            Primitive::F64 | Primitive::Pointer(_) => {
                                 emit_va_arg(self, args[0], ret_ty)
                             }
                             // `va_arg` should never be used with the return type f32.
@@ -164,11 +167,30 @@ fn codegen_intrinsic_call(
             sym::volatile_load | sym::unaligned_volatile_load => {
                 let tp_ty = substs.type_at(0);
                 let ptr = args[0].immediate();
+                if dl.is_cheri_purecap && matches!(fn_abi.ret.mode, PassMode::Cast(..)) {
+                    // Loading through the cast type would read any capabilities in the value as
+                    // integers and clear their tags, so load the value with its own type and
+                    // store it to the return place directly.
+                    let llty = self.layout_of(tp_ty).llvm_type(self);
+                    let load = self.volatile_load(llty, ptr);
+                    let align = if name == sym::unaligned_volatile_load {
+                        1
+                    } else {
+                        self.align_of(tp_ty).bytes() as u32
+                    };
+                    unsafe {
+                        llvm::LLVMSetAlignment(load, align);
+                    }
+                    let address_space = self.pointer_address_space(result.llval);
+                    let ptr_llty = self.type_ptr_to_ext(llty, address_space);
+                    let dst = self.pointercast(result.llval, ptr_llty);
+                    self.store(load, dst, result.align);
+                    return;
+                }
                 let load = if let PassMode::Cast(ty, _) = &fn_abi.ret.mode {
                     let llty = ty.llvm_type(self);
-                    // TODO: Get the correct address space.
-                    let ptr =
-                        self.pointercast(ptr, self.type_ptr_to_ext(llty, dl.default_address_space));
+                    let address_space = self.pointer_address_space(ptr);
+                    let ptr = self.pointercast(ptr, self.type_ptr_to_ext(llty, address_space));
                     self.volatile_load(llty, ptr)
                 } else {
                     self.volatile_load(self.layout_of(tp_ty).llvm_type(self), ptr)
@@ -323,18 +345,18 @@ fn codegen_intrinsic_call(
                     self.const_bool(true)
                 } else if use_integer_compare {
                     let integer_ty = self.type_ix(layout.size().bits());
-                    // TODO: Get the correct address space.
-                    let ptr_ty = self.type_ptr_to_ext(integer_ty, dl.default_address_space);
-                    let a_ptr = self.bitcast(a, ptr_ty);
+                    let a_ptr_ty = self.type_ptr_to_ext(integer_ty, self.pointer_address_space(a));
+                    let a_ptr = self.bitcast(a, a_ptr_ty);
                     let a_val = self.load(integer_ty, a_ptr, layout.align().abi);
-                    let b_ptr = self.bitcast(b, ptr_ty);
+                    let b_ptr_ty = self.type_ptr_to_ext(integer_ty, self.pointer_address_space(b));
+                    let b_ptr = self.bitcast(b, b_ptr_ty);
                     let b_val = self.load(integer_ty, b_ptr, layout.align().abi);
                     self.icmp(IntPredicate::IntEQ, a_val, b_val)
                 } else {
-                    // TODO: Get the correct address space.
+                    // `memcmp` takes pointers in the default address space.
                     let i8p_ty = self.type_i8p_ext(dl.default_address_space);
-                    let a_ptr = self.bitcast(a, i8p_ty);
-                    let b_ptr = self.bitcast(b, i8p_ty);
+                    let a_ptr = self.pointercast(a, i8p_ty);
+                    let b_ptr = self.pointercast(b, i8p_ty);
                     let n = self.const_usize(layout.size().bytes());
                     let cmp = self.call_intrinsic("memcmp", &[a_ptr, b_ptr, n]);
                     match self.cx.sess().target.arch.as_ref() {
@@ -344,8 +366,84 @@ fn codegen_intrinsic_call(
                 }
             }
 
+            sym::ptr_eq_exact => {
+                let (a, b) = (args[0].immediate(), args[1].immediate());
+                if dl.is_cheri_purecap {
+                    // Compare the whole capability, not just its address.
+                    let cap_ty = self.type_i8p_ext(abi::AddressSpace::CHERI_CAPABILITY);
+                    let a = self.pointercast(a, cap_ty);
+                    let b = self.pointercast(b, cap_ty);
+                    self.call_intrinsic("llvm.cheri.cap.equal.exact", &[a, b])
+                } else {
+                    self.icmp(IntPredicate::IntEQ, a, b)
+                }
+            }
+
+            sym::cheri_cap_length_get
+            | sym::cheri_representable_length
+            | sym::cheri_representable_alignment_mask => {
+                let usize_bits = dl.ptr_layout(None).val_size.bits();
+                let arg = args[0].immediate();
+                match (dl.is_cheri_purecap, name) {
+                    (true, sym::cheri_cap_length_get) => {
+                        let cap_ty = self.type_i8p_ext(abi::AddressSpace::CHERI_CAPABILITY);
+                        let cap = self.pointercast(arg, cap_ty);
+                        let name = format!("llvm.cheri.cap.length.get.i{usize_bits}");
+                        self.call_intrinsic(&name, &[cap])
+                    }
+                    (true, sym::cheri_representable_length) => self.call_intrinsic(
+                        &format!("llvm.cheri.round.representable.length.i{usize_bits}"),
+                        &[arg],
+                    ),
+                    (true, _) => self.call_intrinsic(
+                        &format!("llvm.cheri.representable.alignment.mask.i{usize_bits}"),
+                        &[arg],
+                    ),
+                    // Without capabilities pointers are unbounded and every length is
+                    // representable.
+                    (false, sym::cheri_representable_length) => arg,
+                    (false, _) => {
+                        self.const_usize(dl.ptr_layout(None).val_size.unsigned_int_max() as u64)
+                    }
+                }
+            }
+
+            sym::cheri_cap_build => {
+                let ret_layout = self.layout_of(ret_ty);
+                let is_capability = match (ret_layout.abi, dl.capability_address_space()) {
+                    (abi::Abi::Scalar(scalar), Some(address_space)) => {
+                        scalar.primitive() == Primitive::Pointer(address_space)
+                    }
+                    _ => false,
+                };
+                if !is_capability {
+                    span_invalid_monomorphization_error(
+                        tcx.sess,
+                        span,
+                        &format!(
+                            "invalid monomorphization of `{}` intrinsic: \
+                                  expected a capability type, found `{}`",
+                            name, ret_ty
+                        ),
+                    );
+                    return;
+                }
+                // Start from the default data capability, move it to `addr` and then narrow it
+                // to `len` bytes. Both steps keep the tag as long as the result stays within
+                // the bounds of DDC.
+                let usize_bits = dl.ptr_layout(None).val_size.bits();
+                let ddc = self.call_intrinsic("llvm.cheri.ddc.get", &[]);
+                let cap =
+                    self.call_intrinsic("llvm.cheri.cap.address.set", &[ddc, args[0].immediate()]);
+                let cap = self.call_intrinsic(
+                    &format!("llvm.cheri.cap.bounds.set.i{usize_bits}"),
+                    &[cap, args[1].immediate()],
+                );
+                self.pointercast(cap, self.immediate_backend_type(ret_layout))
+            }
+
             sym::black_box => {
-                args[0].val.store(self, result);
+                args[0].val.store_with_flags(self, result, MemFlags::PRESERVE_CHERI_TAGS);
 
                 // We need to "use" the argument in some way LLVM can't introspect, and on
                 // targets that support it we can typically leverage inline assembly to do
@@ -383,8 +481,8 @@ fn codegen_intrinsic_call(
 
         if !fn_abi.ret.is_ignore() {
             if let PassMode::Cast(ty, _) = &fn_abi.ret.mode {
-                // TODO: Get the correct address space from result.llval.
-                let ptr_llty = self.type_ptr_to_ext(ty.llvm_type(self), dl.default_address_space);
+                let address_space = self.pointer_address_space(result.llval);
+                let ptr_llty = self.type_ptr_to_ext(ty.llvm_type(self), address_space);
                 let ptr = self.pointercast(result.llval, ptr_llty);
                 self.store(llval, ptr, result.align);
             } else {
@@ -410,10 +508,10 @@ fn expect(&mut self, cond: Self::Value, expected: bool) -> Self::Value {
     fn type_test(&mut self, pointer: Self::Value, typeid: Self::Value) -> Self::Value {
         // Test the called operand using llvm.type.test intrinsic. The LowerTypeTests link-time
         // optimization pass replaces calls to this intrinsic with code to test type membership.
-        // TODO: Get the correct address space.
-        let i8p_ty = self.type_i8p_ext(self.tcx.data_layout.default_address_space);
-        let bitcast = self.bitcast(pointer, i8p_ty);
-        self.call_intrinsic("llvm.type.test", &[bitcast, typeid])
+        // `llvm.type.test` is overloaded on the address space of the pointer.
+        let address_space = self.pointer_address_space(pointer);
+        let bitcast = self.bitcast(pointer, self.type_i8p_ext(address_space));
+        self.call_intrinsic(&format!("llvm.type.test.p{}i8", address_space.0), &[bitcast, typeid])
     }
 
     fn type_checked_load(
@@ -427,11 +525,29 @@ fn type_checked_load(
     }
 
     fn va_start(&mut self, va_list: &'ll Value) -> &'ll Value {
-        self.call_intrinsic("llvm.va_start", &[va_list])
+        let name = va_intrinsic_name(self, "llvm.va_start", va_list);
+        self.call_intrinsic(&name, &[va_list])
     }
 
     fn va_end(&mut self, va_list: &'ll Value) -> &'ll Value {
-        self.call_intrinsic("llvm.va_end", &[va_list])
+        let name = va_intrinsic_name(self, "llvm.va_end", va_list);
+        self.call_intrinsic(&name, &[va_list])
+    }
+}
+
+/// Returns the name of the `va_*` intrinsic overload for the address space of `va_list`.
+///
+/// On CHERI purecap targets the `va_list` lives in the capability address space, so the
+/// unsuffixed address space 0 declarations cannot be used.
+fn va_intrinsic_name(bx: &Builder<'_, '_, '_>, base: &str, va_list: &Value) -> String {
+    let addr_space = bx.cx.address_space(bx.cx.val_ty(va_list));
+    if addr_space == AddressSpace::DATA {
+        return base.to_string();
+    }
+    if base == "llvm.va_copy" {
+        format!("{base}.p{0}i8.p{0}i8", addr_space.0)
+    } else {
+        format!("{base}.p{}i8", addr_space.0)
     }
 }
 
@@ -443,7 +559,7 @@ fn try_intrinsic<'ll>(
     dest: &'ll Value,
 ) {
     if bx.sess().panic_strategy() == PanicStrategy::Abort {
-        // TODO: Get the correct address space.
+        // `data` is a `*mut u8`, so it is in the default address space.
         let try_func_ty = bx.type_func(
             &[bx.type_i8p_ext(bx.tcx().data_layout.default_address_space)],
             bx.type_void(),
@@ -547,7 +663,7 @@ fn codegen_msvc_try<'ll>(
         //
         // More information can be found in libstd's seh.rs implementation.
         let ptr_align = dl.ptr_layout(Some(dl.alloca_address_space)).align.abi;
-        // TODO: Get the correct address space.
+        // MSVC targets have a single address space.
         let slot = bx.alloca(bx.type_i8p_ext(dl.default_address_space), ptr_align);
         let try_func_ty =
             bx.type_func(&[bx.type_i8p_ext(dl.default_address_space)], bx.type_void());
@@ -573,7 +689,6 @@ fn codegen_msvc_try<'ll>(
         //
         // When modifying, make sure that the type_name string exactly matches
         // the one used in src/libpanic_unwind/seh.rs.
-        // TODO: Is this the right address space?
         let type_info_vtable =
             bx.declare_global("??_7type_info@@6B@", bx.type_i8p_ext(dl.globals_address_space));
         let type_name = bx.const_bytes(b"rust_panic\0");
@@ -601,7 +716,6 @@ fn codegen_msvc_try<'ll>(
         bx.switch_to_block(catchpad_rust);
         let flags = bx.const_i32(8);
         let funclet = bx.catch_pad(cs, &[tydesc, flags, slot]);
-        // TODO: Get the correct address space.
         let ptr = bx.load(bx.type_i8p_ext(dl.default_address_space), slot, ptr_align);
         let catch_ty = bx.type_func(
             &[bx.type_i8p_ext(dl.default_address_space), bx.type_i8p_ext(dl.default_address_space)],
@@ -613,7 +727,6 @@ fn codegen_msvc_try<'ll>(
         // The flag value of 64 indicates a "catch-all".
         bx.switch_to_block(catchpad_foreign);
         let flags = bx.const_i32(64);
-        // TODO: Is this the right address space?
         let null = bx.const_null(bx.type_i8p_ext(dl.globals_address_space));
         let funclet = bx.catch_pad(cs, &[null, flags, null]);
         bx.call(catch_ty, None, catch_func, &[data, null], Some(&funclet));
@@ -668,7 +781,7 @@ fn codegen_gnu_try<'ll>(
         let try_func = llvm::get_param(bx.llfn(), 0);
         let data = llvm::get_param(bx.llfn(), 1);
         let catch_func = llvm::get_param(bx.llfn(), 2);
-        // TODO: Get the correct address space.
+        // `data` and the exception object are `*mut u8`s, in the default address space.
         let try_func_ty =
             bx.type_func(&[bx.type_i8p_ext(dl.default_address_space)], bx.type_void());
         bx.invoke(try_func_ty, None, try_func, &[data], then, catch, None);
@@ -684,12 +797,11 @@ fn codegen_gnu_try<'ll>(
         // rust_try ignores the selector.
         bx.switch_to_block(catch);
         let lpad_ty =
-            bx.type_struct(&[bx.type_i8p_ext(dl.globals_address_space), bx.type_i32()], false);
+            bx.type_struct(&[bx.type_i8p_ext(dl.default_address_space), bx.type_i32()], false);
         let vals = bx.landing_pad(lpad_ty, bx.eh_personality(), 1);
         let tydesc = bx.const_null(bx.type_i8p_ext(dl.globals_address_space));
         bx.add_clause(vals, tydesc);
         let ptr = bx.extract_value(vals, 0);
-        // TODO: Get the correct address space.
         let catch_ty = bx.type_func(
             &[bx.type_i8p_ext(dl.default_address_space), bx.type_i8p_ext(dl.default_address_space)],
             bx.type_void(),
@@ -742,9 +854,8 @@ fn codegen_emcc_try<'ll>(
         let data = llvm::get_param(bx.llfn(), 1);
         let catch_func = llvm::get_param(bx.llfn(), 2);
 
-        // TODO: Get the correct address space. We need to think about whether there needs to be
-        // separate functions for each different address space (IE like LLVM's intriniscs with the
-        // 'p0.i8' vs 'p200.i8' suffix).
+        // `data`, the exception object and `catch_data` are `*mut u8`s, so they are all in the
+        // default address space, and one `__rust_try` serves every caller.
         let try_func_ty =
             bx.type_func(&[bx.type_i8p_ext(dl.default_address_space)], bx.type_void());
         bx.invoke(try_func_ty, None, try_func, &[data], then, catch, None);
@@ -759,12 +870,10 @@ fn codegen_emcc_try<'ll>(
         // the landing pad clauses the exception's type had been matched to.
         bx.switch_to_block(catch);
         let tydesc = bx.eh_catch_typeinfo();
-        // TODO: Get the correct address space.
         let lpad_ty =
             bx.type_struct(&[bx.type_i8p_ext(dl.default_address_space), bx.type_i32()], false);
         let vals = bx.landing_pad(lpad_ty, bx.eh_personality(), 2);
         bx.add_clause(vals, tydesc);
-        // TODO: Get the correct address space.
         bx.add_clause(vals, bx.const_null(bx.type_i8p_ext(dl.default_address_space)));
         let ptr = bx.extract_value(vals, 0);
         let selector = bx.extract_value(vals, 1);
@@ -778,7 +887,6 @@ fn codegen_emcc_try<'ll>(
         // create an alloca and pass a pointer to that.
         let ptr_align = dl.ptr_layout(Some(dl.alloca_address_space)).align.abi;
         let i8_align = dl.i8_align.abi;
-        // TODO: Get the correct address space.
         let catch_data_type =
             bx.type_struct(&[bx.type_i8p_ext(dl.default_address_space), bx.type_bool()], false);
         let catch_data = bx.alloca(catch_data_type, ptr_align);
@@ -788,10 +896,8 @@ fn codegen_emcc_try<'ll>(
         let catch_data_1 =
             bx.inbounds_gep(catch_data_type, catch_data, &[bx.const_usize(0), bx.const_usize(1)]);
         bx.store(is_rust_panic, catch_data_1, i8_align);
-        // TODO: Get the correct address space.
         let catch_data = bx.bitcast(catch_data, bx.type_i8p_ext(dl.default_address_space));
 
-        // TODO: Get the correct address space.
         let catch_ty = bx.type_func(
             &[bx.type_i8p_ext(dl.default_address_space), bx.type_i8p_ext(dl.default_address_space)],
             bx.type_void(),
@@ -1355,7 +1461,7 @@ fn llvm_vector_ty<'ll>(
             _ => unreachable!(),
         };
         while no_pointers > 0 {
-            // TODO: Get the correct address space.
+            // The pointees of SIMD pointer vectors are data, in the default address space.
             elem_ty = cx.type_ptr_to_ext(elem_ty, cx.tcx().data_layout.default_address_space);
             no_pointers -= 1;
         }
@@ -1611,6 +1611,12 @@ pub fn LLVMBuildPointerCast<'a>(
         DestTy: &'a Type,
         Name: *const c_char,
     ) -> &'a Value;
+    pub fn LLVMBuildAddrSpaceCast<'a>(
+        B: &Builder<'a>,
+        Val: &'a Value,
+        DestTy: &'a Type,
+        Name: *const c_char,
+    ) -> &'a Value;
     pub fn LLVMRustBuildIntCast<'a>(
         B: &Builder<'a>,
         Val: &'a Value,
@@ -1786,6 +1792,7 @@ pub fn LLVMBuildAtomicCmpXchg<'a>(
     ) -> &'a Value;
 
     pub fn LLVMSetWeak(CmpXchgInst: &Value, IsWeak: Bool);
+    pub fn LLVMRustSetCmpXchgExact(CmpXchgInst: &Value, Exact: bool);
 
     pub fn LLVMBuildAtomicRMW<'a>(
         B: &Builder<'a>,
@@ -1905,6 +1912,13 @@ pub fn LLVMRustAddModuleFlag(
         name: *const c_char,
         value: u32,
     );
+    pub fn LLVMRustAddModuleFlagString(
+        M: &Module,
+        merge_behavior: LLVMModFlagBehavior,
+        name: *const c_char,
+        value: *const c_char,
+        value_len: size_t,
+    );
     pub fn LLVMRustHasModuleFlag(M: &Module, name: *const c_char, len: size_t) -> bool;
 
     pub fn LLVMRustMetadataAsValue<'a>(C: &'a Context, MD: &'a Metadata) -> &'a Value;
@@ -2025,6 +2039,20 @@ pub fn LLVMRustDIBuilderCreateMemberType<'a>(
         Ty: &'a DIType,
     ) -> &'a DIDerivedType;
 
+    pub fn LLVMRustDIBuilderCreateBitFieldMemberType<'a>(
+        Builder: &DIBuilder<'a>,
+        Scope: &'a DIDescriptor,
+        Name: *const c_char,
+        NameLen: size_t,
+        File: &'a DIFile,
+        LineNo: c_uint,
+        SizeInBits: u64,
+        OffsetInBits: u64,
+        StorageOffsetInBits: u64,
+        Flags: DIFlags,
+        Ty: &'a DIType,
+    ) -> &'a DIDerivedType;
+
     pub fn LLVMRustDIBuilderCreateVariantMemberType<'a>(
         Builder: &DIBuilder<'a>,
         Scope: &'a DIScope,
@@ -1,6 +1,7 @@
 use crate::back::write::create_informational_target_machine;
 use crate::llvm;
 use libc::c_int;
+use rustc_codegen_ssa::back::write::cheri_llvm_args;
 use rustc_codegen_ssa::target_features::{
     supported_target_features, tied_target_features, RUSTC_SPECIFIC_FEATURES,
 };
@@ -100,6 +101,10 @@ fn llvm_arg_to_arg_name(full_arg: &str) -> &str {
             add("-enable-emscripten-cxx-exceptions", false);
         }
 
+        for arg in cheri_llvm_args(sess) {
+            add(arg, false);
+        }
+
         // HACK(eddyb) LLVM inserts `llvm.assume` calls to preserve align attributes
         // during inlining. Unfortunately these may block other optimizations.
         add("-preserve-alignment-assumptions-during-inlining=false", false);
@@ -146,7 +151,11 @@ pub fn time_trace_profiler_finish(file_name: &Path) {
 // which might lead to failures if the oldest tested / supported LLVM version
 // doesn't yet support the relevant intrinsics
 pub fn to_llvm_features<'a>(sess: &Session, s: &'a str) -> SmallVec<[&'a str; 2]> {
-    let arch = if sess.target.arch == "x86_64" { "x86" } else { &*sess.target.arch };
+    let arch = match &*sess.target.arch {
+        "x86_64" => "x86",
+        "morello+c64" => "aarch64",
+        arch => arch,
+    };
     match (arch, s) {
         ("x86", "sse4.2") => {
             if get_version() >= (14, 0, 0) {
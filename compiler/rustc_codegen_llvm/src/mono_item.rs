@@ -137,6 +137,14 @@ pub(crate) unsafe fn should_assume_dso_local(
             return false;
         }
 
+        // CHERI purecap code loads the capabilities for globals from the capability table, which
+        // the runtime fills in from the `__cap_relocs` or dynamic capability relocations. A copy
+        // relocation would move the object out from under the bounds of capabilities derived in
+        // its defining module, so symbols defined elsewhere must always go through the table.
+        if self.tcx.sess.target.pointer_type_width.is_some() && is_declaration_for_linker {
+            return false;
+        }
+
         // Static relocation model should force copy relocations everywhere.
         if self.tcx.sess.relocation_model() == RelocModel::Static {
             return true;
@@ -232,18 +232,21 @@ fn llvm_type<'a>(&self, cx: &CodegenCx<'a, 'tcx>) -> &'a Type {
             if let Some(&llty) = cx.scalar_lltypes.borrow().get(&self.ty) {
                 return llty;
             }
+            // The layout already knows which address space the pointer lives in, e.g. 200 for
+            // every pointer on a CHERI purecap target, so take it from there rather than
+            // assuming the default one.
+            let address_space = match scalar.primitive() {
+                Pointer(address_space) => address_space,
+                _ => dl.default_address_space,
+            };
             let llty = match *self.ty.kind() {
                 ty::Ref(_, ty, _) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => {
-                    // TODO: Get the correct address space.
-                    cx.type_ptr_to_ext(cx.layout_of(ty).llvm_type(cx), dl.default_address_space)
-                }
-                ty::Adt(def, _) if def.is_box() => {
-                    // TODO: Get the correct address space. Possibly global?
-                    cx.type_ptr_to_ext(
-                        cx.layout_of(self.ty.boxed_ty()).llvm_type(cx),
-                        dl.default_address_space,
-                    )
+                    cx.type_ptr_to_ext(cx.layout_of(ty).llvm_type(cx), address_space)
                 }
+                ty::Adt(def, _) if def.is_box() => cx.type_ptr_to_ext(
+                    cx.layout_of(self.ty.boxed_ty()).llvm_type(cx),
+                    address_space,
+                ),
                 ty::FnPtr(sig) => {
                     cx.fn_ptr_backend_type(cx.fn_abi_of_fn_ptr(sig, ty::List::empty()))
                 }
@@ -318,13 +321,13 @@ fn scalar_llvm_type_at<'a>(
             Int(i, _) => cx.type_from_integer(i),
             F32 => cx.type_f32(),
             F64 => cx.type_f64(),
-            Pointer => {
+            Pointer(address_space) => {
                 // If we know the alignment, pick something better than i8.
                 let (pointee, address_space) =
                     if let Some(pointee) = self.pointee_info_at(cx, offset) {
                         (cx.type_pointee_for_align(pointee.align), pointee.address_space)
                     } else {
-                        (cx.type_i8(), cx.tcx.data_layout().default_address_space)
+                        (cx.type_i8(), address_space)
                     };
                 cx.type_ptr_to_ext(pointee, address_space)
             }
@@ -17,10 +17,11 @@ fn round_pointer_up_to_alignment<'ll>(
     align: Align,
     ptr_ty: &'ll Type,
 ) -> &'ll Value {
-    // TODO: Safe for CHERI?
+    // Only the address is rounded, so on CHERI the capability keeps its bounds and permissions.
     let mut ptr_as_int = bx.get_pointer_address(addr);
-    ptr_as_int = bx.add(ptr_as_int, bx.cx().const_i32(align.bytes() as i32 - 1));
-    ptr_as_int = bx.and(ptr_as_int, bx.cx().const_i32(-(align.bytes() as i32)));
+    let int_ty = bx.cx().val_ty(ptr_as_int);
+    ptr_as_int = bx.add(ptr_as_int, bx.cx().const_int(int_ty, align.bytes() as i64 - 1));
+    ptr_as_int = bx.and(ptr_as_int, bx.cx().const_int(int_ty, -(align.bytes() as i64)));
     let int_as_ptr = bx.set_pointer_address(addr, ptr_as_int);
     bx.pointercast(int_as_ptr, ptr_ty)
 }
@@ -219,6 +220,14 @@ pub(super) fn emit_va_arg<'ll, 'tcx>(
             emit_ptr_va_arg(bx, addr, target_ty, false, Align::from_bytes(8).unwrap(), true)
         }
         "aarch64" => emit_aapcs_va_arg(bx, addr, target_ty),
+        // Morello purecap passes all anonymous arguments on the stack and `va_list` is a single
+        // capability. Each argument takes an 8-byte slot, capabilities are realigned to 16 bytes
+        // and composites larger than 16 bytes are passed by reference.
+        "morello+c64" if target.pointer_type_width.is_some() => {
+            let target_ty_size = bx.cx.size_of(target_ty).bytes();
+            let indirect = target_ty_size > 16;
+            emit_ptr_va_arg(bx, addr, target_ty, indirect, Align::from_bytes(8).unwrap(), true)
+        }
         // Windows x86_64
         "x86_64" if target.is_like_windows => {
             let target_ty_size = bx.cx.size_of(target_ty).bytes();
@@ -2170,6 +2170,8 @@ fn add_order_independent_options(
 
     add_apple_sdk(cmd, sess, flavor);
 
+    add_cheribsd_sysroot(cmd, sess, flavor);
+
     add_link_script(cmd, sess, tmpdir, crate_type);
 
     if sess.target.os == "fuchsia"
@@ -2890,6 +2892,51 @@ fn get_apple_sdk_root(sdk_name: &str) -> Result<String, String> {
     }
 }
 
+/// Points the linker at the CheriBSD sysroot named by `CHERIBSD_SYSROOT`, which provides the
+/// purecap C runtime, crt objects and system libraries. This mirrors what the cheribuild clang
+/// wrappers do, so that the target's default linker works without extra `-C link-arg`s.
+fn add_cheribsd_sysroot(cmd: &mut dyn Linker, sess: &Session, flavor: LinkerFlavor) {
+    if sess.target.os != "freebsd" || sess.target.pointer_type_width.is_none() {
+        return;
+    }
+
+    let Ok(sysroot) = env::var("CHERIBSD_SYSROOT") else {
+        // Linking can still work if the sysroot is passed some other way, e.g. by a cheribuild
+        // clang wrapper, so this is only worth a warning if nothing names one.
+        if !sess.opts.cg.link_args.iter().any(|arg| arg.starts_with("--sysroot")) {
+            sess.warn(
+                "`CHERIBSD_SYSROOT` is not set, so the linker is not pointed at a CheriBSD sysroot",
+            );
+        }
+        return;
+    };
+    let p = Path::new(&sysroot);
+    if !p.is_absolute() {
+        sess.warn(&format!(
+            "ignoring `CHERIBSD_SYSROOT={}`, as it is not an absolute path",
+            sysroot
+        ));
+        return;
+    }
+    if !p.exists() {
+        sess.warn(&format!("ignoring `CHERIBSD_SYSROOT={}`, as it does not exist", sysroot));
+        return;
+    }
+
+    match flavor {
+        LinkerFlavor::Gnu(Cc::Yes, _) => {
+            cmd.arg(format!("--sysroot={}", sysroot));
+        }
+        LinkerFlavor::Gnu(Cc::No, _) => {
+            // Without a C compiler driver we have to name the library directories ourselves;
+            // `=` makes them relative to the sysroot.
+            cmd.arg(format!("--sysroot={}", sysroot));
+            cmd.args(&["-L=/lib", "-L=/usr/lib"]);
+        }
+        _ => {}
+    }
+}
+
 fn add_gcc_ld_path(cmd: &mut dyn Linker, sess: &Session, flavor: LinkerFlavor) {
     if let Some(ld_impl) = sess.opts.unstable_opts.gcc_ld {
         if let LinkerFlavor::Gnu(Cc::Yes, _)
@@ -1,4 +1,5 @@
 use super::command::Command;
+use super::write::cheri_llvm_args;
 use super::symbol_export;
 use crate::errors;
 use rustc_span::symbol::sym;
@@ -309,6 +310,9 @@ fn push_linker_plugin_lto_args(&mut self, plugin_path: Option<&OsStr>) {
             &format!("-plugin-opt={}", opt_level),
             &format!("-plugin-opt=mcpu={}", self.target_cpu),
         ]);
+        for arg in cheri_llvm_args(self.sess) {
+            self.linker_arg(&format!("-plugin-opt={}", arg));
+        }
     }
 
     fn build_dylib(&mut self, out_filename: &Path) {
@@ -21,7 +21,7 @@
 use rustc_session::cstore::MetadataLoader;
 use rustc_session::Session;
 use rustc_target::abi::Endian;
-use rustc_target::spec::{RelocModel, Target};
+use rustc_target::spec::{CapabilityAbi, RelocModel, Target};
 
 /// The default metadata loader. This is used by cg_llvm and cg_clif.
 ///
@@ -184,18 +184,17 @@ pub(crate) fn create_object_file(sess: &Session) -> Option<write::Object<'static
                 e_flags |= elf::EF_RISCV_FLOAT_ABI_SOFT;
             }
 
-            if features.contains("+cap-mode") {
-                e_flags |= elf::EF_RISCV_CAP_MODE;
-            }
-
-            if features.contains("+xcheri") {
-                e_flags |= elf::EF_RISCV_CHERIABI;
+            // CheriBSD and its tools tell purecap objects apart by these flags, and the linker
+            // refuses to mix objects that disagree on them. Hybrid code has `+xcheri` as well, but
+            // follows the non-CHERI ABI.
+            if sess.capability_abi() == CapabilityAbi::Purecap {
+                e_flags |= elf::EF_RISCV_CAP_MODE | elf::EF_RISCV_CHERIABI;
             }
             e_flags
         }
         Architecture::Aarch64 => {
             let mut e_flags: u32 = 0x0;
-            if sess.target.arch == "morello+c64" {
+            if sess.capability_abi() == CapabilityAbi::Purecap {
                 e_flags |= elf::EF_AARCH64_CHERI_PURECAP;
             }
             e_flags
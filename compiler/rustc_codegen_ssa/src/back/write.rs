@@ -29,7 +29,7 @@
 use rustc_middle::middle::exported_symbols::SymbolExportInfo;
 use rustc_middle::ty::TyCtxt;
 use rustc_session::cgu_reuse_tracker::CguReuseTracker;
-use rustc_session::config::{self, CrateType, Lto, OutputFilenames, OutputType};
+use rustc_session::config::{self, CheriStackBounds, CrateType, Lto, OutputFilenames, OutputType};
 use rustc_session::config::{Passes, SwitchWithOptPath};
 use rustc_session::Session;
 use rustc_span::source_map::SourceMap;
@@ -432,6 +432,29 @@ fn need_pre_lto_bitcode_for_incr_comp(sess: &Session) -> bool {
     }
 }
 
+/// Returns the LLVM command-line options that select how CHERI capabilities are code generated.
+///
+/// These are global LLVM options rather than module attributes, so they do not travel with
+/// bitcode. Besides configuring the LLVM inside rustc they are handed to the linker plugin for
+/// `-C linker-plugin-lto`, so that code generated at link time matches the rest of the crate.
+pub fn cheri_llvm_args(sess: &Session) -> Vec<&'static str> {
+    let mut args = Vec::new();
+    if sess.target.pointer_type_width.is_none() {
+        return args;
+    }
+    // Purecap CHERI-RISC-V code loads its capabilities from a table addressed PC-relative,
+    // rather than through a dedicated register.
+    if sess.target.arch.starts_with("riscv") {
+        args.push("-cheri-cap-table-abi=pcrel");
+    }
+    args.push(match sess.opts.unstable_opts.cheri_stack_bounds {
+        CheriStackBounds::AllOrNothing => "-cheri-stack-bounds=all-uses",
+        CheriStackBounds::IfEscapes => "-cheri-stack-bounds=if-needed",
+        CheriStackBounds::Off => "-cheri-stack-bounds=never",
+    });
+    args
+}
+
 pub fn start_async_codegen<B: ExtraBackendMethods>(
     backend: B,
     tcx: TyCtxt<'_>,
@@ -38,7 +38,8 @@
 use rustc_span::symbol::sym;
 use rustc_span::Symbol;
 use rustc_span::{DebuggerVisualizerFile, DebuggerVisualizerType};
-use rustc_target::abi::{Align, Size, VariantIdx};
+use rustc_target::abi::{Abi, AddressSpace, Align, FieldsShape, Pointer, Scalar, Size, VariantIdx};
+use rustc_target::abi::Variants;
 
 use std::collections::BTreeSet;
 use std::convert::TryFrom;
@@ -174,6 +175,9 @@ pub fn unsized_info<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
                 let ptr_ty = cx.type_i8p_ext(dl.instruction_address_space);
                 let ptr_align = dl.ptr_layout(Some(dl.instruction_address_space)).align.abi;
                 let vtable_ptr_ty = vtable_ptr_ty(cx, target, target_dyn_kind);
+                let sealed_vtables = cx.sess().cheri_sealed_vtables();
+                let old_info =
+                    if sealed_vtables { bx.unseal_vtable_pointer(old_info) } else { old_info };
                 // TODO: Get the correct address space, probaly global?
                 let llvtable =
                     bx.pointercast(old_info, bx.type_ptr_to_ext(ptr_ty, dl.globals_address_space));
@@ -186,6 +190,8 @@ pub fn unsized_info<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
                 bx.nonnull_metadata(new_vptr);
                 // VTable loads are invariant.
                 bx.set_invariant_load(new_vptr);
+                let new_vptr =
+                    if sealed_vtables { bx.seal_vtable_pointer(new_vptr) } else { new_vptr };
                 bx.pointercast(new_vptr, vtable_ptr_ty)
             } else {
                 old_info
@@ -193,7 +199,9 @@ pub fn unsized_info<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
         }
         (_, &ty::Dynamic(ref data, _, target_dyn_kind)) => {
             let vtable_ptr_ty = vtable_ptr_ty(cx, target, target_dyn_kind);
-            cx.const_ptrcast(meth::get_vtable(cx, source, data.principal()), vtable_ptr_ty)
+            let vtable =
+                cx.const_ptrcast(meth::get_vtable(cx, source, data.principal()), vtable_ptr_ty);
+            if cx.sess().cheri_sealed_vtables() { bx.seal_vtable_pointer(vtable) } else { vtable }
         }
         _ => bug!("unsized_info: invalid unsizing {:?} -> {:?}", source, target),
     }
@@ -392,9 +400,51 @@ pub fn memcpy_ty<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
         return;
     }
 
+    // Moves and copies of values that hold capabilities must not be lowered into integer copies,
+    // which would strip the tags.
+    let flags = match bx.data_layout().capability_address_space() {
+        Some(address_space) if may_hold_capability(bx, layout, address_space) => {
+            flags | MemFlags::PRESERVE_CHERI_TAGS
+        }
+        _ => flags,
+    };
+
     bx.memcpy(dst, dst_align, src, src_align, bx.cx().const_usize(ty_size), flags);
 }
 
+/// Returns `true` if a value with this layout may contain a pointer in the capability address
+/// space `address_space`, looking through all fields and enum variants.
+fn may_hold_capability<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
+    bx: &Bx,
+    layout: TyAndLayout<'tcx>,
+    address_space: AddressSpace,
+) -> bool {
+    // Capabilities can only be stored at capability-aligned addresses.
+    if layout.align.abi < bx.data_layout().ptr_layout(Some(address_space)).align.abi {
+        return false;
+    }
+    let is_capability = |scalar: Scalar| scalar.primitive() == Pointer(address_space);
+    match layout.abi {
+        Abi::Scalar(scalar) => return is_capability(scalar),
+        Abi::ScalarPair(a, b) => return is_capability(a) || is_capability(b),
+        Abi::Vector { .. } => return false,
+        Abi::Uninhabited | Abi::Aggregate { .. } => {}
+    }
+    if let Variants::Multiple { variants, .. } = &layout.variants {
+        return variants.indices().any(|index| {
+            may_hold_capability(bx, layout.for_variant(bx.cx(), index), address_space)
+        });
+    }
+    match layout.fields {
+        FieldsShape::Primitive => false,
+        FieldsShape::Array { count, .. } => {
+            count > 0 && may_hold_capability(bx, layout.field(bx.cx(), 0), address_space)
+        }
+        FieldsShape::Union(_) | FieldsShape::Arbitrary { .. } => (0..layout.fields.count())
+            .any(|i| may_hold_capability(bx, layout.field(bx.cx(), i), address_space)),
+    }
+}
+
 pub fn codegen_instance<'a, 'tcx: 'a, Bx: BuilderMethods<'a, 'tcx>>(
     cx: &'a Bx::CodegenCx,
     instance: Instance<'tcx>,
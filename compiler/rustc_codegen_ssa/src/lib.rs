@@ -106,6 +106,8 @@ pub struct MemFlags: u8 {
         const VOLATILE = 1 << 0;
         const NONTEMPORAL = 1 << 1;
         const UNALIGNED = 1 << 2;
+        /// The copied bytes may hold CHERI capabilities whose tags must survive the copy.
+        const PRESERVE_CHERI_TAGS = 1 << 3;
     }
 }
 
@@ -25,6 +25,7 @@ pub fn get_fn<Bx: BuilderMethods<'a, 'tcx>>(
 
         let dl = &bx.tcx().data_layout;
 
+        let llvtable = unseal_vtable(bx, llvtable);
         let llty = bx.fn_ptr_backend_type(fn_abi);
         // TODO: Get the correct address space. Probably global?
         let llvtable = bx.pointercast(llvtable, bx.type_ptr_to_ext(llty, dl.default_address_space));
@@ -60,6 +61,8 @@ pub fn get_usize<Bx: BuilderMethods<'a, 'tcx>>(
 
         let dl = &bx.tcx().data_layout;
 
+        let llvtable = unseal_vtable(bx, llvtable);
+
         // Our vtable array is represented as a bunch of pointer-width elements, even though we can
         // represent these non-function elements purely in a usize. This means that to obtain the
         // address we have to load with a GEP, we treat them as pointer-width first.
@@ -82,6 +85,19 @@ pub fn get_usize<Bx: BuilderMethods<'a, 'tcx>>(
     }
 }
 
+/// Unseals `llvtable` if vtable pointers are sealed (`-Z cheri-sealed-vtables`), so that the
+/// vtable can be read.
+fn unseal_vtable<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
+    bx: &mut Bx,
+    llvtable: Bx::Value,
+) -> Bx::Value {
+    if bx.cx().sess().cheri_sealed_vtables() {
+        bx.unseal_vtable_pointer(llvtable)
+    } else {
+        llvtable
+    }
+}
+
 /// This takes a valid `self` receiver type and extracts the principal trait
 /// ref of the type.
 fn expect_dyn_trait_in_self<'tcx>(ty: Ty<'tcx>) -> ty::PolyExistentialTraitRef<'tcx> {
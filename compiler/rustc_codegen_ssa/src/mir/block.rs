@@ -1,3 +1,4 @@
+use super::cheri_checks::CheckedCode;
 use super::operand::OperandRef;
 use super::operand::OperandValue::{Immediate, Pair, Ref};
 use super::place::PlaceRef;
@@ -1198,6 +1199,8 @@ fn codegen_terminator(
         let helper = TerminatorCodegenHelper { bb, terminator, funclet_bb };
 
         self.set_debug_loc(&mut bx, terminator.source_info);
+        self.codegen_cheri_access_checks(&mut bx, CheckedCode::Terminator(terminator));
+        self.codegen_cheri_provenance_cast_checks(&mut bx, CheckedCode::Terminator(terminator));
         match terminator.kind {
             mir::TerminatorKind::Resume => self.codegen_resume_terminator(helper, bx),
 
@@ -1411,7 +1414,7 @@ fn codegen_arguments_untupled(
         tuple.layout.fields.count()
     }
 
-    fn get_caller_location(
+    pub(super) fn get_caller_location(
         &mut self,
         bx: &mut Bx,
         mut source_info: mir::SourceInfo,
@@ -1563,8 +1566,10 @@ fn landing_pad_for_uncached(&mut self, bb: mir::BasicBlock) -> Bx::BasicBlock {
     fn landing_pad_type(&self) -> Bx::Type {
         let cx = self.cx;
         let dl = &cx.tcx().data_layout;
-        // TODO: Correct address space?
-        cx.type_struct(&[cx.type_i8p_ext(dl.instruction_address_space), cx.type_i32()], false)
+        // The first field is the exception object, which is data rather than code. On CHERI
+        // purecap targets it is handed over in a capability register (`c0` on Morello), so it must
+        // be a capability for its tag to survive the landing pad.
+        cx.type_struct(&[cx.type_i8p_ext(dl.default_address_space), cx.type_i32()], false)
     }
 
     fn unreachable_block(&mut self) -> Bx::BasicBlock {
@@ -1717,9 +1722,7 @@ fn codegen_transmute_into(
         match (src.layout.abi, dst.layout.abi) {
             (abi::Abi::Scalar(src_scalar), abi::Abi::Scalar(dst_scalar)) => {
                 // HACK(eddyb) LLVM doesn't like `bitcast`s between pointers and non-pointers.
-                if (src_scalar.primitive() == abi::Pointer)
-                    == (dst_scalar.primitive() == abi::Pointer)
-                {
+                if src_scalar.primitive().is_ptr() == dst_scalar.primitive().is_ptr() {
                     assert_eq!(src.layout.ty_size, dst.layout.ty_size);
 
                     // NOTE(eddyb) the `from_immediate` and `to_immediate_scalar`
@@ -1729,7 +1732,7 @@ fn codegen_transmute_into(
                     Immediate(bx.to_immediate_scalar(src_as_dst, dst_scalar)).store(bx, dst);
                     return;
                 }
-                if src_scalar.primitive() != abi::Pointer && dst_scalar.primitive() == abi::Pointer {
+                if !src_scalar.primitive().is_ptr() && dst_scalar.primitive().is_ptr() {
                     // We are generating an invalid pointer - IE with no provenance - with the
                     // address set to the given scalar.
                     assert_eq!(src_scalar.val_size(bx), dst_scalar.val_size(bx));
@@ -1741,7 +1744,7 @@ fn codegen_transmute_into(
                     Immediate(bx.to_immediate_scalar(src_as_dst, dst_scalar)).store(bx, dst);
                     return;
                 }
-                if src_scalar.primitive() == abi::Pointer && dst_scalar.primitive() != abi::Pointer {
+                if src_scalar.primitive().is_ptr() && !dst_scalar.primitive().is_ptr() {
                     // We are generating a non-pointer (IE usize) from a pointer. In which case we
                     // can simply use get_pointer_address.
                     assert_eq!(src_scalar.val_size(bx), dst_scalar.val_size(bx));
@@ -0,0 +1,227 @@
+//! Capability checks for `-Z sanitizer=cheri` and `-Z check-provenance-casts=runtime`.
+//!
+//! Before every access through a raw pointer, in a statement or in the arguments, destination or
+//! dropped place of a terminator, a call to the `cheri_check_access` lang item is inserted with
+//! the capability used for the access, the number of bytes accessed and whether the access is a
+//! store. The lang item panics with the capability's tag, bounds and permissions and the location
+//! of the access if the hardware would fault on it, rather than leaving the process to die on a
+//! `SIGPROT` that says nothing about where the capability came from.
+//!
+//! References are not checked: the compiler derives them from in-bounds places, so any fault
+//! through one goes back to a raw pointer that was checked when it was dereferenced.
+//!
+//! Under `-Z check-provenance-casts=runtime`, the `cheri_check_provenance_cast` lang item is
+//! called instead before every use of a local that holds the result of an int-to-pointer cast,
+//! with the pointer and the size of its pointee. Such pointers have no capability unless the
+//! integer came from one, and the check names the integer they were cast from. Pointers that are
+//! passed on before they are used are left to the checks in the functions that use them.
+
+use super::FunctionCx;
+use crate::common;
+use crate::glue;
+use crate::traits::*;
+
+use rustc_hir::LangItem;
+use rustc_index::bit_set::BitSet;
+use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::{self, Location};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::config::CheckProvenanceCasts;
+use rustc_target::spec::{CapabilityAbi, SanitizerSet};
+
+/// A statement or terminator whose accesses through pointers are checked. Terminators access
+/// memory for the arguments and destinations of calls and for the places they drop.
+#[derive(Copy, Clone)]
+pub enum CheckedCode<'a, 'tcx> {
+    Statement(&'a mir::Statement<'tcx>),
+    Terminator(&'a mir::Terminator<'tcx>),
+}
+
+impl<'a, 'tcx> CheckedCode<'a, 'tcx> {
+    fn source_info(self) -> mir::SourceInfo {
+        match self {
+            CheckedCode::Statement(statement) => statement.source_info,
+            CheckedCode::Terminator(terminator) => terminator.source_info,
+        }
+    }
+
+    /// Returns the places that go through a dereference, and how each one is used.
+    fn accessed_places(self) -> Vec<(mir::Place<'tcx>, PlaceContext)> {
+        let mut visitor = AccessedPlaces { places: Vec::new() };
+        match self {
+            CheckedCode::Statement(statement) => {
+                visitor.visit_statement(statement, Location::START)
+            }
+            CheckedCode::Terminator(terminator) => {
+                visitor.visit_terminator(terminator, Location::START)
+            }
+        }
+        visitor.places
+    }
+}
+
+/// Collects the places in a statement or terminator that go through a dereference, and how each
+/// one is used.
+struct AccessedPlaces<'tcx> {
+    places: Vec<(mir::Place<'tcx>, PlaceContext)>,
+}
+
+impl<'tcx> Visitor<'tcx> for AccessedPlaces<'tcx> {
+    fn visit_place(&mut self, place: &mir::Place<'tcx>, context: PlaceContext, _: Location) {
+        if place.projection.iter().any(|elem| elem == mir::ProjectionElem::Deref) {
+            self.places.push((*place, context));
+        }
+    }
+}
+
+/// Returns the locals of `mir` that are assigned the result of an int-to-pointer cast, or none if
+/// `-Z check-provenance-casts=runtime` doesn't apply.
+pub(super) fn provenance_cast_locals<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    mir: &mir::Body<'tcx>,
+) -> BitSet<mir::Local> {
+    let mut locals = BitSet::new_empty(mir.local_decls.len());
+    if tcx.sess.opts.unstable_opts.check_provenance_casts != CheckProvenanceCasts::Runtime
+        || tcx.sess.capability_abi() != CapabilityAbi::Purecap
+    {
+        return locals;
+    }
+    for data in mir.basic_blocks.iter() {
+        for statement in &data.statements {
+            let mir::StatementKind::Assign(box (place, rvalue)) = &statement.kind else {
+                continue;
+            };
+            if let mir::Rvalue::Cast(mir::CastKind::PointerFromExposedAddress, ..) = rvalue {
+                if let Some(local) = place.as_local() {
+                    locals.insert(local);
+                }
+            }
+        }
+    }
+    locals
+}
+
+impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
+    pub fn codegen_cheri_access_checks(&mut self, bx: &mut Bx, code: CheckedCode<'_, 'tcx>) {
+        let tcx = bx.tcx();
+        let no_sanitize = tcx.codegen_fn_attrs(self.instance.def_id()).no_sanitize;
+        if !tcx.sess.opts.unstable_opts.sanitizer.contains(SanitizerSet::CHERI)
+            || no_sanitize.contains(SanitizerSet::CHERI)
+        {
+            return;
+        }
+
+        let source_info = code.source_info();
+        for (place, context) in code.accessed_places() {
+            let accesses_place = match context {
+                PlaceContext::NonMutatingUse(
+                    NonMutatingUseContext::Copy
+                    | NonMutatingUseContext::Move
+                    | NonMutatingUseContext::Inspect,
+                ) => Some(false),
+                // Calls and inline assembly store their results, and a drop may write to the
+                // place it drops.
+                PlaceContext::MutatingUse(
+                    MutatingUseContext::Store
+                    | MutatingUseContext::SetDiscriminant
+                    | MutatingUseContext::Call
+                    | MutatingUseContext::AsmOutput
+                    | MutatingUseContext::Yield
+                    | MutatingUseContext::Drop,
+                ) => Some(true),
+                // Borrows and `addr_of!` only compute an address, but still load any pointers
+                // that are dereferenced on the way there.
+                _ => None,
+            };
+
+            let derefs: Vec<_> = place
+                .projection
+                .iter()
+                .enumerate()
+                .filter(|(_, elem)| *elem == mir::ProjectionElem::Deref)
+                .map(|(i, _)| i)
+                .collect();
+            for (n, &i) in derefs.iter().enumerate() {
+                let base = mir::Place::ty_from(place.local, &place.projection[..i], self.mir, tcx);
+                if !self.monomorphize(base.ty).is_unsafe_ptr() {
+                    continue;
+                }
+
+                // The access through this pointer is of everything up to the next dereference,
+                // which loads the pointer for it, or of the whole place if there is none.
+                let end = derefs.get(n + 1).copied().unwrap_or(place.projection.len());
+                let is_store = if end == place.projection.len() {
+                    match accesses_place {
+                        Some(is_store) => is_store,
+                        None => continue,
+                    }
+                } else {
+                    false
+                };
+
+                let accessed =
+                    mir::PlaceRef { local: place.local, projection: &place.projection[..end] };
+                let cg_place = self.codegen_place(bx, accessed);
+                let size = if cg_place.layout.is_unsized() {
+                    glue::size_and_align_of_dst(bx, cg_place.layout.ty, cg_place.llextra).0
+                } else if cg_place.layout.is_zst() {
+                    continue;
+                } else {
+                    bx.const_usize(cg_place.layout.size.bytes())
+                };
+
+                // It's `fn check_access(ptr: *const u8, len: usize, store: bool)`, and
+                // `#[track_caller]` adds an implicit fourth argument.
+                let location = self.get_caller_location(bx, source_info).immediate();
+                let args = [cg_place.llval, size, bx.const_bool(is_store), location];
+                let (fn_abi, llfn) =
+                    common::build_langcall(bx, Some(source_info.span), LangItem::CheriCheckAccess);
+                let fn_ty = bx.fn_decl_backend_type(fn_abi);
+                bx.call(fn_ty, Some(fn_abi), llfn, &args, None);
+            }
+        }
+    }
+
+    pub fn codegen_cheri_provenance_cast_checks(
+        &mut self,
+        bx: &mut Bx,
+        code: CheckedCode<'_, 'tcx>,
+    ) {
+        if self.provenance_cast_locals.is_empty() {
+            return;
+        }
+
+        let source_info = code.source_info();
+        let mut checked = Vec::new();
+        for (place, context) in code.accessed_places() {
+            // Only the first dereference is of the pointer held by the local itself.
+            if !context.is_use()
+                || place.projection.first() != Some(&mir::ProjectionElem::Deref)
+                || !self.provenance_cast_locals.contains(place.local)
+                || checked.contains(&place.local)
+            {
+                continue;
+            }
+            checked.push(place.local);
+
+            let pointee = mir::PlaceRef { local: place.local, projection: &place.projection[..1] };
+            let cg_place = self.codegen_place(bx, pointee);
+            if cg_place.layout.is_unsized() || cg_place.layout.is_zst() {
+                continue;
+            }
+
+            // It's `fn check_provenance_cast(ptr: *const u8, len: usize)`, and `#[track_caller]`
+            // adds an implicit third argument.
+            let size = bx.const_usize(cg_place.layout.size.bytes());
+            let location = self.get_caller_location(bx, source_info).immediate();
+            let args = [cg_place.llval, size, location];
+            let (fn_abi, llfn) = common::build_langcall(
+                bx,
+                Some(source_info.span),
+                LangItem::CheriCheckProvenanceCast,
+            );
+            let fn_ty = bx.fn_decl_backend_type(fn_abi);
+            bx.call(fn_ty, Some(fn_abi), llfn, &args, None);
+        }
+    }
+}
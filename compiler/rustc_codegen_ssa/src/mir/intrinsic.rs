@@ -27,7 +27,10 @@ fn copy_intrinsic<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     let ty_size = layout.ty_size;
     let align = layout.align.abi;
     let ty_size = bx.mul(bx.const_usize(ty_size.bytes()), count);
-    let flags = if volatile { MemFlags::VOLATILE } else { MemFlags::empty() };
+    let mut flags = if volatile { MemFlags::VOLATILE } else { MemFlags::empty() };
+    // The copy intrinsics are how untyped memory gets relocated, so whatever the element type
+    // says, the bytes may hold capabilities.
+    flags |= MemFlags::PRESERVE_CHERI_TAGS;
     if allow_overlap {
         bx.memmove(dst, align, src, align, ty_size, flags);
     } else {
@@ -531,12 +534,14 @@ pub fn codegen_intrinsic_call(
 
                         let ty = substs.type_at(0);
                         if int_type_width_signed(ty, bx.tcx()).is_some() || ty.is_unsafe_ptr() {
+                            let layout = bx.layout_of(ty);
                             let mut ptr = args[0].immediate();
                             let mut val = args[1].immediate();
-                            if ty.is_unsafe_ptr() && !dl.is_cheri_purecap {
+                            // Capabilities stay capabilities, so that the result keeps the tag
+                            // and bounds of the value in memory.
+                            if ty.is_unsafe_ptr() && layout.ty_size == layout.val_size {
                                 // Some platforms do not support atomic operations on pointers,
                                 // so we cast to integer first.
-                                // TODO: This needs fixing for CHERI.
                                 // TODO: Get the correct address space from ptr.
                                 let ptr_llty = bx.type_ptr_to_ext(bx.type_isize(), dl.default_address_space);
                                 ptr = bx.pointercast(ptr, ptr_llty);
@@ -562,6 +567,17 @@ pub fn codegen_intrinsic_call(
                 bx.icmp(IntPredicate::IntEQ, a, b)
             }
 
+            sym::ptr_addr => {
+                let addr = bx.get_pointer_address(args[0].immediate());
+                bx.intcast(addr, llret_ty, false)
+            }
+
+            sym::ptr_with_addr => {
+                // This keeps the provenance of the original pointer, and on CHERI its tag, bounds and
+                // permissions.
+                bx.set_pointer_address(args[0].immediate(), args[1].immediate())
+            }
+
             sym::ptr_offset_from | sym::ptr_offset_from_unsigned => {
                 let ty = substs.type_at(0);
                 let pointee_size = bx.layout_of(ty).ty_size;
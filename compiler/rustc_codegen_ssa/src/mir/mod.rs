@@ -87,6 +87,10 @@ pub struct FunctionCx<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> {
 
     /// Caller location propagated if this function has `#[track_caller]`.
     caller_location: Option<OperandRef<'tcx, Bx::Value>>,
+
+    /// The locals that are assigned pointers cast from integers, whose uses are checked under
+    /// `-Z check-provenance-casts=runtime`.
+    provenance_cast_locals: BitSet<mir::Local>,
 }
 
 impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
@@ -178,6 +182,7 @@ pub fn codegen_mir<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
         debug_context,
         per_local_var_debug_info: None,
         caller_location: None,
+        provenance_cast_locals: cheri_checks::provenance_cast_locals(cx.tcx(), mir),
     };
 
     fx.per_local_var_debug_info = fx.compute_per_local_var_debug_info(&mut start_bx);
@@ -400,6 +405,7 @@ fn arg_local_refs<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
 
 mod analyze;
 mod block;
+mod cheri_checks;
 pub mod constant;
 pub mod coverageinfo;
 pub mod debuginfo;
@@ -98,7 +98,8 @@ pub fn from_const<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
                 OperandValue::Pair(a_llval, b_llval)
             }
             ConstValue::ByRef { alloc, offset } => {
-                return bx.load_operand(bx.from_const_alloc(layout, alloc, offset));
+                let op = bx.load_operand(bx.from_const_alloc(layout, alloc, offset));
+                return seal_const_vtable_pointer(bx, op);
             }
         };
 
@@ -242,6 +243,25 @@ pub fn extract_field<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
     }
 }
 
+/// Seals the vtable pointer of a trait object constant under `-Z cheri-sealed-vtables`. The
+/// constant is created at compile time, when the vtable pointer can't be sealed yet.
+fn seal_const_vtable_pointer<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
+    bx: &mut Bx,
+    op: OperandRef<'tcx, Bx::Value>,
+) -> OperandRef<'tcx, Bx::Value> {
+    if !bx.cx().sess().cheri_sealed_vtables() {
+        return op;
+    }
+    let is_trait_object = op.layout.ty.builtin_deref(true).map_or(false, |mt| mt.ty.is_trait());
+    match op.val {
+        OperandValue::Pair(data, vtable) if is_trait_object => {
+            let vtable = bx.seal_vtable_pointer(vtable);
+            OperandRef { val: OperandValue::Pair(data, vtable), layout: op.layout }
+        }
+        _ => op,
+    }
+}
+
 impl<'a, 'tcx, V: CodegenObject> OperandValue<V> {
     pub fn store<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
         self,
@@ -275,7 +295,7 @@ pub fn nontemporal_store<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
         self.store_with_flags(bx, dest, MemFlags::NONTEMPORAL);
     }
 
-    fn store_with_flags<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
+    pub fn store_with_flags<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
         self,
         bx: &mut Bx,
         dest: PlaceRef<'tcx, V>,
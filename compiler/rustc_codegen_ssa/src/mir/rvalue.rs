@@ -12,6 +12,7 @@
 use rustc_middle::ty::cast::{CastTy, IntTy};
 use rustc_middle::ty::layout::{HasTyCtxt, LayoutOf};
 use rustc_middle::ty::{self, adjustment::PointerCast, Instance, Ty, TyCtxt};
+use rustc_session::config::CheriSubobjectBounds;
 use rustc_span::source_map::{Span, DUMMY_SP};
 
 impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
@@ -359,7 +360,7 @@ pub fn codegen_rvalue_operand(
                         ty::TypeAndMut { ty, mutbl: bk.to_mutbl_lossy() },
                     )
                 };
-                self.codegen_place_to_pointer(bx, place, mk_ref)
+                self.codegen_place_to_pointer(bx, place, mk_ref, false)
             }
 
             mir::Rvalue::CopyForDeref(place) => {
@@ -370,7 +371,7 @@ pub fn codegen_rvalue_operand(
                 let mk_ptr = move |tcx: TyCtxt<'tcx>, ty: Ty<'tcx>| {
                     tcx.mk_ptr(ty::TypeAndMut { ty, mutbl: mutability })
                 };
-                self.codegen_place_to_pointer(bx, place, mk_ptr)
+                self.codegen_place_to_pointer(bx, place, mk_ptr, true)
             }
 
             mir::Rvalue::Len(place) => {
@@ -535,21 +536,65 @@ fn codegen_place_to_pointer(
         mut bx: Bx,
         place: mir::Place<'tcx>,
         mk_ptr_ty: impl FnOnce(TyCtxt<'tcx>, Ty<'tcx>) -> Ty<'tcx>,
+        raw: bool,
     ) -> (Bx, OperandRef<'tcx, Bx::Value>) {
         let cg_place = self.codegen_place(&mut bx, place.as_ref());
 
         let ty = cg_place.layout.ty;
 
+        let mut llval = cg_place.llval;
+        if cg_place.layout.is_sized()
+            && !cg_place.layout.is_zst()
+            && self.narrows_subobject_bounds(place, raw)
+        {
+            let size = bx.cx().const_usize(cg_place.layout.size.bytes());
+            llval = bx.narrow_pointer_bounds(llval, size);
+        }
+
         // Note: places are indirect, so storing the `llval` into the
         // destination effectively creates a reference.
         let val = if !bx.cx().type_has_metadata(ty) {
-            OperandValue::Immediate(cg_place.llval)
+            OperandValue::Immediate(llval)
         } else {
-            OperandValue::Pair(cg_place.llval, cg_place.llextra.unwrap())
+            OperandValue::Pair(llval, cg_place.llextra.unwrap())
         };
         (bx, OperandRef { val, layout: self.cx.layout_of(mk_ptr_ty(self.cx.tcx(), ty)) })
     }
 
+    /// Returns whether a borrow of `place` gets the bounds of just that place
+    /// under `-Z cheri-subobject-bounds`, rather than those of the enclosing
+    /// object.
+    fn narrows_subobject_bounds(&self, place: mir::Place<'tcx>, raw: bool) -> bool {
+        let tcx = self.cx.tcx();
+        let Some((base, elem)) = place.as_ref().last_projection() else { return false };
+        let subobject = matches!(
+            elem,
+            mir::ProjectionElem::Field(..)
+                | mir::ProjectionElem::Index(_)
+                | mir::ProjectionElem::ConstantIndex { .. }
+                | mir::ProjectionElem::Subslice { .. }
+        );
+        match tcx.sess.cheri_subobject_bounds() {
+            CheriSubobjectBounds::Off => false,
+            CheriSubobjectBounds::Aggressive => subobject,
+            // Raw pointers are what `container_of`-style code offsets back to
+            // the enclosing object, and so is the first field of a `repr(C)`
+            // type, which C code uses as a header. Leave those alone.
+            CheriSubobjectBounds::Safe => {
+                if raw || !subobject {
+                    return false;
+                }
+                let mir::ProjectionElem::Field(field, _) = elem else { return true };
+                let base_ty = mir::Place::ty_from(base.local, base.projection, self.mir, tcx);
+                let is_repr_c = match self.monomorphize(base_ty.ty).kind() {
+                    ty::Adt(def, _) => def.repr().c(),
+                    _ => false,
+                };
+                !(is_repr_c && field.index() == 0)
+            }
+        }
+    }
+
     pub fn codegen_scalar_binop(
         &mut self,
         bx: &mut Bx,
@@ -1,6 +1,7 @@
 use rustc_middle::mir;
 use rustc_middle::mir::NonDivergingIntrinsic;
 
+use super::cheri_checks::CheckedCode;
 use super::FunctionCx;
 use super::LocalRef;
 use crate::traits::BuilderMethods;
@@ -10,6 +11,8 @@ impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
     #[instrument(level = "debug", skip(self, bx))]
     pub fn codegen_statement(&mut self, mut bx: Bx, statement: &mir::Statement<'tcx>) -> Bx {
         self.set_debug_loc(&mut bx, statement.source_info);
+        self.codegen_cheri_access_checks(&mut bx, CheckedCode::Statement(statement));
+        self.codegen_cheri_provenance_cast_checks(&mut bx, CheckedCode::Statement(statement));
         match statement.kind {
             mir::StatementKind::Assign(box (ref place, ref rvalue)) => {
                 if let Some(index) = place.as_local() {
@@ -52,6 +52,8 @@
     ("bf16", None),
     // FEAT_BTI
     ("bti", None),
+    // Morello capability mode (purecap code generation)
+    ("c64", Some(sym::cheri_target_feature)),
     // FEAT_CRC
     ("crc", None),
     // FEAT_DIT
@@ -84,6 +86,8 @@
     ("lor", None),
     // FEAT_LSE
     ("lse", None),
+    // Morello capability instructions
+    ("morello", Some(sym::cheri_target_feature)),
     // FEAT_MTE
     ("mte", None),
     // FEAT_AdvSimd & FEAT_FP
@@ -232,11 +236,13 @@
     // tidy-alphabetical-start
     ("a", Some(sym::riscv_target_feature)),
     ("c", Some(sym::riscv_target_feature)),
+    ("cap-mode", Some(sym::cheri_target_feature)),
     ("d", Some(sym::riscv_target_feature)),
     ("e", Some(sym::riscv_target_feature)),
     ("f", Some(sym::riscv_target_feature)),
     ("m", Some(sym::riscv_target_feature)),
     ("v", Some(sym::riscv_target_feature)),
+    ("xcheri", Some(sym::cheri_target_feature)),
     ("zba", Some(sym::riscv_target_feature)),
     ("zbb", Some(sym::riscv_target_feature)),
     ("zbc", Some(sym::riscv_target_feature)),
@@ -299,7 +305,7 @@ pub fn all_known_features() -> impl Iterator<Item = (&'static str, Option<Symbol
 pub fn supported_target_features(sess: &Session) -> &'static [(&'static str, Option<Symbol>)] {
     match &*sess.target.arch {
         "arm" => ARM_ALLOWED_FEATURES,
-        "aarch64" => AARCH64_ALLOWED_FEATURES,
+        "aarch64" | "morello+c64" => AARCH64_ALLOWED_FEATURES,
         "x86" | "x86_64" => X86_ALLOWED_FEATURES,
         "hexagon" => HEXAGON_ALLOWED_FEATURES,
         "mips" | "mips64" => MIPS_ALLOWED_FEATURES,
@@ -313,7 +319,7 @@ pub fn supported_target_features(sess: &Session) -> &'static [(&'static str, Opt
 
 pub fn tied_target_features(sess: &Session) -> &'static [&'static [&'static str]] {
     match &*sess.target.arch {
-        "aarch64" => AARCH64_TIED_FEATURES,
+        "aarch64" | "morello+c64" => AARCH64_TIED_FEATURES,
         _ => &[],
     }
 }
@@ -231,6 +231,20 @@ fn cast_float_to_int(
     fn get_pointer_address(&mut self, ptr: Self::Value) -> Self::Value;
     fn set_pointer_address(&mut self, ptr: Self::Value, addr: Self::Value) -> Self::Value;
 
+    /// Seals the vtable pointer of a trait object under `-Z cheri-sealed-vtables`.
+    fn seal_vtable_pointer(&mut self, vtable: Self::Value) -> Self::Value {
+        vtable
+    }
+    /// Undoes [`seal_vtable_pointer`](Self::seal_vtable_pointer) before the vtable is read.
+    fn unseal_vtable_pointer(&mut self, vtable: Self::Value) -> Self::Value {
+        vtable
+    }
+    /// Narrows the bounds of a capability to the `size` bytes at its address, for
+    /// `-Z cheri-subobject-bounds`.
+    fn narrow_pointer_bounds(&mut self, ptr: Self::Value, _size: Self::Value) -> Self::Value {
+        ptr
+    }
+
     fn memcpy(
         &mut self,
         dst: Self::Value,
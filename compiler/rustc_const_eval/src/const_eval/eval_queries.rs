@@ -6,14 +6,19 @@
     RefTracking, StackPopCleanup,
 };
 
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir as hir;
 use rustc_hir::def::DefKind;
 use rustc_middle::mir;
-use rustc_middle::mir::interpret::ErrorHandled;
+use rustc_middle::mir::interpret::{
+    alloc_range, AllocId, ErrorHandled, GlobalAlloc, PointerArithmetic, Scalar,
+};
 use rustc_middle::mir::pretty::display_allocation;
 use rustc_middle::traits::Reveal;
 use rustc_middle::ty::layout::LayoutOf;
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, TyCtxt};
+use rustc_session::lint::builtin::CHERI_UNREPRESENTABLE_BOUNDS;
 use rustc_span::source_map::Span;
 use rustc_target::abi::{self, Abi};
 use std::borrow::Cow;
@@ -375,9 +380,131 @@ pub fn eval_to_allocation_raw_provider<'tcx>(
                     },
                 ))
             } else {
+                // Promoteds are used as immediates, or get globals that code points to directly
+                // rather than through a capability relocation.
+                if let Some(def_id) = def.did.as_local() && cid.promoted.is_none() {
+                    let lint_root = tcx.hir().local_def_id_to_hir_id(def_id);
+                    check_cheri_bounds(&ecx, alloc_id, lint_root, tcx.def_span(def.did));
+                }
                 // Convert to raw constant
                 Ok(ConstAlloc { alloc_id, ty: mplace.layout.ty })
             }
         }
     }
 }
+
+/// Lints the pointers in the final value of a constant or static, and in the allocations it
+/// points to, whose capability can't have the bounds of the allocation they point to on CHERI
+/// purecap targets. These capabilities are only made by the linker or the loader, which is too
+/// late to say which item they come from.
+fn check_cheri_bounds<'mir, 'tcx>(
+    ecx: &CompileTimeEvalContext<'mir, 'tcx>,
+    root: AllocId,
+    lint_root: hir::HirId,
+    span: Span,
+) {
+    let tcx = *ecx.tcx;
+    if !tcx.data_layout.is_cheri_purecap {
+        return;
+    }
+    let ptr_layout = tcx.data_layout.ptr_layout(None);
+
+    let mut todo = vec![root];
+    let mut seen = FxHashSet::default();
+    seen.insert(root);
+    while let Some(alloc_id) = todo.pop() {
+        // Other statics are checked when they are evaluated themselves.
+        let GlobalAlloc::Memory(alloc) = tcx.global_alloc(alloc_id) else { continue };
+        for &(offset, target) in alloc.inner().provenance().iter() {
+            if !seen.insert(target) {
+                continue;
+            }
+            let (size, align) = match tcx.global_alloc(target) {
+                GlobalAlloc::Memory(target_alloc) => {
+                    todo.push(target);
+                    (target_alloc.inner().size(), target_alloc.inner().align)
+                }
+                GlobalAlloc::Static(_) => {
+                    let (size, align, _) = ecx.get_alloc_info(target);
+                    (size, align)
+                }
+                // Functions and vtables get their capabilities from the code, not the data.
+                GlobalAlloc::Function(_) | GlobalAlloc::VTable(..) => continue,
+            };
+            let range = alloc_range(offset, ptr_layout.ty_size, ptr_layout.val_size);
+            let Ok(Scalar::Ptr(ptr, _)) = alloc.inner().read_scalar(&tcx, range, true) else {
+                continue;
+            };
+            let offset = ptr_layout.val_size.sign_extend(ptr.into_parts().1.bytes().into());
+            lint_unrepresentable_bounds(ecx, lint_root, span, size, align, offset as i128);
+        }
+    }
+}
+
+fn lint_unrepresentable_bounds<'mir, 'tcx>(
+    ecx: &CompileTimeEvalContext<'mir, 'tcx>,
+    lint_root: hir::HirId,
+    span: Span,
+    size: abi::Size,
+    align: abi::Align,
+    offset: i128,
+) {
+    let size = size.bytes();
+    let (Ok((length, mask)), Ok(slack)) =
+        (ecx.cheri_representable(size), ecx.cheri_representable_slack(size))
+    else {
+        return;
+    };
+    let required_align = (!mask & ecx.machine_usize_max()) + 1;
+    let inexact = length != size;
+    let misaligned = align.bytes() < required_align;
+    let distance = if offset < 0 {
+        offset.unsigned_abs()
+    } else {
+        (offset as u128).saturating_sub(size.into())
+    };
+    let too_far = distance > u128::from(slack);
+    if !inexact && !misaligned && !too_far {
+        return;
+    }
+
+    let def_id = lint_root.owner.to_def_id();
+    let kind = ecx.tcx.def_kind(def_id).descr(def_id);
+    ecx.tcx.struct_span_lint_hir(
+        CHERI_UNREPRESENTABLE_BOUNDS,
+        lint_root,
+        span,
+        format!(
+            "the capability of a pointer in this {kind} can't have the bounds of the allocation \
+             it points to"
+        ),
+        |lint| {
+            if inexact {
+                lint.note(&format!(
+                    "the bounds of the {size}-byte allocation are rounded up to {length} bytes"
+                ));
+            }
+            if misaligned {
+                let align = align.bytes();
+                let bytes = if align == 1 { "byte" } else { "bytes" };
+                lint.note(&format!(
+                    "the bounds must be aligned to {required_align} bytes, but the allocation is \
+                     only aligned to {align} {bytes}"
+                ));
+            }
+            if inexact || misaligned {
+                lint.help(&format!(
+                    "pad the allocation to {length} bytes and align it to {required_align} bytes, \
+                     for example with a `#[repr(C, align({required_align}))]` wrapper"
+                ));
+            }
+            if too_far {
+                lint.note(&format!(
+                    "the pointer is {distance} bytes outside of the {size}-byte allocation, but a \
+                     capability to it can only be moved {slack} bytes outside of its bounds"
+                ));
+            }
+            lint
+        },
+    );
+}
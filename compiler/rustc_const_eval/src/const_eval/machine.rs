@@ -10,6 +10,7 @@
 
 use rustc_ast::Mutability;
 use rustc_hir::def_id::DefId;
+use rustc_middle::mir::interpret::GlobalAlloc;
 use rustc_middle::mir::AssertMessage;
 use rustc_session::Limit;
 use rustc_span::symbol::{sym, Symbol};
@@ -17,8 +18,8 @@
 use rustc_target::spec::abi::Abi as CallAbi;
 
 use crate::interpret::{
-    self, compile_time_machine, AllocId, ConstAllocation, Frame, ImmTy, InterpCx, InterpResult,
-    OpTy, PlaceTy, Pointer, Scalar, StackPopUnwind,
+    self, compile_time_machine, AllocId, AllocKind, ConstAllocation, Frame, ImmTy, InterpCx,
+    InterpResult, OpTy, PlaceTy, Pointer, Scalar, StackPopUnwind,
 };
 
 use super::error::*;
@@ -213,15 +214,50 @@ fn guaranteed_cmp(&mut self, a: Scalar, b: Scalar) -> InterpResult<'tcx, u8> {
             }
             // Equality with integers can never be known for sure.
             (Scalar::Int { .. }, Scalar::Ptr(..)) | (Scalar::Ptr(..), Scalar::Int { .. }) => 2,
-            // FIXME: return a `1` for when both sides are the same pointer, *except* that
-            // some things (like functions and vtables) do not have stable addresses
-            // so we need to be careful around them (see e.g. #73722).
-            // FIXME: return `0` for at least some comparisons where we can reliably
-            // determine the result of runtime inequality tests at compile-time.
-            // Examples include comparison of addresses in different static items.
-            (Scalar::Ptr(..), Scalar::Ptr(..)) => 2,
+            // The runtime comparison only looks at addresses, also on CHERI where the pointers
+            // are capabilities, so this only needs to know where the pointers will end up.
+            (Scalar::Ptr(..), Scalar::Ptr(..)) => {
+                let a = a.to_pointer(self)?;
+                let b = b.to_pointer(self)?;
+                let (Ok((a_id, a_offset, _)), Ok((b_id, b_offset, _))) =
+                    (self.ptr_try_get_alloc_id(a), self.ptr_try_get_alloc_id(b))
+                else {
+                    return Ok(2);
+                };
+                let (a_size, _, a_kind) = self.get_alloc_info(a_id);
+                let (b_size, _, b_kind) = self.get_alloc_info(b_id);
+                if !matches!((a_kind, b_kind), (AllocKind::LiveData, AllocKind::LiveData)) {
+                    // Functions and vtables do not have stable addresses, the same one may
+                    // end up at several places (see e.g. #73722).
+                    2
+                } else if a_id == b_id {
+                    // Both pointers are relative to the same base address, so they are equal
+                    // exactly when their offsets are.
+                    if a_offset == b_offset { 1 } else { 0 }
+                } else if self.is_rust_static_alloc(a_id)
+                    && self.is_rust_static_alloc(b_id)
+                    && a_offset < a_size
+                    && b_offset < b_size
+                {
+                    // Distinct statics never overlap, so pointers strictly inside each of them
+                    // are different. One-past-the-end pointers may still coincide with the start
+                    // of the other static, and other allocations may be deduplicated.
+                    0
+                } else {
+                    2
+                }
+            }
         })
     }
+
+    /// Returns whether `id` is a static defined in Rust. Several `extern` statics may name the
+    /// same symbol, e.g. through `#[link_name]`, so these can't be told apart.
+    fn is_rust_static_alloc(&self, id: AllocId) -> bool {
+        matches!(
+            self.tcx.try_get_global_alloc(id),
+            Some(GlobalAlloc::Static(def_id)) if !self.tcx.is_foreign_item(def_id)
+        )
+    }
 }
 
 impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir, 'tcx> {
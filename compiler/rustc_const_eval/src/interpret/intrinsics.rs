@@ -20,8 +20,8 @@
 use rustc_target::abi::{Abi, Align, Primitive, Size};
 
 use super::{
-    util::ensure_monomorphic_enough, CheckInAllocMsg, ImmTy, InterpCx, Machine, OpTy, PlaceTy,
-    Pointer,
+    util::ensure_monomorphic_enough, AllocKind, CheckInAllocMsg, ImmTy, InterpCx, Machine, OpTy,
+    PlaceTy, Pointer,
 };
 
 mod caller_location;
@@ -43,6 +43,33 @@ fn numeric_intrinsic<Prov>(name: Symbol, bits: u128, kind: Primitive) -> Scalar<
     Scalar::from_uint(bits_out, size)
 }
 
+/// Computes the length that a capability of `len` bytes gets once its bounds are rounded up to be
+/// representable by CHERI Concentrate bounds compression with a bottom bound mantissa of
+/// `mantissa_width` bits, and the mask its base must be aligned to for that. `max` is the largest
+/// address.
+pub fn cheri_concentrate_bounds(len: u64, mantissa_width: u32, max: u64) -> (u64, u64) {
+    // Lengths below a quarter of the range of the mantissa are always exact; larger ones lose
+    // `msb - (mantissa_width - 2)` bits to the exponent, plus three more bits that the exponent
+    // itself is stored in.
+    if len < 1 << (mantissa_width - 2) {
+        return (len, max);
+    }
+    let msb = 63 - len.leading_zeros();
+    let round_up = |shift: u32| {
+        let unit = 1u128 << shift;
+        (u128::from(len) + unit - 1) & !(unit - 1)
+    };
+    let mut shift = msb + 5 - mantissa_width;
+    let mut rounded = round_up(shift);
+    if rounded >> (msb + 1) != 0 {
+        // Rounding up carried into a new top bit, which no longer fits the mantissa.
+        shift += 1;
+        rounded = round_up(shift);
+    }
+    let mask = !((1u64 << shift) - 1) & max;
+    (u64::try_from(rounded).unwrap_or(0) & max, mask)
+}
+
 /// Directly returns an `Allocation` containing an absolute path representation of the given type.
 pub(crate) fn alloc_type_name<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> ConstAllocation<'tcx> {
     let path = crate::util::type_name(tcx, ty);
@@ -317,6 +344,44 @@ pub fn emulate_intrinsic(
                 let offset_ptr = ptr.wrapping_signed_offset(offset_bytes, self);
                 self.write_pointer(offset_ptr, dest)?;
             }
+            sym::ptr_addr => {
+                // Behaves like a pointer-to-integer transmute.
+                let ptr = self.read_scalar(&args[0])?;
+                self.write_scalar(ptr, dest)?;
+            }
+            sym::ptr_with_addr => {
+                // Behaves like a `wrapping_offset` to the new address.
+                let ptr = self.read_pointer(&args[0])?;
+                let addr = self.read_scalar(&args[1])?.to_machine_usize(self)?;
+                let offset = (addr as i64).wrapping_sub(ptr.addr().bytes() as i64);
+                self.write_pointer(ptr.wrapping_signed_offset(offset, self), dest)?;
+            }
+            sym::cheri_cap_length_get => {
+                let ptr = self.read_pointer(&args[0])?;
+                let len = match self.ptr_try_get_alloc_id(ptr) {
+                    Ok((alloc_id, _, _)) if self.tcx.data_layout.is_cheri_purecap => {
+                        let (size, _align, kind) = self.get_alloc_info(alloc_id);
+                        if !matches!(kind, AllocKind::LiveData) {
+                            throw_unsup_format!(
+                                "`{intrinsic_name}` is only supported on pointers to data"
+                            );
+                        }
+                        // The bounds of the global emitted for the allocation cover it exactly,
+                        // rounded up to be representable.
+                        self.cheri_representable(size.bytes())?.0
+                    }
+                    // Without provenance there are no bounds to narrow the capability.
+                    _ => self.machine_usize_max(),
+                };
+                self.write_scalar(Scalar::from_machine_usize(len, self), dest)?;
+            }
+            sym::cheri_representable_length | sym::cheri_representable_alignment_mask => {
+                let len = self.read_scalar(&args[0])?.to_machine_usize(self)?;
+                let (length, mask) = self.cheri_representable(len)?;
+                let result =
+                    if intrinsic_name == sym::cheri_representable_length { length } else { mask };
+                self.write_scalar(Scalar::from_machine_usize(result, self), dest)?;
+            }
             sym::ptr_offset_from | sym::ptr_offset_from_unsigned => {
                 let a = self.read_pointer(&args[0])?;
                 let b = self.read_pointer(&args[1])?;
@@ -658,6 +723,44 @@ pub fn ptr_offset_inbounds(
         Ok(offset_ptr)
     }
 
+    /// Computes the length that a capability of `len` bytes gets once its bounds are rounded up
+    /// to be representable, and the mask its base must be aligned to for that, the way the CHERI
+    /// Concentrate bounds compression of the target does.
+    ///
+    /// On targets without capabilities every length is representable.
+    pub(crate) fn cheri_representable(&self, len: u64) -> InterpResult<'tcx, (u64, u64)> {
+        let max = self.machine_usize_max();
+        if !self.tcx.data_layout.is_cheri_purecap {
+            return Ok((len, max));
+        }
+        Ok(cheri_concentrate_bounds(len, self.cheri_mantissa_width()?, max))
+    }
+
+    /// Computes how many bytes a pointer with a capability for `len` bytes can always be moved
+    /// below its base or above its top, however the bounds are aligned, before its address can
+    /// no longer be represented together with the bounds and the capability loses its tag.
+    ///
+    /// This is an eighth of the representable region of CHERI Concentrate, which is as large as
+    /// the range of the mantissa at the exponent that `len` needs.
+    pub(crate) fn cheri_representable_slack(&self, len: u64) -> InterpResult<'tcx, u64> {
+        let (_, mask) = self.cheri_representable(len)?;
+        let shift = (!mask & self.machine_usize_max()).count_ones();
+        let region = 1u128 << (shift + self.cheri_mantissa_width()?);
+        Ok(u64::try_from(region >> 3).unwrap_or(u64::MAX))
+    }
+
+    /// The width of the mantissa of the bottom bound in the capability bounds encoding of the
+    /// target.
+    fn cheri_mantissa_width(&self) -> InterpResult<'tcx, u32> {
+        let cap_bits = self.tcx.data_layout.ptr_layout(None).ty_size.bits();
+        Ok(match (&*self.tcx.sess.target.arch, cap_bits) {
+            ("morello+c64", _) => 16,
+            (_, 128) => 14,
+            (_, 64) => 8,
+            (arch, _) => throw_unsup_format!("capability bounds encoding of `{arch}` is unknown"),
+        })
+    }
+
     /// Copy `count*size_of::<T>()` many bytes from `*src` to `*dst`.
     pub(crate) fn copy_intrinsic(
         &mut self,
@@ -241,6 +241,16 @@ fn before_terminator(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx
         Ok(())
     }
 
+    /// Called before a function is called through the function pointer `fn_ptr`, before it is
+    /// resolved to the function it points to.
+    #[inline]
+    fn before_indirect_call(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _fn_ptr: Pointer<Option<Self::Provenance>>,
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
     /// Called before a global allocation is accessed.
     /// `def_id` is `Some` if this is the "lazy" allocation of a static.
     #[inline]
@@ -918,7 +918,7 @@ pub fn write_scalar(&mut self, range: AllocRange, val: Scalar<Prov>) -> InterpRe
 
     /// `offset` is relative to this allocation reference, not the base of the allocation.
     pub fn write_ptr_sized(&mut self, offset: Size, val: Scalar<Prov>) -> InterpResult<'tcx> {
-        // TODO: More complexity needed here. val_size vs ty_size.
+        // A pointer takes up its whole type size, but only the address is its value.
         self.write_scalar(
             alloc_range(
                 offset,
@@ -961,7 +961,7 @@ pub fn read_integer(&self, range: AllocRange) -> InterpResult<'tcx, Scalar<Prov>
 
     /// `offset` is relative to this allocation reference, not the base of the allocation.
     pub fn read_pointer(&self, offset: Size) -> InterpResult<'tcx, Scalar<Prov>> {
-        // TODO: More complexity needed here. val_size vs ty_size.
+        // A pointer takes up its whole type size, but only the address is its value.
         self.read_scalar(
             alloc_range(
                 offset,
@@ -1100,16 +1100,19 @@ pub fn mem_copy_repeatedly(
         };
 
         // Checks provenance edges on the src, which needs to happen before
-        // `prepare_provenance_copy`.
-        if src_alloc
-            .range_has_provenance(&tcx, alloc_range(src_range.start, Size::ZERO, Size::ZERO))
-        {
-            throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.start)));
-        }
-        if src_alloc
-            .range_has_provenance(&tcx, alloc_range(src_range.end(), Size::ZERO, Size::ZERO))
-        {
-            throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.end())));
+        // `prepare_provenance_copy`. On CHERI purecap targets copying part of a capability just
+        // clears its tag, which `prepare_provenance_copy` takes care of.
+        if !tcx.data_layout.is_cheri_purecap {
+            if src_alloc
+                .range_has_provenance(&tcx, alloc_range(src_range.start, Size::ZERO, Size::ZERO))
+            {
+                throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.start)));
+            }
+            if src_alloc
+                .range_has_provenance(&tcx, alloc_range(src_range.end(), Size::ZERO, Size::ZERO))
+            {
+                throw_unsup!(PartialPointerCopy(Pointer::new(src_alloc_id, src_range.end())));
+            }
         }
         let src_bytes = src_alloc.get_bytes_unchecked(src_range).as_ptr(); // raw ptr, so we can also get a ptr to the destination allocation
         // first copy the provenance to a temporary buffer, because
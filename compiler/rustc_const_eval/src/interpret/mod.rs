@@ -23,6 +23,7 @@
     Frame, FrameInfo, InterpCx, LocalState, LocalValue, StackPopCleanup, StackPopUnwind,
 };
 pub use self::intern::{intern_const_alloc_recursive, InternKind};
+pub use self::intrinsics::cheri_concentrate_bounds;
 pub use self::machine::{compile_time_machine, AllocMap, Machine, MayLeak, StackPopJump};
 pub use self::memory::{AllocKind, AllocRef, AllocRefMut, FnVal, Memory, MemoryKind};
 pub use self::operand::{ImmTy, Immediate, OpTy, Operand};
@@ -653,8 +653,7 @@ fn copy_op_no_validate(
                 if dest.layout.is_unsized() {
                     throw_inval!(SizeOfUnsizedType(dest.layout.ty));
                 }
-                // TODO: Should this really be val_size? It makes sense as we are checking if we
-                // can write the value of `src` to `dest`.
+                // Only the value has to fit; the rest of a capability's type size is its metadata.
                 assert_eq!(src.layout.val_size, dest.layout.val_size);
                 // Yay, we got a value that we can write directly.
                 return if layout_compat {
@@ -11,3 +11,11 @@ errors_target_inconsistent_architecture = inconsistent target specification: "da
 errors_target_inconsistent_pointer_width = inconsistent target specification: "data-layout" claims pointers are {$pointer_size}-bit, while "target-pointer-width" is `{$target}`
 
 errors_target_invalid_bits_size = {$err}
+
+errors_target_missing_pointer_layout = inconsistent target specification: "data-layout" places `{$cause}` in address space {$addr_space}, but does not declare a pointer layout for it
+
+errors_target_inconsistent_pointer_type_width = inconsistent target specification: "data-layout" claims pointer types are {$pointer_size}-bit, while "target-pointer-type-width" is `{$target}`
+
+errors_target_missing_pointer_type_width = inconsistent target specification: "data-layout" declares {$pointer_size}-bit capability pointers, but "target-pointer-type-width" is not set
+
+errors_target_inconsistent_capability_address_space = inconsistent target specification: "data-layout" places `{$cause}` in address space {$addr_space}, while capabilities use address space {$default}
@@ -132,6 +132,49 @@ lint_noop_method_call = call to `.{$method}()` on a reference in this situation
 lint_pass_by_value = passing `{$ty}` by reference
     .suggestion = try passing by value
 
+lint_purecap_exposed_provenance = `{$name}` cannot recover a valid capability on a purecap target
+    .note = there is no ambient capability to derive the pointer from, so the returned pointer has its tag cleared and any access through it traps
+    .help = derive the pointer from an existing one with `with_addr` instead
+
+lint_cheri_int_to_ptr_cast = casting an integer to a pointer cannot produce a valid capability on a purecap target
+    .note = an integer only holds an address, so the pointer has its tag cleared and any access through it traps
+    .suggestion = offset the address of the original pointer instead
+    .help = derive the pointer from an existing one with `with_addr` instead
+
+lint_cheri_ptr_to_int_cast = casting a pointer to an integer keeps only its address on a purecap target
+    .note = the integer doesn't hold the capability of the pointer, so it can't be turned back into a usable pointer
+    .addr_suggestion = use `addr` to get the address explicitly
+    .expose_suggestion = use `expose_addr`, which is meant for casting back
+
+lint_cheri_pointer_in_integer_atomic = storing a pointer in an `{$atomic}` drops its capability on a purecap target
+    .note = the pointer loaded back from the atomic has its tag cleared and any access through it traps
+    .help = use an `AtomicPtr` to share the pointer, or an `AtomicAddr` if only its address is needed
+
+lint_cheri_pointer_from_integer_atomic = casting the value of an `{$atomic}` to a pointer cannot produce a valid capability on a purecap target
+    .note = the atomic only holds the address of the pointer that was stored in it
+    .help = use an `AtomicPtr` to share the pointer
+
+lint_usize_pointer_size_assumption = this assumes that `{$address_ty}` and `{$ptr_ty}` have the same size
+    .differ_note = on this target `{$address_ty}` is {$address_size} bytes and `{$ptr_ty}` is {$ptr_size} bytes
+    .help = use `size_of::<{$ptr_ty}>()` for the size of a pointer, and `size_of::<{$address_ty}>()` only for the size of an address
+
+lint_cheri_box_into_integer = turning a `Box` into an integer handle drops its capability on a purecap target
+    .note = a `Box` rebuilt from the handle has its tag cleared, and using or dropping it traps
+    .help = return the pointer itself, or a `Sealed` handle from `core::arch::cheri` if it is given to untrusted code
+
+lint_cheri_box_from_integer = rebuilding a `Box` from an integer handle cannot produce a valid capability on a purecap target
+    .note = a `Box` rebuilt from the handle has its tag cleared, and using or dropping it traps
+    .help = take the pointer itself, or a `Sealed` handle from `core::arch::cheri` if it is given to untrusted code
+
+lint_cheri_pointer_address_derive = `{$ty}` derives {$traits} from the address of this pointer
+    .note = on a purecap target the address alone isn't enough to rebuild the pointer
+    .serialized_note = `{$ty}` is serialized here
+    .help = implement these traits by hand on the `addr()` of the pointer to make explicit that only its address is used
+
+lint_cheri_unqualified_fn_pointer = this function is passed to `{$callee}` as a plain function pointer
+    .note = the callee receives only its {$bits}-bit address, not a capability to it
+    .help = if the C parameter is a `__capability` function pointer, declare it with a `#[cheri_capability]` type in the `extern` block
+
 lint_redundant_semicolons =
     unnecessary trailing {$multiple ->
         [true] semicolons
@@ -226,6 +269,22 @@ lint_improper_ctypes_enum_phantomdata = this enum contains a PhantomData field
 
 lint_improper_ctypes_struct_zst = this struct contains only zero-sized fields
 
+lint_cheri_capability_union =
+    `extern` {$desc} uses union `{$ty}`, which overlaps a capability with other data
+    .note = writing a field that isn't a capability clears the overlapping capability's tag
+    .raw_bits_note = reading such a field after writing the capability gives the bits of the capability
+    .defined_note = the union is defined here
+
+lint_cheri_integer_intptr =
+    `extern` {$desc} uses `{$name}` as `{$ty}`, which can't hold the capability that C's `{$name}` holds
+    .note = on purecap targets `intptr_t` and `uintptr_t` are capabilities, twice as wide as `{$ty}`
+    .help = use a pointer type such as `*mut c_void` instead
+
+lint_cheri_unqualified_pointer =
+    `extern` {$desc} passes a plain pointer alongside capabilities
+    .note = on hybrid targets a plain pointer is an address relative to the default data capability, not a capability
+    .help = if the C declaration qualifies this pointer with `__capability`, use a `#[cheri_capability]` type
+
 lint_improper_ctypes_array_reason = passing raw arrays by value is not FFI-safe
 lint_improper_ctypes_array_help = consider passing a pointer to the array
 
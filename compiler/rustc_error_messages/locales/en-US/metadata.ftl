@@ -230,6 +230,10 @@ metadata_found_crate_versions =
 metadata_no_crate_with_triple =
     couldn't find crate `{$crate_name}` with expected target triple {$locator_triple}{$add_info}
 
+metadata_no_crate_with_capability_abi =
+    couldn't find crate `{$crate_name}` compiled for the {$locator_capability_abi} capability ABI{$add_info}
+    .help = purecap crates can't be linked with crates using another capability ABI; recompile `{$crate_name}` with the same target and CHERI target features
+
 metadata_found_staticlib =
     found staticlib `{$crate_name}` instead of rlib or dylib{$add_info}
     .help = please recompile that crate using --crate-type lib
@@ -218,6 +218,18 @@ passes_rustc_layout_scalar_valid_range_not_struct =
 passes_rustc_layout_scalar_valid_range_arg =
     expected exactly one integer literal argument
 
+passes_cheri_capability_not_struct =
+    `#[cheri_capability]` should be applied to a struct
+    .label = not a struct
+
+passes_cheri_capability_non_phantom_field =
+    `#[cheri_capability]` structs may only have `PhantomData` fields
+    .label = this field is not `PhantomData`
+
+passes_cheri_capability_unsupported_target =
+    `#[cheri_capability]` is not supported on this target
+    .note = the target's data layout does not declare an address space for capabilities
+
 passes_rustc_legacy_const_generics_only =
     #[rustc_legacy_const_generics] functions must only have const generics
     .label = non-const generic parameter
@@ -490,6 +502,15 @@ passes_naked_functions_must_use_noreturn =
     asm in naked functions must use `noreturn` option
     .suggestion = consider specifying that the asm block is responsible for returning from the function
 
+passes_naked_functions_purecap_stack_pointer =
+    naked functions on purecap targets must use `csp` rather than `{$reg}`
+    .note = `{$reg}` is only the address of the stack capability, so using it drops the bounds and permissions of the stack
+
+passes_naked_functions_purecap_branch =
+    naked functions on purecap targets must branch through capability registers
+    .note = `{$insn} {$reg}` jumps to an integer address and leaves capability mode
+    .help = use `{$insn} {$suggestion}` instead
+
 passes_attr_only_on_main =
     `{$attr}` attribute can only be used on `fn main()`
 
@@ -43,6 +43,10 @@ session_target_stack_protector_not_supported = `-Z stack-protector={$stack_prote
 
 session_split_debuginfo_unstable_platform = `-Csplit-debuginfo={$debuginfo}` is unstable on this platform
 
+session_branch_protection_purecap = `-Zbranch-protection` is not supported on Morello purecap targets
+    .note = return addresses and function pointers are sentries, sealed capabilities that can only be branched to at their entry point, so they can't be forged or redirected
+    .help = PAC would have to sign the address bits of the capability, which clears its tag, and Morello doesn't implement BTI
+
 session_file_is_not_writeable = output file {$file} is not writeable -- check its permissions
 
 session_crate_name_does_not_match = `--crate-name` and `#[crate_name]` are required to match, but `{$s}` != `{$name}`
@@ -45,3 +45,9 @@ ty_utils_control_flow_not_supported = control flow is not supported in generic c
 ty_utils_inline_asm_not_supported = assembly is not supported in generic constants
 
 ty_utils_operation_not_supported = unsupported operation in generic constant
+
+ty_utils_packed_capability = `{$ty}` cannot be packed because it holds a capability
+    .label = this field of type `{$field_ty}` holds a capability, which needs {$capability_align}-byte alignment but would only be {$pack}-byte aligned
+    .note = a capability that isn't aligned to its size can't be loaded or stored with its tag
+    .help = use `#[repr(C)]` and add explicit padding fields to control the layout instead
+    .address_help = or, if only the address is needed, store it in a `usize` field
@@ -8,7 +8,9 @@
 use rustc_span::edition::Edition;
 use rustc_span::symbol::{Ident, MacroRulesNormalizedIdent, Symbol};
 use rustc_target::abi::TargetDataLayoutErrors;
-use rustc_target::spec::{PanicStrategy, SplitDebuginfo, StackProtector, TargetTriple};
+use rustc_target::spec::{
+    CapabilityAbi, PanicStrategy, SplitDebuginfo, StackProtector, TargetTriple,
+};
 use std::borrow::Cow;
 use std::fmt;
 use std::num::ParseIntError;
@@ -127,6 +129,12 @@ fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
     }
 }
 
+impl IntoDiagnosticArg for CapabilityAbi {
+    fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
+        DiagnosticArgValue::Str(Cow::Owned(self.desc().to_string()))
+    }
+}
+
 impl IntoDiagnosticArg for hir::ConstContext {
     fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
         DiagnosticArgValue::Str(Cow::Borrowed(match self {
@@ -217,6 +225,35 @@ fn into_diagnostic(self, handler: &Handler) -> DiagnosticBuilder<'_, !> {
                 diag.set_arg("err", err);
                 diag
             }
+            TargetDataLayoutErrors::MissingPointerLayout { addr_space, cause } => {
+                diag = handler.struct_fatal(fluent::errors_target_missing_pointer_layout);
+                diag.set_arg("addr_space", addr_space);
+                diag.set_arg("cause", cause);
+                diag
+            }
+            TargetDataLayoutErrors::InconsistentTargetPointerTypeWidth { pointer_size, target } => {
+                diag = handler.struct_fatal(fluent::errors_target_inconsistent_pointer_type_width);
+                diag.set_arg("pointer_size", pointer_size);
+                diag.set_arg("target", target);
+                diag
+            }
+            TargetDataLayoutErrors::MissingTargetPointerTypeWidth { pointer_size } => {
+                diag = handler.struct_fatal(fluent::errors_target_missing_pointer_type_width);
+                diag.set_arg("pointer_size", pointer_size);
+                diag
+            }
+            TargetDataLayoutErrors::InconsistentCapabilityAddressSpace {
+                addr_space,
+                cause,
+                default,
+            } => {
+                diag = handler
+                    .struct_fatal(fluent::errors_target_inconsistent_capability_address_space);
+                diag.set_arg("addr_space", addr_space);
+                diag.set_arg("cause", cause);
+                diag.set_arg("default", default);
+                diag
+            }
         }
     }
 }
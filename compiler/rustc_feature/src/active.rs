@@ -250,6 +250,7 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, arm_target_feature, "1.27.0", Some(44839), None),
     (active, avx512_target_feature, "1.27.0", Some(44839), None),
     (active, bpf_target_feature, "1.54.0", Some(44839), None),
+    (active, cheri_target_feature, "CURRENT_RUSTC_VERSION", Some(44839), None),
     (active, cmpxchg16b_target_feature, "1.32.0", Some(44839), None),
     (active, ermsb_target_feature, "1.49.0", Some(44839), None),
     (active, f16c_target_feature, "1.36.0", Some(44839), None),
@@ -316,6 +317,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, c_variadic, "1.34.0", Some(44930), None),
     /// Allows capturing disjoint fields in a closure/generator (RFC 2229).
     (incomplete, capture_disjoint_fields, "1.49.0", Some(53488), None),
+    /// Allows `#[cheri_capability]` to declare capability pointer types on hybrid CHERI targets.
+    (active, cheri_capability, "CURRENT_RUSTC_VERSION", None, None),
     /// Allows the use of `#[cfg(sanitize = "option")]`; set when -Zsanitizer is used.
     (active, cfg_sanitize, "1.41.0", Some(39699), None),
     /// Allows `cfg(target_abi = "...")`.
@@ -472,6 +472,10 @@ pub struct BuiltinAttribute {
         cmse_nonsecure_entry, Normal, template!(Word), WarnFollowing,
         experimental!(cmse_nonsecure_entry)
     ),
+    gated!(
+        cheri_capability, Normal, template!(Word), WarnFollowing,
+        experimental!(cheri_capability)
+    ),
     // RFC 2632
     gated!(
         const_trait, Normal, template!(Word), WarnFollowing, const_trait_impl,
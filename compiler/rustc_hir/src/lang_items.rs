@@ -232,6 +232,11 @@ pub fn extract(attrs: &[ast::Attribute]) -> Option<(Symbol, Span)> {
     PanicLocation,           sym::panic_location,      panic_location,             Target::Struct,         GenericRequirement::None;
     PanicImpl,               sym::panic_impl,          panic_impl,                 Target::Fn,             GenericRequirement::None;
     PanicNoUnwind,           sym::panic_no_unwind,     panic_no_unwind,            Target::Fn,             GenericRequirement::Exact(0);
+    /// Capability check inserted before raw pointer accesses by `-Z sanitizer=cheri`.
+    CheriCheckAccess,        sym::cheri_check_access,  cheri_check_access_fn,      Target::Fn,             GenericRequirement::Exact(0);
+    /// Capability check inserted before accesses through pointers cast from integers by
+    /// `-Z check-provenance-casts=runtime`.
+    CheriCheckProvenanceCast, sym::cheri_check_provenance_cast, cheri_check_provenance_cast_fn, Target::Fn, GenericRequirement::Exact(0);
     /// libstd panic entry point. Necessary for const eval to be able to catch it
     BeginPanic,              sym::begin_panic,         begin_panic_fn,             Target::Fn,             GenericRequirement::None;
 
@@ -100,6 +100,12 @@ pub fn intrinsic_operation_unsafety(tcx: TyCtxt<'_>, intrinsic_id: DefId) -> hir
         | sym::likely
         | sym::unlikely
         | sym::ptr_guaranteed_cmp
+        | sym::ptr_eq_exact
+        | sym::ptr_addr
+        | sym::ptr_with_addr
+        | sym::cheri_cap_length_get
+        | sym::cheri_representable_length
+        | sym::cheri_representable_alignment_mask
         | sym::minnumf32
         | sym::minnumf64
         | sym::maxnumf32
@@ -321,6 +327,22 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 (1, vec![tcx.mk_imm_ptr(param(0)), tcx.mk_imm_ptr(param(0))], tcx.types.u8)
             }
 
+            sym::ptr_addr => (1, vec![tcx.mk_imm_ptr(param(0))], tcx.types.usize),
+            sym::ptr_with_addr => (
+                1,
+                vec![tcx.mk_imm_ptr(param(0)), tcx.types.usize],
+                tcx.mk_imm_ptr(param(0)),
+            ),
+            sym::cheri_cap_length_get => (1, vec![tcx.mk_imm_ptr(param(0))], tcx.types.usize),
+            sym::cheri_cap_build => (1, vec![tcx.types.usize, tcx.types.usize], param(0)),
+            sym::cheri_representable_length | sym::cheri_representable_alignment_mask => {
+                (0, vec![tcx.types.usize], tcx.types.usize)
+            }
+
+            sym::ptr_eq_exact => {
+                (1, vec![tcx.mk_imm_ptr(param(0)), tcx.mk_imm_ptr(param(0))], tcx.types.bool)
+            }
+
             sym::const_allocate => {
                 (0, vec![tcx.types.usize, tcx.types.usize], tcx.mk_mut_ptr(tcx.types.u8))
             }
@@ -62,6 +62,12 @@ fn check_asm_operand_type(
             64 => InlineAsmType::I64,
             _ => unreachable!(),
         };
+        // On CHERI purecap targets pointers are capabilities rather than
+        // integers, so they can only be passed in capability registers.
+        let dl = &self.tcx.data_layout;
+        let asm_ty_cap =
+            InlineAsmType::Cap(dl.ptr_layout(dl.capability_address_space()).ty_size.bytes());
+        let asm_ty_ptr = if dl.is_cheri_purecap { asm_ty_cap } else { asm_ty_isize };
 
         let asm_ty = match *ty.kind() {
             // `!` is allowed for input but not for output (issue #87802)
@@ -75,10 +81,11 @@ fn check_asm_operand_type(
             ty::Int(IntTy::Isize) | ty::Uint(UintTy::Usize) => Some(asm_ty_isize),
             ty::Float(FloatTy::F32) => Some(InlineAsmType::F32),
             ty::Float(FloatTy::F64) => Some(InlineAsmType::F64),
-            ty::FnPtr(_) => Some(asm_ty_isize),
+            ty::FnPtr(_) => Some(asm_ty_ptr),
             ty::RawPtr(ty::TypeAndMut { ty, mutbl: _ }) if self.is_thin_ptr_ty(ty) => {
-                Some(asm_ty_isize)
+                Some(asm_ty_ptr)
             }
+            ty::Adt(adt, _) if adt.is_cheri_capability() => Some(asm_ty_cap),
             ty::Adt(adt, substs) if adt.repr().simd() => {
                 let fields = &adt.non_enum_variant().fields;
                 let elem_ty = fields[0].ty(self.tcx, substs);
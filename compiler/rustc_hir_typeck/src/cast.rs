@@ -31,7 +31,9 @@
 use super::FnCtxt;
 
 use crate::type_error_struct;
-use rustc_errors::{struct_span_err, Applicability, DelayDm, DiagnosticBuilder, ErrorGuaranteed};
+use rustc_errors::{
+    struct_span_err, Applicability, DelayDm, Diagnostic, DiagnosticBuilder, ErrorGuaranteed,
+};
 use rustc_hir as hir;
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty::adjustment::AllowTwoPhase;
@@ -44,6 +46,7 @@
 use rustc_span::def_id::{DefId, LOCAL_CRATE};
 use rustc_span::symbol::sym;
 use rustc_span::Span;
+use rustc_target::spec::CapabilityAbi;
 use rustc_trait_selection::infer::InferCtxtExt;
 use rustc_trait_selection::traits::error_reporting::report_object_safety_error;
 
@@ -274,6 +277,7 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                         }
                     ));
                 }
+                self.note_capability_representation(fcx, &mut err);
                 err.emit();
             }
             CastError::NeedViaInt => {
@@ -522,6 +526,7 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                 } else {
                     err.span_label(self.span, "invalid cast");
                 }
+                self.note_capability_representation(fcx, &mut err);
                 err.emit();
             }
             CastError::SizedUnsizedCast => {
@@ -564,6 +569,7 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                         then using `core::ptr::from_raw_parts`",
                     );
                 }
+                self.note_capability_representation(fcx, &mut err);
 
                 err.emit();
             }
@@ -609,6 +615,43 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
         }
     }
 
+    /// On CHERI purecap targets, explains how pointers differ from integers when a cast between
+    /// them is rejected, since the usual way around the error doesn't give a usable pointer.
+    fn note_capability_representation(&self, fcx: &FnCtxt<'a, 'tcx>, err: &mut Diagnostic) {
+        if fcx.tcx.sess.capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        let is_pointer = |ty: Ty<'tcx>| {
+            matches!(ty.kind(), ty::RawPtr(..) | ty::Ref(..) | ty::FnPtr(..) | ty::FnDef(..))
+        };
+        let to_int = if is_pointer(self.expr_ty) && self.cast_ty.is_integral() {
+            true
+        } else if self.expr_ty.is_integral() && is_pointer(self.cast_ty) {
+            false
+        } else {
+            return;
+        };
+
+        let layout = fcx.tcx.data_layout.ptr_layout(None);
+        err.note(&format!(
+            "on this target pointers are {}-bit capabilities: a {}-bit address with bounds and \
+             permissions, and a validity tag that is not part of their bits",
+            layout.ty_size.bits(),
+            layout.val_size.bits(),
+        ));
+        if to_int {
+            err.help(
+                "use `addr` to get the address of a pointer, or `expose_addr` if it is cast back \
+                 to a pointer later",
+            );
+        } else {
+            err.help(
+                "an integer can't become a valid capability; use `with_addr` or `map_addr` to \
+                 change the address of a pointer that already has the right provenance",
+            );
+        }
+    }
+
     fn report_cast_to_unsized_type(&self, fcx: &FnCtxt<'a, 'tcx>) -> ErrorGuaranteed {
         if let Err(err) = self.cast_ty.error_reported() {
             return err;
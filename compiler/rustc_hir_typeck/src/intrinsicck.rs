@@ -1,10 +1,13 @@
 use hir::HirId;
-use rustc_errors::struct_span_err;
+use rustc_errors::{struct_span_err, DelayDm};
 use rustc_hir as hir;
 use rustc_index::vec::Idx;
 use rustc_middle::ty::layout::{LayoutError, SizeSkeleton};
 use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_session::lint;
+use rustc_span::Span;
 use rustc_target::abi::{Pointer, VariantIdx};
+use rustc_target::spec::CapabilityAbi;
 
 use super::FnCtxt;
 
@@ -57,16 +60,25 @@ pub fn check_transmute(&self, from: Ty<'tcx>, to: Ty<'tcx>, hir_id: HirId) {
         let sk_to = skel(to);
         trace!(?sk_from, ?sk_to);
 
+        // On CHERI purecap targets pointers are capabilities, whose tag no integer can hold.
+        let is_pointer =
+            |ty: Ty<'tcx>| matches!(ty.kind(), ty::RawPtr(..) | ty::Ref(..) | ty::FnPtr(_));
+        let capability_int = tcx.sess.capability_abi() == CapabilityAbi::Purecap
+            && ((is_pointer(from) && to.is_integral()) || (from.is_integral() && is_pointer(to)));
+
         // Check for same size using the skeletons.
         if let (Ok(sk_from), Ok(sk_to)) = (sk_from, sk_to) {
             if sk_from.same_size(sk_to) {
+                if capability_int {
+                    self.lint_capability_transmute(from, to, hir_id, span);
+                }
                 return;
             }
 
             // Special-case transmuting from `typeof(function)` and
             // `Option<typeof(function)>` to present a clearer error.
             let from = unpack_option_like(tcx, from);
-            if let (&ty::FnDef(..), SizeSkeleton::Known(size_to)) = (from.kind(), sk_to) && size_to == Pointer.ty_size(&tcx) {
+            if let (&ty::FnDef(..), SizeSkeleton::Known(size_to)) = (from.kind(), sk_to) && size_to == Pointer(tcx.data_layout.default_address_space).ty_size(&tcx) {
                 struct_span_err!(tcx.sess, span, E0591, "can't transmute zero-sized type")
                     .note(&format!("source type: {from}"))
                     .note(&format!("target type: {to}"))
@@ -90,6 +102,25 @@ pub fn check_transmute(&self, from: Ty<'tcx>, to: Ty<'tcx>, hir_id: HirId) {
             Err(err) => err.to_string(),
         };
 
+        if capability_int && sk_from.is_ok() && sk_to.is_ok() {
+            struct_span_err!(
+                tcx.sess,
+                span,
+                E0512,
+                "cannot transmute between pointers and integers of different sizes on CHERI \
+                 purecap targets"
+            )
+            .note(&format!("source type: `{}` ({})", from, skeleton_string(from, sk_from)))
+            .note(&format!("target type: `{}` ({})", to, skeleton_string(to, sk_to)))
+            .note(
+                "pointers are capabilities, which are twice as wide as an address and carry a \
+                 validity tag that integers can't hold",
+            )
+            .help("use `addr` to get the address of a pointer, or `with_addr` to change it")
+            .emit();
+            return;
+        }
+
         let mut err = struct_span_err!(
             tcx.sess,
             span,
@@ -105,4 +136,28 @@ pub fn check_transmute(&self, from: Ty<'tcx>, to: Ty<'tcx>, hir_id: HirId) {
         }
         err.emit();
     }
+
+    /// Warns about a transmute between a capability and an integer of the same size, which
+    /// passes the size check but loses the tag of the capability.
+    fn lint_capability_transmute(&self, from: Ty<'tcx>, to: Ty<'tcx>, hir_id: HirId, span: Span) {
+        let to_int = to.is_integral();
+        self.tcx.struct_span_lint_hir(
+            lint::builtin::CHERI_CAPABILITY_TRANSMUTES,
+            hir_id,
+            span,
+            DelayDm(|| {
+                if to_int {
+                    format!("transmuting a capability to `{to}` loses its tag")
+                } else {
+                    format!("transmuting `{from}` to a pointer cannot produce a valid capability")
+                }
+            }),
+            |lint| {
+                lint.note(&format!("source type: `{from}`, target type: `{to}`")).note(
+                    "the integer holds the bits of the capability, but not its validity tag, so \
+                     transmuting it back gives a pointer that can't be used",
+                )
+            },
+        );
+    }
 }
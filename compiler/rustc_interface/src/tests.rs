@@ -11,9 +11,12 @@
 };
 use rustc_session::config::{
     BranchProtection, Externs, OomStrategy, OutputType, OutputTypes, PAuthKey, PacRet,
-    ProcMacroExecutionStrategy, SymbolManglingVersion, WasiExecModel,
+    ProcMacroExecutionStrategy, StrictCapability, SymbolManglingVersion, WasiExecModel,
+};
+use rustc_session::config::{
+    CFGuard, CheckProvenanceCasts, CheriStackBounds, CheriSubobjectBounds, ExternEntry,
+    LinkerPluginLto, LtoCli, SwitchWithOptPath,
 };
-use rustc_session::config::{CFGuard, ExternEntry, LinkerPluginLto, LtoCli, SwitchWithOptPath};
 use rustc_session::lint::Level;
 use rustc_session::search_paths::SearchPath;
 use rustc_session::utils::{CanonicalizedPath, NativeLib, NativeLibKind};
@@ -724,6 +727,11 @@ macro_rules! tracked {
         })
     );
     tracked!(chalk, true);
+    tracked!(check_provenance_casts, CheckProvenanceCasts::Runtime);
+    tracked!(cheri_debuginfo_members, true);
+    tracked!(cheri_sealed_vtables, true);
+    tracked!(cheri_stack_bounds, CheriStackBounds::Off);
+    tracked!(cheri_subobject_bounds, CheriSubobjectBounds::Safe);
     tracked!(codegen_backend, Some("abc".to_string()));
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_info_for_profiling, true);
@@ -785,6 +793,7 @@ macro_rules! tracked {
     tracked!(simulate_remapped_rust_src_base, Some(PathBuf::from("/rustc/abc")));
     tracked!(src_hash_algorithm, Some(SourceFileHashAlgorithm::Sha1));
     tracked!(stack_protector, StackProtector::All);
+    tracked!(strict_capability, StrictCapability::Error);
     tracked!(symbol_mangling_version, Some(SymbolManglingVersion::V0));
     tracked!(teach, true);
     tracked!(thinlto, Some(true));
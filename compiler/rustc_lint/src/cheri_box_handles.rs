@@ -0,0 +1,129 @@
+use crate::{context::LintContext, LateContext, LateLintPass};
+use rustc_errors::fluent;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_span::{sym, Symbol};
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `cheri_box_integer_handles` lint detects a `Box` turned into an
+    /// integer with `Box::into_raw` and rebuilt from one with `Box::from_raw`,
+    /// when compiling for a CHERI purecap target.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// pub extern "C" fn session_new() -> usize {
+    ///     Box::into_raw(Box::new(Session::default())) as usize
+    /// }
+    ///
+    /// pub unsafe extern "C" fn session_free(handle: usize) {
+    ///     drop(Box::from_raw(handle as *mut Session));
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: turning a `Box` into an integer handle drops its capability on a purecap target
+    ///  --> lint_example.rs:2:5
+    ///   |
+    /// 2 |     Box::into_raw(Box::new(Session::default())) as usize
+    ///   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: a `Box` rebuilt from the handle has its tag cleared, and using or dropping it traps
+    ///   = help: return the pointer itself, or a `Sealed` handle from `core::arch::cheri` if it is given to untrusted code
+    ///   = note: `#[warn(cheri_box_integer_handles)]` on by default
+    ///
+    /// warning: rebuilding a `Box` from an integer handle cannot produce a valid capability on a purecap target
+    ///  --> lint_example.rs:6:10
+    ///   |
+    /// 6 |     drop(Box::from_raw(handle as *mut Session));
+    ///   |          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: a `Box` rebuilt from the handle has its tag cleared, and using or dropping it traps
+    ///   = help: take the pointer itself, or a `Sealed` handle from `core::arch::cheri` if it is given to untrusted code
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// Handing out a boxed object as an integer handle, and turning the handle
+    /// back into a `Box` when it is passed back, is a common way to expose Rust
+    /// objects through a C API. In the purecap ABI the integer only holds the
+    /// address of the allocation, not its capability, so the `Box` rebuilt from
+    /// it is not usable. Pass the pointer itself through the API instead, which
+    /// C sees as an opaque `void *`.
+    ///
+    /// The lint does nothing on other targets.
+    pub CHERI_BOX_INTEGER_HANDLES,
+    Warn,
+    "detects boxes that are passed around as integers on CHERI purecap targets"
+}
+
+declare_lint_pass!(CheriBoxHandles => [CHERI_BOX_INTEGER_HANDLES]);
+
+impl<'tcx> LateLintPass<'tcx> for CheriBoxHandles {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        match expr.kind {
+            // `Box::into_raw(b) as usize`
+            hir::ExprKind::Cast(inner, _)
+                if cx.typeck_results().expr_ty(expr).is_integral()
+                    && is_call_to(cx, through_local(cx, inner), sym::box_into_raw) =>
+            {
+                cx.struct_span_lint(
+                    CHERI_BOX_INTEGER_HANDLES,
+                    expr.span,
+                    fluent::lint_cheri_box_into_integer,
+                    |lint| lint.note(fluent::note).help(fluent::help),
+                );
+            }
+            // `Box::from_raw(handle as *mut T)`
+            hir::ExprKind::Call(_, [arg]) if is_call_to(cx, expr, sym::box_from_raw) => {
+                let arg = through_local(cx, arg);
+                if let hir::ExprKind::Cast(inner, _) = arg.kind
+                    && cx.typeck_results().expr_ty(inner).is_integral()
+                {
+                    cx.struct_span_lint(
+                        CHERI_BOX_INTEGER_HANDLES,
+                        expr.span,
+                        fluent::lint_cheri_box_from_integer,
+                        |lint| lint.note(fluent::note).help(fluent::help),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether `expr` calls the function that is the diagnostic item `name`.
+fn is_call_to(cx: &LateContext<'_>, expr: &hir::Expr<'_>, name: Symbol) -> bool {
+    let hir::ExprKind::Call(callee, _) = expr.kind else { return false };
+    let hir::ExprKind::Path(qpath) = &callee.kind else { return false };
+    cx.qpath_res(qpath, callee.hir_id)
+        .opt_def_id()
+        .map_or(false, |def_id| cx.tcx.is_diagnostic_item(name, def_id))
+}
+
+/// Returns the initializer of the immutable local variable that `expr` reads, or `expr` itself,
+/// so that `let p = Box::into_raw(b); p as usize` is treated like the cast of the call.
+fn through_local<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+) -> &'tcx hir::Expr<'tcx> {
+    let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind else { return expr };
+    let Res::Local(binding) = path.res else { return expr };
+    let hir = cx.tcx.hir();
+    if let Some(hir::Node::Pat(pat)) = hir.find(binding)
+        && let hir::PatKind::Binding(hir::BindingAnnotation::NONE, ..) = pat.kind
+        && let Some(hir::Node::Local(hir::Local { init: Some(init), .. })) =
+            hir.find(hir.get_parent_node(binding))
+    {
+        init
+    } else {
+        expr
+    }
+}
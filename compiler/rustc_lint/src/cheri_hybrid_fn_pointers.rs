@@ -0,0 +1,124 @@
+use crate::{context::LintContext, LateContext, LateLintPass};
+use rustc_errors::fluent;
+use rustc_hir as hir;
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_middle::ty::{self, DefIdTree, Ty};
+use rustc_span::sym;
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `cheri_unqualified_fn_pointers` lint detects Rust functions and
+    /// closures that are passed to foreign functions as plain function
+    /// pointers, when compiling for a CHERI hybrid target.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a hybrid target)
+    /// extern "C" {
+    ///     fn register_callback(cb: extern "C" fn(i32));
+    /// }
+    ///
+    /// extern "C" fn on_event(_: i32) {}
+    ///
+    /// fn main() {
+    ///     unsafe { register_callback(on_event) };
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: this function is passed to `register_callback` as a plain function pointer
+    ///  --> lint_example.rs:8:32
+    ///   |
+    /// 8 |     unsafe { register_callback(on_event) };
+    ///   |                                ^^^^^^^^
+    ///   |
+    ///   = note: the callee receives only its 64-bit address, not a capability to it
+    ///   = help: if the C parameter is a `__capability` function pointer, declare it with a `#[cheri_capability]` type in the `extern` block
+    ///   = note: `#[warn(cheri_unqualified_fn_pointers)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// In the hybrid ABI function pointers are plain addresses unless they
+    /// are qualified with `__capability` in C, or have a `#[cheri_capability]`
+    /// type in Rust. Callbacks are usually handed to C code that was ported
+    /// to capabilities, whose declarations use capability function pointers.
+    /// If the parameter is declared with a plain function pointer type on the
+    /// Rust side instead, the callee only gets the address of the callback,
+    /// and code that expects a capability can't call it.
+    ///
+    /// The lint can be allowed where the C side also takes a plain function
+    /// pointer. It does nothing on other targets.
+    pub CHERI_UNQUALIFIED_FN_POINTERS,
+    Warn,
+    "detects functions passed to foreign code as plain function pointers on CHERI hybrid targets"
+}
+
+declare_lint_pass!(CheriHybridFnPointers => [CHERI_UNQUALIFIED_FN_POINTERS]);
+
+impl<'tcx> LateLintPass<'tcx> for CheriHybridFnPointers {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Hybrid {
+            return;
+        }
+        let hir::ExprKind::Call(callee, args) = expr.kind else { return };
+        let hir::ExprKind::Path(qpath) = &callee.kind else { return };
+        let Some(def_id) = cx.qpath_res(qpath, callee.hir_id).opt_def_id() else { return };
+        if !cx.tcx.is_foreign_item(def_id) {
+            return;
+        }
+
+        let sig = cx.tcx.fn_sig(def_id).skip_binder();
+        for (&param_ty, arg) in sig.inputs().iter().zip(args) {
+            let Some(function) = passed_function(cx, param_ty, arg) else { continue };
+            let is_function = matches!(
+                cx.typeck_results().expr_ty(function).kind(),
+                ty::FnDef(..) | ty::Closure(..) | ty::FnPtr(_)
+            );
+            if !is_function {
+                continue;
+            }
+            cx.struct_span_lint(
+                CHERI_UNQUALIFIED_FN_POINTERS,
+                function.span,
+                fluent::lint_cheri_unqualified_fn_pointer,
+                |lint| {
+                    lint.set_arg("callee", cx.tcx.item_name(def_id));
+                    lint.set_arg("bits", cx.tcx.data_layout.pointer_size.bits());
+                    lint.note(fluent::note);
+                    lint.help(fluent::help);
+                    lint
+                },
+            );
+        }
+    }
+}
+
+/// Returns the function that `arg` passes for a parameter of type `param_ty`, if that is a plain
+/// function pointer or an `Option` of one. `None` doesn't pass anything.
+fn passed_function<'tcx>(
+    cx: &LateContext<'tcx>,
+    param_ty: Ty<'tcx>,
+    arg: &'tcx hir::Expr<'tcx>,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    match param_ty.kind() {
+        ty::FnPtr(_) => Some(arg),
+        ty::Adt(def, substs)
+            if cx.tcx.is_diagnostic_item(sym::Option, def.did())
+                && substs.type_at(0).is_fn_ptr() =>
+        {
+            let hir::ExprKind::Call(ctor, [function]) = arg.kind else { return None };
+            let hir::ExprKind::Path(qpath) = &ctor.kind else { return None };
+            let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_id) =
+                cx.qpath_res(qpath, ctor.hir_id)
+            else {
+                return None;
+            };
+            (cx.tcx.lang_items().option_some_variant() == Some(cx.tcx.parent(ctor_id)))
+                .then_some(function)
+        }
+        _ => None,
+    }
+}
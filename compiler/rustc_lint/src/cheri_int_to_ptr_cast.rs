@@ -0,0 +1,129 @@
+use crate::{context::LintContext, LateContext, LateLintPass};
+use rustc_ast::LitKind;
+use rustc_errors::{fluent, Applicability, Diagnostic};
+use rustc_hir as hir;
+use rustc_middle::ty;
+use rustc_session::config::StrictCapability;
+use rustc_span::Span;
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `cheri_int_to_ptr_cast` lint detects `as` casts from an integer to
+    /// a raw pointer when compiling for a CHERI purecap target.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// fn next(p: *const u8) -> *const u8 {
+    ///     (p as usize + 1) as *const u8
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// error: casting an integer to a pointer cannot produce a valid capability on a purecap target
+    ///  --> lint_example.rs:2:5
+    ///   |
+    /// 2 |     (p as usize + 1) as *const u8
+    ///   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ help: offset the address of the original pointer instead: `p.map_addr(|addr| addr + 1)`
+    ///   |
+    ///   = note: an integer only holds an address, so the pointer has its tag cleared and any access through it traps
+    ///   = note: `#[deny(cheri_int_to_ptr_cast)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// In the purecap ABI every pointer is a capability, and the only way to
+    /// obtain a valid capability is to derive it from another one. Casting a
+    /// pointer to an integer keeps only its address, so casting the integer
+    /// back gives an untagged pointer that faults on first use. Change the
+    /// address of a pointer that already has the right provenance with
+    /// `map_addr` or `with_addr` instead.
+    ///
+    /// The lint does nothing on other targets. Passing
+    /// `-Z strict-capability=error` turns these casts into hard errors that
+    /// cannot be allowed.
+    pub CHERI_INT_TO_PTR_CAST,
+    Deny,
+    "detects casts from integers to pointers on CHERI purecap targets"
+}
+
+declare_lint_pass!(CheriIntToPtrCast => [CHERI_INT_TO_PTR_CAST]);
+
+impl<'tcx> LateLintPass<'tcx> for CheriIntToPtrCast {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        let hir::ExprKind::Cast(inner, _) = &expr.kind else { return };
+        let typeck_results = cx.typeck_results();
+        let ptr_ty = typeck_results.expr_ty(expr);
+        if !ptr_ty.is_unsafe_ptr() || !typeck_results.expr_ty(inner).is_integral() {
+            return;
+        }
+        // `0 as *const T` is the null capability, the same as `ptr::null()`.
+        if let hir::ExprKind::Lit(lit) = &inner.kind
+            && let LitKind::Int(0, _) = lit.node
+        {
+            return;
+        }
+
+        let suggestion = map_addr_suggestion(cx, inner, ptr_ty);
+        if cx.sess().opts.unstable_opts.strict_capability == StrictCapability::Error {
+            let mut err = cx.sess().struct_span_err(expr.span, fluent::lint_cheri_int_to_ptr_cast);
+            explain(&mut err, expr.span, suggestion);
+            err.emit();
+            return;
+        }
+        cx.struct_span_lint(
+            CHERI_INT_TO_PTR_CAST,
+            expr.span,
+            fluent::lint_cheri_int_to_ptr_cast,
+            |lint| {
+                explain(lint, expr.span, suggestion);
+                lint
+            },
+        );
+    }
+}
+
+fn explain(diag: &mut Diagnostic, span: Span, suggestion: Option<String>) {
+    diag.note(fluent::note);
+    match suggestion {
+        Some(code) => {
+            diag.span_suggestion(span, fluent::suggestion, code, Applicability::MaybeIncorrect);
+        }
+        None => {
+            diag.help(fluent::help);
+        }
+    }
+}
+
+/// Suggests `p.map_addr(|addr| addr + n)` for `(p as usize + n) as *const T`, when `p` is
+/// already a `*const T`. The suggestion may be incorrect: `map_addr` needs
+/// `#![feature(strict_provenance)]`, and the closure parameter shadows anything called `addr` in
+/// the operand.
+fn map_addr_suggestion<'tcx>(
+    cx: &LateContext<'tcx>,
+    inner: &'tcx hir::Expr<'tcx>,
+    ptr_ty: ty::Ty<'tcx>,
+) -> Option<String> {
+    let hir::ExprKind::Binary(op, lhs, rhs) = &inner.kind else { return None };
+    let hir::ExprKind::Cast(ptr, _) = &lhs.kind else { return None };
+    if cx.typeck_results().expr_ty(ptr) != ptr_ty {
+        return None;
+    }
+    let source_map = cx.sess().source_map();
+    let ptr_snippet = source_map.span_to_snippet(ptr.span).ok()?;
+    let rhs_snippet = source_map.span_to_snippet(rhs.span).ok()?;
+    let ptr_snippet = match ptr.kind {
+        hir::ExprKind::Path(_)
+        | hir::ExprKind::Field(..)
+        | hir::ExprKind::MethodCall(..)
+        | hir::ExprKind::Call(..)
+        | hir::ExprKind::Index(..) => ptr_snippet,
+        _ => format!("({ptr_snippet})"),
+    };
+    Some(format!("{ptr_snippet}.map_addr(|addr| addr {} {rhs_snippet})", op.node.as_str()))
+}
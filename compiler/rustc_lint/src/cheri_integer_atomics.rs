@@ -0,0 +1,155 @@
+use crate::{context::LintContext, LateContext, LateLintPass};
+use rustc_errors::fluent;
+use rustc_hir as hir;
+use rustc_middle::ty::{self, DefIdTree, Ty};
+use rustc_span::{sym, Symbol};
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `cheri_pointers_in_integer_atomics` lint detects pointers that are
+    /// stored in integer atomics, or read back from them, when compiling for a
+    /// CHERI purecap target.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static HEAD: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// fn push(node: *mut u8) {
+    ///     HEAD.store(node as usize, Ordering::Release);
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: storing a pointer in an `AtomicUsize` drops its capability on a purecap target
+    ///  --> lint_example.rs:6:16
+    ///   |
+    /// 6 |     HEAD.store(node as usize, Ordering::Release);
+    ///   |                ^^^^^^^^^^^^^
+    ///   |
+    ///   = note: the pointer loaded back from the atomic has its tag cleared and any access through it traps
+    ///   = help: use an `AtomicPtr` to share the pointer, or an `AtomicAddr` if only its address is needed
+    ///   = note: `#[warn(cheri_pointers_in_integer_atomics)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// Code written for targets where pointers and `usize` have the same
+    /// size often shares pointers through an `AtomicUsize` or `AtomicU64`.
+    /// In the purecap ABI pointers are capabilities, and an integer only holds
+    /// their address, so the pointer that is read back and cast from the
+    /// atomic is never valid. `AtomicPtr` has the size of a capability and
+    /// keeps the tag.
+    ///
+    /// The lint does nothing on other targets.
+    pub CHERI_POINTERS_IN_INTEGER_ATOMICS,
+    Warn,
+    "detects pointers shared through integer atomics on CHERI purecap targets"
+}
+
+declare_lint_pass!(CheriIntegerAtomics => [CHERI_POINTERS_IN_INTEGER_ATOMICS]);
+
+/// The atomics that pointers are stashed in, because they have the size of a pointer on most
+/// targets.
+const ADDRESS_SIZED_ATOMICS: &[Symbol] =
+    &[sym::AtomicUsize, sym::AtomicIsize, sym::AtomicU64, sym::AtomicI64];
+
+impl<'tcx> LateLintPass<'tcx> for CheriIntegerAtomics {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        match expr.kind {
+            hir::ExprKind::MethodCall(path, receiver, args, _) => {
+                let receiver_ty = cx.typeck_results().expr_ty(receiver);
+                let Some(atomic) = address_sized_atomic(cx, receiver_ty) else { return };
+                let stored = match path.ident.name {
+                    sym::store | sym::swap => &args[..1],
+                    sym::compare_exchange | sym::compare_exchange_weak => &args[..2],
+                    _ => return,
+                };
+                for arg in stored {
+                    if is_pointer_to_int(cx, arg) {
+                        lint_store(cx, arg, atomic);
+                    }
+                }
+            }
+            hir::ExprKind::Call(callee, [arg]) => {
+                let hir::ExprKind::Path(qpath) = &callee.kind else { return };
+                let Some(def_id) = cx.qpath_res(qpath, callee.hir_id).opt_def_id() else { return };
+                if cx.tcx.item_name(def_id) == sym::new
+                    && let Some(impl_did) = cx.tcx.impl_of_method(def_id)
+                    && let Some(atomic) = address_sized_atomic(cx, cx.tcx.type_of(impl_did))
+                    && is_pointer_to_int(cx, arg)
+                {
+                    lint_store(cx, arg, atomic);
+                }
+            }
+            hir::ExprKind::Cast(inner, _) => {
+                let typeck_results = cx.typeck_results();
+                if !typeck_results.expr_ty(expr).is_unsafe_ptr()
+                    || !typeck_results.expr_ty(inner).is_integral()
+                {
+                    return;
+                }
+                let hir::ExprKind::MethodCall(_, receiver, ..) = inner.kind else { return };
+                let receiver_ty = typeck_results.expr_ty(receiver);
+                let Some(atomic) = address_sized_atomic(cx, receiver_ty) else { return };
+                cx.struct_span_lint(
+                    CHERI_POINTERS_IN_INTEGER_ATOMICS,
+                    expr.span,
+                    fluent::lint_cheri_pointer_from_integer_atomic,
+                    |lint| {
+                        lint.set_arg("atomic", atomic);
+                        lint.note(fluent::note);
+                        lint.help(fluent::help);
+                        lint
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn lint_store(cx: &LateContext<'_>, arg: &hir::Expr<'_>, atomic: Symbol) {
+    cx.struct_span_lint(
+        CHERI_POINTERS_IN_INTEGER_ATOMICS,
+        arg.span,
+        fluent::lint_cheri_pointer_in_integer_atomic,
+        |lint| {
+            lint.set_arg("atomic", atomic);
+            lint.note(fluent::note);
+            lint.help(fluent::help);
+            lint
+        },
+    );
+}
+
+/// Returns the name of `ty`, or of the type it references, if it is one of the
+/// `ADDRESS_SIZED_ATOMICS` of the standard library.
+fn address_sized_atomic<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Symbol> {
+    let ty::Adt(adt, _) = ty.peel_refs().kind() else { return None };
+    let name = cx.tcx.item_name(adt.did());
+    (cx.tcx.is_diagnostic_item(sym::atomic_mod, cx.tcx.parent(adt.did()))
+        && ADDRESS_SIZED_ATOMICS.contains(&name))
+    .then_some(name)
+}
+
+/// Returns whether `expr` turns a pointer into an integer with an `as` cast or `expose_addr`.
+/// Storing the result of `addr` says that only the address is wanted, which is fine.
+fn is_pointer_to_int<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> bool {
+    let typeck_results = cx.typeck_results();
+    match expr.kind {
+        hir::ExprKind::Cast(inner, _) => typeck_results.expr_ty(inner).is_unsafe_ptr(),
+        hir::ExprKind::MethodCall(path, receiver, ..) => {
+            path.ident.name == sym::expose_addr
+                && typeck_results.expr_ty_adjusted(receiver).is_unsafe_ptr()
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{context::LintContext, LateContext, LateLintPass};
+use rustc_errors::fluent;
+use rustc_hir as hir;
+use rustc_span::{sym, Span, Symbol};
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `cheri_pointer_address_derives` lint detects types that derive
+    /// `Hash` or an ordering from a raw pointer field and are also
+    /// serialized, when compiling for a CHERI purecap target.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target and serde)
+    /// #[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    /// struct Entry {
+    ///     #[serde(serialize_with = "serialize_address")]
+    ///     node: *const Node,
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: `Entry` derives `Hash`, `PartialOrd` and `Ord` from the address of this pointer
+    ///  --> lint_example.rs:4:5
+    ///   |
+    /// 4 |     node: *const Node,
+    ///   |     ^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: on a purecap target the address alone isn't enough to rebuild the pointer
+    /// note: `Entry` is serialized here
+    ///  --> lint_example.rs:1:48
+    ///   |
+    /// 1 | #[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    ///   |                                                ^^^^^^^^^
+    ///   = help: implement these traits by hand on the `addr()` of the pointer to make explicit that only its address is used
+    ///   = note: `#[warn(cheri_pointer_address_derives)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// The derived implementations hash and order a raw pointer by its
+    /// address. That is fine while the values stay in the process, but
+    /// when the type is also serialized the hashes, orderings and addresses
+    /// that are written out are easily taken to identify the pointer. In the
+    /// purecap ABI a pointer is a capability, and it can't be rebuilt from
+    /// its address when the data is read back. Implementing the traits on
+    /// `ptr.addr()` by hand says that only the address is meant.
+    ///
+    /// The lint does nothing on other targets.
+    pub CHERI_POINTER_ADDRESS_DERIVES,
+    Warn,
+    "detects serialized types that derive `Hash` or `Ord` from pointer addresses on CHERI purecap targets"
+}
+
+declare_lint_pass!(CheriPointerDerives => [CHERI_POINTER_ADDRESS_DERIVES]);
+
+/// The derivable traits that only look at the address of a raw pointer.
+const ADDRESS_TRAITS: &[Symbol] = &[sym::Hash, sym::PartialOrd, sym::Ord];
+
+/// The names of the traits of the common serialization crates.
+const SERIALIZATION_TRAITS: &[Symbol] = &[sym::Serialize, sym::Encodable];
+
+impl<'tcx> LateLintPass<'tcx> for CheriPointerDerives {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        match item.kind {
+            hir::ItemKind::Struct(..) | hir::ItemKind::Enum(..) => {}
+            _ => return,
+        }
+
+        let adt = cx.tcx.adt_def(item.owner_id);
+        let pointer_fields: Vec<Span> = adt
+            .all_fields()
+            .filter(|field| cx.tcx.type_of(field.did).is_unsafe_ptr())
+            .map(|field| cx.tcx.def_span(field.did))
+            .collect();
+        if pointer_fields.is_empty() {
+            return;
+        }
+
+        let self_ty = cx.tcx.type_of(item.owner_id);
+        let mut derived = vec![];
+        let mut serialized = None;
+        for &trait_def_id in cx.tcx.all_local_trait_impls(()).keys() {
+            let derived_trait = cx
+                .tcx
+                .get_diagnostic_name(trait_def_id)
+                .filter(|name| ADDRESS_TRAITS.contains(name));
+            let serialization = SERIALIZATION_TRAITS.contains(&cx.tcx.item_name(trait_def_id));
+            if derived_trait.is_none() && !serialization {
+                continue;
+            }
+            for impl_def_id in cx.tcx.non_blanket_impls_for_ty(trait_def_id, self_ty) {
+                if let Some(name) = derived_trait
+                    && cx.tcx.has_attr(impl_def_id, sym::automatically_derived)
+                {
+                    derived.push(name);
+                } else if serialization {
+                    serialized = Some(cx.tcx.def_span(impl_def_id));
+                }
+            }
+        }
+        let Some(serialized) = serialized else { return };
+        if derived.is_empty() {
+            return;
+        }
+        // List the traits in the order they are usually derived in.
+        derived.sort_by_key(|name| ADDRESS_TRAITS.iter().position(|t| t == name));
+        let traits = match &derived[..] {
+            [name] => format!("`{name}`"),
+            [init @ .., last] => {
+                let init: Vec<_> = init.iter().map(|name| format!("`{name}`")).collect();
+                format!("{} and `{last}`", init.join(", "))
+            }
+            [] => unreachable!(),
+        };
+
+        for span in pointer_fields {
+            cx.struct_span_lint(
+                CHERI_POINTER_ADDRESS_DERIVES,
+                span,
+                fluent::lint_cheri_pointer_address_derive,
+                |lint| {
+                    lint.set_arg("ty", item.ident);
+                    lint.set_arg("traits", traits.clone());
+                    lint.note(fluent::note);
+                    lint.span_note(serialized, fluent::serialized_note);
+                    lint.help(fluent::help);
+                    lint
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{context::LintContext, LateContext, LateLintPass};
+use rustc_errors::{fluent, Applicability};
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_hir::HirId;
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `cheri_ptr_to_int_cast` lint detects `as` casts from a raw pointer
+    /// to an integer when compiling for a CHERI purecap target.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// fn is_aligned(p: *const u64) -> bool {
+    ///     p as usize % 8 == 0
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: casting a pointer to an integer keeps only its address on a purecap target
+    ///  --> lint_example.rs:2:5
+    ///   |
+    /// 2 |     p as usize % 8 == 0
+    ///   |     ^^^^^^^^^^ help: use `addr` to get the address explicitly: `p.addr()`
+    ///   |
+    ///   = note: the integer doesn't hold the capability of the pointer, so it can't be turned back into a usable pointer
+    ///   = note: `#[warn(cheri_ptr_to_int_cast)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// In the purecap ABI every pointer is a capability, which is twice as
+    /// wide as its address and carries a validity tag. An integer can only
+    /// hold the address, so the cast silently drops the rest of the
+    /// capability. That is harmless when only the address is needed, as for
+    /// an alignment check or hashing, and `addr` says so explicitly. When the
+    /// integer is cast back to a pointer later, the lint suggests
+    /// `expose_addr` instead, which marks the provenance as exposed; the
+    /// pointer cast back from it is still not a valid capability, see the
+    /// `cheri_int_to_ptr_cast` lint.
+    ///
+    /// The lint does nothing on other targets.
+    pub CHERI_PTR_TO_INT_CAST,
+    Warn,
+    "detects casts from pointers to integers on CHERI purecap targets"
+}
+
+declare_lint_pass!(CheriPtrToIntCast => [CHERI_PTR_TO_INT_CAST]);
+
+impl<'tcx> LateLintPass<'tcx> for CheriPtrToIntCast {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        let hir::ExprKind::Cast(ptr, _) = &expr.kind else { return };
+        let typeck_results = cx.typeck_results();
+        let int_ty = typeck_results.expr_ty(expr);
+        let ptr_ty = typeck_results.expr_ty(ptr);
+        if !ptr_ty.is_unsafe_ptr() || !int_ty.is_integral() {
+            return;
+        }
+
+        let (method, msg) = if is_cast_back(cx, expr) {
+            ("expose_addr", fluent::expose_suggestion)
+        } else {
+            ("addr", fluent::addr_suggestion)
+        };
+        let source_map = cx.sess().source_map();
+        let suggestion = source_map.span_to_snippet(ptr.span).ok().map(|snippet| {
+            let snippet = match ptr.kind {
+                hir::ExprKind::Path(_)
+                | hir::ExprKind::Field(..)
+                | hir::ExprKind::MethodCall(..)
+                | hir::ExprKind::Call(..)
+                | hir::ExprKind::Index(..) => snippet,
+                _ => format!("({snippet})"),
+            };
+            if int_ty == cx.tcx.types.usize {
+                format!("{snippet}.{method}()")
+            } else {
+                format!("{snippet}.{method}() as {int_ty}")
+            }
+        });
+        // `addr` and `expose_addr` need the pointee to be sized.
+        let pointee = ptr_ty.builtin_deref(true).unwrap().ty;
+        let applicability = if pointee.is_sized(cx.tcx, cx.param_env) {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+
+        cx.struct_span_lint(
+            CHERI_PTR_TO_INT_CAST,
+            expr.span,
+            fluent::lint_cheri_ptr_to_int_cast,
+            |lint| {
+                lint.note(fluent::note);
+                if let Some(suggestion) = suggestion {
+                    lint.span_suggestion(expr.span, msg, suggestion, applicability);
+                }
+                lint
+            },
+        );
+    }
+}
+
+/// Where the integer that an expression evaluates to ends up.
+enum Flow {
+    /// It is cast to a pointer, possibly after some arithmetic.
+    ToPointer,
+    /// It is used to initialize the local variable `binding`.
+    ToLocal(HirId),
+    Elsewhere,
+}
+
+fn flow<'tcx>(cx: &LateContext<'tcx>, expr: HirId) -> Flow {
+    for (_, node) in cx.tcx.hir().parent_iter(expr) {
+        match node {
+            hir::Node::Expr(parent) => match parent.kind {
+                hir::ExprKind::Binary(..) | hir::ExprKind::Unary(..) => {}
+                hir::ExprKind::Cast(..) => {
+                    let ty = cx.typeck_results().expr_ty(parent);
+                    if ty.is_unsafe_ptr() {
+                        return Flow::ToPointer;
+                    } else if !ty.is_integral() {
+                        return Flow::Elsewhere;
+                    }
+                }
+                _ => return Flow::Elsewhere,
+            },
+            hir::Node::Local(hir::Local { pat, .. }) => {
+                return match pat.kind {
+                    hir::PatKind::Binding(_, binding, ..) => Flow::ToLocal(binding),
+                    _ => Flow::Elsewhere,
+                };
+            }
+            _ => return Flow::Elsewhere,
+        }
+    }
+    Flow::Elsewhere
+}
+
+/// Returns whether the integer `expr` evaluates to is cast back to a pointer, either directly
+/// or through a local variable that it is assigned to.
+fn is_cast_back<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> bool {
+    match flow(cx, expr.hir_id) {
+        Flow::ToPointer => true,
+        Flow::Elsewhere => false,
+        Flow::ToLocal(binding) => {
+            let Some(body_id) = cx.enclosing_body else { return false };
+            let mut uses = LocalUses { binding, uses: Vec::new() };
+            uses.visit_body(cx.tcx.hir().body(body_id));
+            uses.uses.into_iter().any(|id| matches!(flow(cx, id), Flow::ToPointer))
+        }
+    }
+}
+
+/// Collects the uses of a local variable.
+struct LocalUses {
+    binding: HirId,
+    uses: Vec<HirId>,
+}
+
+impl<'tcx> Visitor<'tcx> for LocalUses {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = &expr.kind
+            && let Res::Local(id) = path.res
+            && id == self.binding
+        {
+            self.uses.push(expr.hir_id);
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
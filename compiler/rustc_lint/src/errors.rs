@@ -148,3 +148,13 @@ pub struct CheckNameDeprecated {
     #[subdiagnostic]
     pub sub: RequestedLevel,
 }
+
+#[derive(Diagnostic)]
+#[diag(lint_purecap_exposed_provenance)]
+#[note]
+#[help]
+pub struct ExposedProvenanceOnPurecap {
+    #[primary_span]
+    pub span: Span,
+    pub name: Symbol,
+}
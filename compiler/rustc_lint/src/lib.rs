@@ -47,6 +47,12 @@
 
 mod array_into_iter;
 pub mod builtin;
+mod cheri_box_handles;
+mod cheri_hybrid_fn_pointers;
+mod cheri_int_to_ptr_cast;
+mod cheri_integer_atomics;
+mod cheri_pointer_derives;
+mod cheri_ptr_to_int_cast;
 mod context;
 mod early;
 mod enum_intrinsics_non_enums;
@@ -66,10 +72,12 @@
 mod opaque_hidden_inferred_bound;
 mod pass_by_value;
 mod passes;
+mod purecap_exposed_provenance;
 mod redundant_semicolon;
 mod traits;
 mod types;
 mod unused;
+mod usize_pointer_size;
 
 pub use array_into_iter::ARRAY_INTO_ITER;
 
@@ -86,6 +94,12 @@
 
 use array_into_iter::ArrayIntoIter;
 use builtin::*;
+use cheri_box_handles::*;
+use cheri_hybrid_fn_pointers::*;
+use cheri_int_to_ptr_cast::*;
+use cheri_integer_atomics::*;
+use cheri_pointer_derives::*;
+use cheri_ptr_to_int_cast::*;
 use enum_intrinsics_non_enums::EnumIntrinsicsNonEnums;
 use for_loops_over_fallibles::*;
 use hidden_unicode_codepoints::*;
@@ -98,10 +112,12 @@
 use noop_method_call::*;
 use opaque_hidden_inferred_bound::*;
 use pass_by_value::*;
+use purecap_exposed_provenance::*;
 use redundant_semicolon::*;
 use traits::*;
 use types::*;
 use unused::*;
+use usize_pointer_size::*;
 
 /// Useful for other parts of the compiler / Clippy.
 pub use builtin::SoftLints;
@@ -226,6 +242,14 @@ macro_rules! late_lint_mod_passes {
                 NonPanicFmt: NonPanicFmt,
                 NoopMethodCall: NoopMethodCall,
                 EnumIntrinsicsNonEnums: EnumIntrinsicsNonEnums,
+                PurecapExposedProvenance: PurecapExposedProvenance,
+                CheriIntToPtrCast: CheriIntToPtrCast,
+                CheriPtrToIntCast: CheriPtrToIntCast,
+                CheriIntegerAtomics: CheriIntegerAtomics,
+                UsizePointerSize: UsizePointerSize,
+                CheriBoxHandles: CheriBoxHandles,
+                CheriPointerDerives: CheriPointerDerives,
+                CheriHybridFnPointers: CheriHybridFnPointers,
                 InvalidAtomicOrdering: InvalidAtomicOrdering,
                 NamedAsmLabels: NamedAsmLabels,
                 OpaqueHiddenInferredBound: OpaqueHiddenInferredBound,
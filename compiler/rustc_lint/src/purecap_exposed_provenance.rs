@@ -0,0 +1,80 @@
+use crate::{context::LintContext, errors::ExposedProvenanceOnPurecap, LateContext, LateLintPass};
+use rustc_errors::fluent;
+use rustc_hir as hir;
+use rustc_session::config::StrictCapability;
+use rustc_span::symbol::sym;
+use rustc_target::spec::CapabilityAbi;
+
+declare_lint! {
+    /// The `purecap_exposed_provenance` lint detects calls to
+    /// [`ptr::from_exposed_addr`] and [`ptr::from_exposed_addr_mut`] when
+    /// compiling for a CHERI purecap target.
+    ///
+    /// [`ptr::from_exposed_addr`]: https://doc.rust-lang.org/core/ptr/fn.from_exposed_addr.html
+    /// [`ptr::from_exposed_addr_mut`]: https://doc.rust-lang.org/core/ptr/fn.from_exposed_addr_mut.html
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// #![feature(strict_provenance)]
+    /// let p = core::ptr::from_exposed_addr::<u8>(0x1000);
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// error: `from_exposed_addr` cannot recover a valid capability on a purecap target
+    ///  --> lint_example.rs:2:9
+    ///   |
+    /// 2 | let p = core::ptr::from_exposed_addr::<u8>(0x1000);
+    ///   |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: there is no ambient capability to derive the pointer from, so the returned pointer has its tag cleared and any access through it traps
+    ///   = help: derive the pointer from an existing one with `with_addr` instead
+    ///   = note: `#[deny(purecap_exposed_provenance)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// In the purecap ABI every pointer is a capability, and the only way to
+    /// obtain a valid capability is to derive it from another one. An integer
+    /// carries no capability, so the pointer produced by `from_exposed_addr`
+    /// is untagged and faults on first use. Use `with_addr` on a pointer that
+    /// already has the right provenance instead.
+    ///
+    /// Passing `-Z strict-capability=error` turns these calls into hard
+    /// errors that cannot be allowed.
+    pub PURECAP_EXPOSED_PROVENANCE,
+    Deny,
+    "detects calls to `ptr::from_exposed_addr` on CHERI purecap targets"
+}
+
+declare_lint_pass!(PurecapExposedProvenance => [PURECAP_EXPOSED_PROVENANCE]);
+
+impl<'tcx> LateLintPass<'tcx> for PurecapExposedProvenance {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if cx.sess().capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+        let hir::ExprKind::Call(func, _) = &expr.kind else { return };
+        let hir::ExprKind::Path(qpath) = &func.kind else { return };
+        let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id() else { return };
+        if !matches!(
+            cx.tcx.get_diagnostic_name(def_id),
+            Some(sym::ptr_from_exposed_addr | sym::ptr_from_exposed_addr_mut)
+        ) {
+            return;
+        }
+        let name = cx.tcx.item_name(def_id);
+        if cx.sess().opts.unstable_opts.strict_capability == StrictCapability::Error {
+            cx.sess().emit_err(ExposedProvenanceOnPurecap { span: expr.span, name });
+            return;
+        }
+        cx.struct_span_lint(
+            PURECAP_EXPOSED_PROVENANCE,
+            expr.span,
+            fluent::lint_purecap_exposed_provenance,
+            |lint| lint.set_arg("name", name).note(fluent::note).help(fluent::help),
+        );
+    }
+}
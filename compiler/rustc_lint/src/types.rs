@@ -4,6 +4,7 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{fluent, Applicability, DiagnosticMessage};
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{is_range_literal, Expr, ExprKind, Node};
 use rustc_macros::LintDiagnostic;
 use rustc_middle::ty::layout::{IntegerExt, LayoutOf};
@@ -15,6 +16,7 @@
 use rustc_target::abi::{Abi, WrappingRange};
 use rustc_target::abi::{Integer, TagEncoding, Variants};
 use rustc_target::spec::abi::Abi as SpecAbi;
+use rustc_target::spec::CapabilityAbi;
 
 use std::cmp;
 use std::iter;
@@ -638,7 +640,15 @@ fn is_comparison(binop: hir::BinOp) -> bool {
     "proper use of libc types in foreign modules"
 }
 
-declare_lint_pass!(ImproperCTypesDeclarations => [IMPROPER_CTYPES]);
+// The CHERI lints are also emitted by `ImproperCTypesDefinitions`, which shares the visitor,
+// but a lint can only be registered by one pass.
+declare_lint_pass!(
+    ImproperCTypesDeclarations => [
+        IMPROPER_CTYPES,
+        CHERI_CAPABILITY_UNIONS,
+        CHERI_FFI_CAPABILITY_MISMATCH
+    ]
+);
 
 declare_lint! {
     /// The `improper_ctypes_definitions` lint detects incorrect use of
@@ -667,6 +677,101 @@ fn is_comparison(binop: hir::BinOp) -> bool {
     "proper use of libc types in foreign item definitions"
 }
 
+declare_lint! {
+    /// The `cheri_capability_unions` lint detects unions in `extern` signatures that overlap a
+    /// CHERI capability with data that isn't a capability.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (fails on non-CHERI targets)
+    /// #[repr(C)]
+    /// pub union PtrOrAddr {
+    ///     ptr: *const u8,
+    ///     addr: usize,
+    /// }
+    ///
+    /// extern "C" {
+    ///     fn take(x: PtrOrAddr);
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: `extern` block uses union `PtrOrAddr`, which overlaps a capability with other data
+    ///  --> src/main.rs:8:16
+    ///   |
+    /// 8 |     fn take(x: PtrOrAddr);
+    ///   |                ^^^^^^^^^
+    ///   |
+    ///   = note: `#[warn(cheri_capability_unions)]` on by default
+    ///   = note: writing a field that isn't a capability clears the overlapping capability's tag
+    ///   = note: reading such a field after writing the capability gives the bits of the capability
+    /// note: the union is defined here
+    ///  --> src/main.rs:2:1
+    ///   |
+    /// 2 | pub union PtrOrAddr {
+    ///   | ^^^^^^^^^^^^^^^^^^^
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// On CHERI targets, pointers are capabilities that carry a validity tag in addition to their
+    /// bits. Any store of data that isn't a capability clears the tag of the capability it
+    /// overlaps, so a pointer can't be rebuilt from the other fields of such a union, and the
+    /// pointer field can only be used if it was the last one written. C code on the other side
+    /// of the boundary that treats the union as an integer and a pointer interchangeably will not
+    /// work as it does on other targets.
+    CHERI_CAPABILITY_UNIONS,
+    Warn,
+    "unions in foreign signatures that overlap capabilities with other data"
+}
+
+declare_lint! {
+    /// The `cheri_ffi_capability_mismatch` lint detects `extern` signatures whose pointer-sized
+    /// values are unlikely to match the C declaration on CHERI targets.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// #[allow(non_camel_case_types)]
+    /// type intptr_t = isize;
+    ///
+    /// extern "C" {
+    ///     fn stash(value: intptr_t);
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: `extern` block uses `intptr_t` as `isize`, which can't hold the capability that C's `intptr_t` holds
+    ///  --> src/main.rs:5:21
+    ///   |
+    /// 5 |     fn stash(value: intptr_t);
+    ///   |                     ^^^^^^^^
+    ///   |
+    ///   = note: `#[warn(cheri_ffi_capability_mismatch)]` on by default
+    ///   = note: on purecap targets `intptr_t` and `uintptr_t` are capabilities, twice as wide as `isize`
+    ///   = help: use a pointer type such as `*mut c_void` instead
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// In the purecap ABI C's `intptr_t` and `uintptr_t` are capabilities, while `isize` and
+    /// `usize` only hold an address. Bindings that declare them as integers, as the `libc` crate
+    /// does on other targets, pass half of the value in the wrong register and lose the tag.
+    ///
+    /// On hybrid targets only pointers qualified with `__capability` in C are capabilities, and
+    /// they are declared with a `#[cheri_capability]` type in Rust. A signature that passes
+    /// such a type next to a plain raw pointer is flagged as well, since a missing qualifier on
+    /// one of the pointers is the usual cause. Allow the lint if the C declaration really mixes
+    /// the two.
+    pub CHERI_FFI_CAPABILITY_MISMATCH,
+    Warn,
+    "pointer-sized values in foreign signatures that don't match the C declaration on CHERI"
+}
+
 declare_lint_pass!(ImproperCTypesDefinitions => [IMPROPER_CTYPES_DEFINITIONS]);
 
 #[derive(Clone, Copy)]
@@ -945,6 +1050,10 @@ fn check_type_for_ffi(&self, cache: &mut FxHashSet<Ty<'tcx>>, ty: Ty<'tcx>) -> F
                 if def.is_phantom_data() {
                     return FfiPhantom(ty);
                 }
+                if def.is_cheri_capability() {
+                    // Laid out as a capability pointer, like C's `void * __capability`.
+                    return FfiSafe;
+                }
                 match def.adt_kind() {
                     AdtKind::Struct | AdtKind::Union => {
                         if !def.repr().c() && !def.repr().transparent() {
@@ -1175,12 +1284,8 @@ fn emit_ffi_unsafe_type_lint(
         };
 
         self.cx.struct_span_lint(lint, sp, fluent::lint_improper_ctypes, |lint| {
-            let item_description = match self.mode {
-                CItemKind::Declaration => "block",
-                CItemKind::Definition => "fn",
-            };
             lint.set_arg("ty", ty);
-            lint.set_arg("desc", item_description);
+            lint.set_arg("desc", self.item_description());
             lint.span_label(sp, fluent::label);
             if let Some(help) = help {
                 lint.help(help);
@@ -1195,6 +1300,65 @@ fn emit_ffi_unsafe_type_lint(
         });
     }
 
+    /// Returns a union stored by value in `ty` that overlaps a capability with a field that
+    /// doesn't hold one.
+    fn find_capability_union(
+        &self,
+        cache: &mut FxHashSet<Ty<'tcx>>,
+        ty: Ty<'tcx>,
+    ) -> Option<Ty<'tcx>> {
+        if !cache.insert(ty) {
+            return None;
+        }
+
+        let tcx = self.cx.tcx;
+        match *ty.kind() {
+            ty::Adt(def, substs) if !def.is_box() => {
+                let field_tys = def.all_fields().map(|field| {
+                    tcx.normalize_erasing_regions(self.cx.param_env, field.ty(tcx, substs))
+                });
+                if def.is_union() {
+                    let (mut holds_capability, mut holds_other) = (false, false);
+                    for field_ty in field_tys.clone() {
+                        let Ok(layout) = self.cx.layout_of(field_ty) else { continue };
+                        if layout.contains_capability(self.cx) {
+                            holds_capability = true;
+                        } else if !layout.is_zst() {
+                            holds_other = true;
+                        }
+                    }
+                    if holds_capability && holds_other {
+                        return Some(ty);
+                    }
+                }
+                field_tys.into_iter().find_map(|ty| self.find_capability_union(cache, ty))
+            }
+            ty::Array(ty, _) => self.find_capability_union(cache, ty),
+            ty::Tuple(tys) => tys.iter().find_map(|ty| self.find_capability_union(cache, ty)),
+            _ => None,
+        }
+    }
+
+    fn check_for_capability_union(&mut self, sp: Span, ty: Ty<'tcx>) {
+        let Some(union_ty) = self.find_capability_union(&mut FxHashSet::default(), ty) else {
+            return;
+        };
+
+        let msg = fluent::lint_cheri_capability_union;
+        self.cx.struct_span_lint(CHERI_CAPABILITY_UNIONS, sp, msg, |lint| {
+            lint.set_arg("ty", union_ty);
+            lint.set_arg("desc", self.item_description());
+            lint.note(fluent::note);
+            lint.note(fluent::raw_bits_note);
+            if let ty::Adt(def, _) = union_ty.kind() {
+                if let Some(sp) = self.cx.tcx.hir().span_if_local(def.did()) {
+                    lint.span_note(sp, fluent::defined_note);
+                }
+            }
+            lint
+        });
+    }
+
     fn check_for_opaque_ty(&mut self, sp: Span, ty: Ty<'tcx>) -> bool {
         struct ProhibitOpaqueTypes<'a, 'tcx> {
             cx: &'a LateContext<'tcx>,
@@ -1265,7 +1429,7 @@ fn check_type_for_ffi_and_report_errors(
         }
 
         match self.check_type_for_ffi(&mut FxHashSet::default(), ty) {
-            FfiResult::FfiSafe => {}
+            FfiResult::FfiSafe => self.check_for_capability_union(sp, ty),
             FfiResult::FfiPhantom(ty) => {
                 self.emit_ffi_unsafe_type_lint(
                     ty,
@@ -1296,6 +1460,90 @@ fn check_foreign_fn(&mut self, id: hir::HirId, decl: &hir::FnDecl<'_>) {
             let ret_ty = sig.output();
             self.check_type_for_ffi_and_report_errors(ret_hir.span, ret_ty, false, true);
         }
+
+        match self.cx.sess().capability_abi() {
+            CapabilityAbi::Purecap => self.check_for_integer_intptr(decl),
+            CapabilityAbi::Hybrid => self.check_for_unqualified_pointers(sig, decl),
+            CapabilityAbi::None => {}
+        }
+    }
+
+    /// Checks for `intptr_t` and `uintptr_t` declared as integers, which are capabilities in the
+    /// purecap ABI.
+    fn check_for_integer_intptr(&mut self, decl: &hir::FnDecl<'_>) {
+        struct IntegerIntptr<'a, 'tcx> {
+            cx: &'a LateContext<'tcx>,
+            found: Vec<(Span, Symbol, Ty<'tcx>)>,
+        }
+
+        impl<'a, 'tcx> hir::intravisit::Visitor<'tcx> for IntegerIntptr<'a, 'tcx> {
+            fn visit_ty(&mut self, ty: &'tcx hir::Ty<'tcx>) {
+                if let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = ty.kind
+                    && let Res::Def(DefKind::TyAlias, def_id) = path.res
+                {
+                    let name = self.cx.tcx.item_name(def_id);
+                    let aliased = self.cx.tcx.type_of(def_id);
+                    if matches!(name.as_str(), "intptr_t" | "uintptr_t") && aliased.is_integral() {
+                        self.found.push((ty.span, name, aliased));
+                    }
+                }
+                hir::intravisit::walk_ty(self, ty);
+            }
+        }
+
+        let mut visitor = IntegerIntptr { cx: self.cx, found: Vec::new() };
+        hir::intravisit::Visitor::visit_fn_decl(&mut visitor, decl);
+        for (sp, name, ty) in visitor.found {
+            let msg = fluent::lint_cheri_integer_intptr;
+            self.cx.struct_span_lint(CHERI_FFI_CAPABILITY_MISMATCH, sp, msg, |lint| {
+                lint.set_arg("desc", self.item_description());
+                lint.set_arg("name", name);
+                lint.set_arg("ty", ty);
+                lint.note(fluent::note);
+                lint.help(fluent::help);
+                lint
+            });
+        }
+    }
+
+    /// Checks for raw pointers passed next to `#[cheri_capability]` types in hybrid code, which
+    /// usually means that a `__capability` qualifier is missing on the Rust side.
+    fn check_for_unqualified_pointers(&mut self, sig: ty::FnSig<'tcx>, decl: &hir::FnDecl<'_>) {
+        let tcx = self.cx.tcx;
+        let mut tys: Vec<_> = iter::zip(sig.inputs(), decl.inputs)
+            .map(|(&ty, hir_ty)| (ty, hir_ty.span))
+            .collect();
+        if let hir::FnRetTy::Return(ref ret_hir) = decl.output {
+            tys.push((sig.output(), ret_hir.span));
+        }
+        for (ty, _) in &mut tys {
+            *ty = tcx.normalize_erasing_regions(self.cx.param_env, *ty);
+        }
+
+        let is_capability =
+            |ty: Ty<'tcx>| matches!(ty.kind(), ty::Adt(def, _) if def.is_cheri_capability());
+        if !tys.iter().any(|&(ty, _)| is_capability(ty)) {
+            return;
+        }
+        for (ty, sp) in tys {
+            if !ty.is_unsafe_ptr() {
+                continue;
+            }
+            let msg = fluent::lint_cheri_unqualified_pointer;
+            self.cx.struct_span_lint(CHERI_FFI_CAPABILITY_MISMATCH, sp, msg, |lint| {
+                lint.set_arg("desc", self.item_description());
+                lint.note(fluent::note);
+                lint.help(fluent::help);
+                lint
+            });
+        }
+    }
+
+    fn item_description(&self) -> &'static str {
+        match self.mode {
+            CItemKind::Declaration => "block",
+            CItemKind::Definition => "fn",
+        }
     }
 
     fn check_foreign_static(&mut self, id: hir::HirId, span: Span) {
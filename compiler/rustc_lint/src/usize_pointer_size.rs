@@ -0,0 +1,123 @@
+use crate::{LateContext, LateLintPass, LintContext};
+use rustc_errors::fluent;
+use rustc_hir as hir;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::lint::FutureIncompatibilityReason;
+use rustc_span::sym;
+
+declare_lint! {
+    /// The `usize_pointer_size_assumptions` lint detects code that compares
+    /// the size of `usize` with the size of a pointer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// const _: () = assert!(
+    ///     std::mem::size_of::<usize>() == std::mem::size_of::<*const u8>()
+    /// );
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// On most targets a pointer is just an address, and has the size of a
+    /// `usize`. That is not the case on CHERI targets, where a pointer is a
+    /// capability that holds its bounds and permissions next to the address,
+    /// and is twice as wide as a `usize`. Compile-time assertions that the two
+    /// sizes are equal then fail, and code that computes the size of one from
+    /// the other, such as the length of an array that a pointer is copied
+    /// into, silently gets it wrong.
+    ///
+    /// Use `size_of::<*const T>()` where the size of a pointer is meant, and
+    /// `size_of::<usize>()` only for the size of an address.
+    pub USIZE_POINTER_SIZE_ASSUMPTIONS,
+    Warn,
+    "detects code that assumes that `usize` and pointers have the same size",
+    @future_incompatible = FutureIncompatibleInfo {
+        reason: FutureIncompatibilityReason::Custom(
+            "pointers are larger than `usize` on some targets, such as CHERI, and may become \
+             larger on others"
+        ),
+        reference: "issue #95228 <https://github.com/rust-lang/rust/issues/95228>",
+    };
+}
+
+declare_lint_pass!(UsizePointerSize => [USIZE_POINTER_SIZE_ASSUMPTIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for UsizePointerSize {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let (lhs, rhs) = match expr.kind {
+            hir::ExprKind::Binary(op, lhs, rhs) => match op.node {
+                hir::BinOpKind::Eq | hir::BinOpKind::Ne | hir::BinOpKind::Sub => (lhs, rhs),
+                _ => return,
+            },
+            // `assert_eq!(a, b)` matches on `(&a, &b)`.
+            hir::ExprKind::Tup(
+                [
+                    hir::Expr { kind: hir::ExprKind::AddrOf(_, _, lhs), .. },
+                    hir::Expr { kind: hir::ExprKind::AddrOf(_, _, rhs), .. },
+                ],
+            ) if expr.span.from_expansion() => (*lhs, *rhs),
+            _ => return,
+        };
+        let (Some(lhs_ty), Some(rhs_ty)) = (size_of_ty(cx, lhs), size_of_ty(cx, rhs)) else {
+            return;
+        };
+        let (address_ty, ptr_ty) = if is_address(lhs_ty) && is_thin_pointer(cx, rhs_ty) {
+            (lhs_ty, rhs_ty)
+        } else if is_thin_pointer(cx, lhs_ty) && is_address(rhs_ty) {
+            (rhs_ty, lhs_ty)
+        } else {
+            return;
+        };
+
+        let sizes = cx.layout_of(address_ty).ok().zip(cx.layout_of(ptr_ty).ok());
+        let span = expr.span.source_callsite();
+        cx.struct_span_lint(
+            USIZE_POINTER_SIZE_ASSUMPTIONS,
+            span,
+            fluent::lint_usize_pointer_size_assumption,
+            |lint| {
+                lint.set_arg("address_ty", address_ty);
+                lint.set_arg("ptr_ty", ptr_ty);
+                if let Some((address, ptr)) = sizes && address.size != ptr.size {
+                    lint.set_arg("address_size", address.size.bytes());
+                    lint.set_arg("ptr_size", ptr.size.bytes());
+                    lint.note(fluent::differ_note);
+                }
+                lint.help(fluent::help);
+                lint
+            },
+        );
+    }
+}
+
+/// Returns `T` if `expr` is a call to `size_of::<T>()`.
+fn size_of_ty<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> Option<Ty<'tcx>> {
+    let hir::ExprKind::Call(callee, []) = expr.kind else { return None };
+    let hir::ExprKind::Path(qpath) = &callee.kind else { return None };
+    let def_id = cx.qpath_res(qpath, callee.hir_id).opt_def_id()?;
+    if !cx.tcx.is_diagnostic_item(sym::mem_size_of, def_id) {
+        return None;
+    }
+    let ty::FnDef(_, substs) = cx.typeck_results().node_type(callee.hir_id).kind() else {
+        return None;
+    };
+    Some(substs.type_at(0))
+}
+
+fn is_address(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Uint(ty::UintTy::Usize) | ty::Int(ty::IntTy::Isize))
+}
+
+fn is_thin_pointer<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match *ty.kind() {
+        ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) | ty::Ref(_, pointee, _) => {
+            pointee.is_sized(cx.tcx, cx.param_env)
+        }
+        ty::FnPtr(_) => true,
+        _ => false,
+    }
+}
@@ -2682,6 +2682,135 @@
     @feature_gate = sym::strict_provenance;
 }
 
+declare_lint! {
+    /// The `cheri_capability_transmutes` lint detects transmutes between a
+    /// pointer and an integer of the same size on CHERI purecap targets.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// fn bits(p: *const u8) -> u128 {
+    ///     unsafe { std::mem::transmute(p) }
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: transmuting a capability to `u128` loses its tag
+    ///  --> lint_example.rs:2:14
+    ///   |
+    /// 2 |     unsafe { std::mem::transmute(p) }
+    ///   |              ^^^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: source type: `*const u8`, target type: `u128`
+    ///   = note: the integer holds the bits of the capability, but not its validity tag, so transmuting it back gives a pointer that can't be used
+    ///   = note: `#[warn(cheri_capability_transmutes)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// On CHERI purecap targets pointers are capabilities, which are as wide
+    /// as a `u128` on Morello or a `u64` on 32-bit RISC-V, so transmuting
+    /// between them passes the size check. The validity tag of a capability
+    /// is not part of its bits, however, and is never stored in an integer.
+    /// The integer only holds the address and the encoded metadata, and any
+    /// pointer transmuted from an integer faults when it is used.
+    ///
+    /// Use `addr` to get the address of a pointer, and `with_addr` to derive
+    /// a pointer with a given address from one that is still valid.
+    pub CHERI_CAPABILITY_TRANSMUTES,
+    Warn,
+    "detects transmutes between capabilities and integers on CHERI purecap targets"
+}
+
+declare_lint! {
+    /// The `cheri_address_arithmetic` lint detects pointers that are offset by
+    /// doing arithmetic on their address and casting the result back to a
+    /// pointer, on CHERI purecap targets.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// fn second(p: *const u32) -> *const u32 {
+    ///     let addr = p as usize;
+    ///     let next = addr + 4;
+    ///     next as *const u32
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: pointer arithmetic on the address of a pointer cannot produce a valid capability
+    ///  --> lint_example.rs:4:5
+    ///   |
+    /// 4 |     next as *const u32
+    ///   |     ^^^^^^^^^^^^^^^^^^ help: use `wrapping_byte_add` to offset the pointer itself: `p.wrapping_byte_add(4)`
+    ///   |
+    ///   = note: the address doesn't carry the capability of the pointer, so the pointer cast from it has its tag cleared
+    ///   = note: `#[warn(cheri_address_arithmetic)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// Offsetting a pointer through its address is a common idiom in code
+    /// written for targets where pointers are plain integers. On CHERI
+    /// purecap targets the pointer that is cast back from the address is not
+    /// a valid capability, and faults when it is used. `wrapping_byte_add`
+    /// and `wrapping_byte_sub` do the same arithmetic on the pointer, and keep
+    /// its capability.
+    ///
+    /// The lint follows the address through simple assignments within a
+    /// function, so that it also catches the idiom when it is split across
+    /// several statements.
+    pub CHERI_ADDRESS_ARITHMETIC,
+    Warn,
+    "detects pointer arithmetic done on addresses on CHERI purecap targets"
+}
+
+declare_lint! {
+    /// The `cheri_unrepresentable_bounds` lint detects pointers in the value
+    /// of a constant or static whose capability can't have the bounds of the
+    /// allocation they point to, on CHERI purecap targets.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a purecap target)
+    /// static TABLE: [u8; 20001] = [0; 20001];
+    /// static TABLE_REF: &[u8; 20001] = &TABLE;
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: the capability of a pointer in this static can't have the bounds of the allocation it points to
+    ///  --> lint_example.rs:2:1
+    ///   |
+    /// 2 | static TABLE_REF: &[u8; 20001] = &TABLE;
+    ///   | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: the bounds of the 20001-byte allocation are rounded up to 20008 bytes
+    ///   = note: the bounds must be aligned to 8 bytes, but the allocation is only aligned to 1 byte
+    ///   = help: pad the allocation to 20008 bytes and align it to 8 bytes, for example with a `#[repr(C, align(8))]` wrapper
+    ///   = note: `#[warn(cheri_unrepresentable_bounds)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// A capability stores its bounds in a compressed form that can only
+    /// represent the exact bounds of small objects. The length of a larger
+    /// object is rounded up, and its base must be aligned accordingly. For a
+    /// pointer to a global, the capability is only created by the linker or
+    /// the loader, which then either fail on the relocation or silently give
+    /// the capability bounds that cover the neighbouring globals too. The
+    /// same goes for a pointer so far outside of its allocation that its
+    /// address can't be represented together with the bounds at all.
+    pub CHERI_UNREPRESENTABLE_BOUNDS,
+    Warn,
+    "detects pointers in constants whose bounds can't be represented on CHERI purecap targets"
+}
+
 declare_lint! {
     /// The `const_evaluatable_unchecked` lint detects a generic constant used
     /// in a type.
@@ -3240,6 +3369,9 @@
         CENUM_IMPL_DROP_CAST,
         FUZZY_PROVENANCE_CASTS,
         LOSSY_PROVENANCE_CASTS,
+        CHERI_CAPABILITY_TRANSMUTES,
+        CHERI_ADDRESS_ARITHMETIC,
+        CHERI_UNREPRESENTABLE_BOUNDS,
         CONST_EVALUATABLE_UNCHECKED,
         INEFFECTIVE_UNSTABLE_TRAIT_IMPL,
         MUST_NOT_SUSPEND,
@@ -406,6 +406,12 @@ extern "C" LLVMValueRef LLVMRustBuildAtomicStore(LLVMBuilderRef B,
   return wrap(SI);
 }
 
+// Makes a compare-exchange of capabilities compare every bit of the capability,
+// including its tag, rather than only its address.
+extern "C" void LLVMRustSetCmpXchgExact(LLVMValueRef CmpXchgInst, bool Exact) {
+  unwrap<AtomicCmpXchgInst>(CmpXchgInst)->setExactCompare(Exact);
+}
+
 enum class LLVMRustAsmDialect {
   Att,
   Intel,
@@ -703,6 +709,17 @@ extern "C" void LLVMRustAddModuleFlag(
   unwrap(M)->addModuleFlag(MergeBehavior, Name, Value);
 }
 
+extern "C" void LLVMRustAddModuleFlagString(
+    LLVMModuleRef M,
+    Module::ModFlagBehavior MergeBehavior,
+    const char *Name,
+    const char *Value,
+    size_t ValueLen) {
+  LLVMContext &Ctx = unwrap(M)->getContext();
+  unwrap(M)->addModuleFlag(MergeBehavior, Name,
+                           MDString::get(Ctx, StringRef(Value, ValueLen)));
+}
+
 extern "C" bool LLVMRustHasModuleFlag(LLVMModuleRef M, const char *Name,
                                       size_t Len) {
   return unwrap(M)->getModuleFlag(StringRef(Name, Len)) != nullptr;
@@ -855,6 +872,20 @@ extern "C" LLVMMetadataRef LLVMRustDIBuilderCreateMemberType(
                                         fromRust(Flags), unwrapDI<DIType>(Ty)));
 }
 
+extern "C" LLVMMetadataRef LLVMRustDIBuilderCreateBitFieldMemberType(
+    LLVMRustDIBuilderRef Builder, LLVMMetadataRef Scope,
+    const char *Name, size_t NameLen,
+    LLVMMetadataRef File, unsigned LineNo, uint64_t SizeInBits,
+    uint64_t OffsetInBits, uint64_t StorageOffsetInBits, LLVMRustDIFlags Flags,
+    LLVMMetadataRef Ty) {
+  return wrap(Builder->createBitFieldMemberType(unwrapDI<DIDescriptor>(Scope),
+                                                StringRef(Name, NameLen),
+                                                unwrapDI<DIFile>(File), LineNo,
+                                                SizeInBits, OffsetInBits,
+                                                StorageOffsetInBits, fromRust(Flags),
+                                                unwrapDI<DIType>(Ty)));
+}
+
 extern "C" LLVMMetadataRef LLVMRustDIBuilderCreateVariantMemberType(
     LLVMRustDIBuilderRef Builder, LLVMMetadataRef Scope,
     const char *Name, size_t NameLen, LLVMMetadataRef File, unsigned LineNo,
@@ -1834,7 +1865,7 @@ using LLVMDiagnosticHandlerTy = DiagnosticHandler::DiagnosticHandlerTy;
 //
 // When RemarkAllPasses is true, remarks are enabled for all passes. Otherwise
 // the RemarkPasses array specifies individual passes for which remarks will be
-// enabled.
+// enabled, or "cheri" for all of the passes CHERI LLVM adds.
 extern "C" void LLVMRustContextConfigureDiagnosticHandler(
     LLVMContextRef C, LLVMDiagnosticHandlerTy DiagnosticHandlerCallback,
     void *DiagnosticHandlerContext, bool RemarkAllPasses,
@@ -1881,7 +1912,8 @@ extern "C" void LLVMRustContextConfigureDiagnosticHandler(
         return true;
 
       for (auto &Pass : RemarkPasses)
-        if (Pass == PassName)
+        if (Pass == PassName ||
+            (Pass == "cheri" && PassName.startswith_insensitive("cheri")))
           return true;
 
       return false;
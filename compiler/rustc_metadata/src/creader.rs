@@ -499,6 +499,7 @@ fn load_proc_macro<'b>(
         locator.is_proc_macro = true;
         locator.target = &self.sess.host;
         locator.triple = TargetTriple::from_triple(config::host_triple());
+        locator.capability_abi = None;
         locator.filesearch = self.sess.host_filesearch(path_kind);
 
         let Some(host_result) = self.load(locator)? else {
@@ -7,7 +7,7 @@
 use rustc_macros::Diagnostic;
 use rustc_session::config;
 use rustc_span::{sym, Span, Symbol};
-use rustc_target::spec::{PanicStrategy, TargetTriple};
+use rustc_target::spec::{CapabilityAbi, PanicStrategy, TargetTriple};
 
 use crate::locator::CrateFlavor;
 
@@ -570,6 +570,19 @@ pub struct NoCrateWithTriple<'a> {
     pub found_crates: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(metadata_no_crate_with_capability_abi)]
+#[note(metadata_found_crate_versions)]
+#[help]
+pub struct NoCrateWithCapabilityAbi {
+    #[primary_span]
+    pub span: Span,
+    pub crate_name: Symbol,
+    pub locator_capability_abi: CapabilityAbi,
+    pub add_info: String,
+    pub found_crates: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(metadata_found_staticlib, code = "E0462")]
 #[note(metadata_found_crate_versions)]
@@ -217,8 +217,8 @@
     CannotFindCrate, CrateLocationUnknownType, DlError, ExternLocationNotExist,
     ExternLocationNotFile, FoundStaticlib, IncompatibleRustc, InvalidMetadataFiles,
     LibFilenameForm, MultipleCandidates, MultipleMatchingCrates, NewerCrateVersion,
-    NoCrateWithTriple, NoDylibPlugin, NonAsciiName, StableCrateIdCollision, SymbolConflictsCurrent,
-    SymbolConflictsOthers,
+    NoCrateWithCapabilityAbi, NoCrateWithTriple, NoDylibPlugin, NonAsciiName,
+    StableCrateIdCollision, SymbolConflictsCurrent, SymbolConflictsOthers,
 };
 use crate::rmeta::{rustc_version, MetadataBlob, METADATA_HEADER};
 
@@ -236,7 +236,7 @@
 use rustc_session::Session;
 use rustc_span::symbol::Symbol;
 use rustc_span::Span;
-use rustc_target::spec::{Target, TargetTriple};
+use rustc_target::spec::{CapabilityAbi, Target, TargetTriple};
 
 use snap::read::FrameDecoder;
 use std::borrow::Cow;
@@ -259,6 +259,8 @@ pub(crate) struct CrateLocator<'a> {
     extra_filename: Option<&'a str>,
     pub target: &'a Target,
     pub triple: TargetTriple,
+    /// The capability ABI that crates must be link-compatible with, if it matters.
+    pub capability_abi: Option<CapabilityAbi>,
     pub filesearch: FileSearch<'a>,
     pub is_proc_macro: bool,
 
@@ -351,6 +353,7 @@ pub(crate) fn new(
             } else {
                 sess.opts.target_triple.clone()
             },
+            capability_abi: if is_host { None } else { Some(sess.capability_abi()) },
             filesearch: if is_host {
                 sess.host_filesearch(path_kind)
             } else {
@@ -364,6 +367,7 @@ pub(crate) fn new(
     pub(crate) fn reset(&mut self) {
         self.crate_rejections.via_hash.clear();
         self.crate_rejections.via_triple.clear();
+        self.crate_rejections.via_capability_abi.clear();
         self.crate_rejections.via_kind.clear();
         self.crate_rejections.via_version.clear();
         self.crate_rejections.via_filename.clear();
@@ -679,6 +683,21 @@ fn crate_matches(&mut self, metadata: &MetadataBlob, libpath: &Path) -> Option<S
             return None;
         }
 
+        if let Some(capability_abi) = self.capability_abi {
+            if !root.capability_abi().is_link_compatible(capability_abi) {
+                info!(
+                    "Rejecting via capability ABI: expected {:?} got {:?}",
+                    capability_abi,
+                    root.capability_abi()
+                );
+                self.crate_rejections.via_capability_abi.push(CrateMismatch {
+                    path: libpath.to_path_buf(),
+                    got: root.capability_abi().desc().to_string(),
+                });
+                return None;
+            }
+        }
+
         let hash = root.hash();
         if let Some(expected_hash) = self.hash {
             if hash != expected_hash {
@@ -902,6 +921,7 @@ struct CrateMismatch {
 struct CrateRejections {
     via_hash: Vec<CrateMismatch>,
     via_triple: Vec<CrateMismatch>,
+    via_capability_abi: Vec<CrateMismatch>,
     via_kind: Vec<CrateMismatch>,
     via_version: Vec<CrateMismatch>,
     via_filename: Vec<CrateMismatch>,
@@ -1074,6 +1094,23 @@ pub(crate) fn report(self, sess: &Session, span: Span, missing_core: bool) {
                         add_info,
                         found_crates,
                     });
+                } else if !locator.crate_rejections.via_capability_abi.is_empty() {
+                    let mismatches = locator.crate_rejections.via_capability_abi.iter();
+                    for CrateMismatch { path, got } in mismatches {
+                        found_crates.push_str(&format!(
+                            "\ncrate `{}`, {} capability ABI: {}",
+                            crate_name,
+                            got,
+                            path.display(),
+                        ));
+                    }
+                    sess.emit_err(NoCrateWithCapabilityAbi {
+                        span,
+                        crate_name,
+                        locator_capability_abi: locator.capability_abi.unwrap(),
+                        add_info,
+                        found_crates,
+                    });
                 } else if !locator.crate_rejections.via_kind.is_empty() {
                     let mismatches = locator.crate_rejections.via_kind.iter();
                     for CrateMismatch { path, .. } in mismatches {
@@ -747,6 +747,10 @@ pub(crate) fn triple(&self) -> &TargetTriple {
         &self.triple
     }
 
+    pub(crate) fn capability_abi(&self) -> CapabilityAbi {
+        self.capability_abi
+    }
+
     pub(crate) fn decode_crate_deps<'a>(
         &self,
         metadata: &'a MetadataBlob,
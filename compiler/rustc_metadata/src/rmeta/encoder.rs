@@ -664,6 +664,7 @@ macro_rules! stat {
                 name: tcx.crate_name(LOCAL_CRATE),
                 extra_filename: tcx.sess.opts.cg.extra_filename.clone(),
                 triple: tcx.sess.opts.target_triple.clone(),
+                capability_abi: tcx.sess.capability_abi(),
                 hash: tcx.crate_hash(LOCAL_CRATE),
                 stable_crate_id: tcx.def_path_hash(LOCAL_CRATE.as_def_id()).stable_crate_id(),
                 required_panic_strategy: tcx.required_panic_strategy(LOCAL_CRATE),
@@ -31,7 +31,7 @@
 use rustc_span::hygiene::{ExpnIndex, MacroKind};
 use rustc_span::symbol::{Ident, Symbol};
 use rustc_span::{self, ExpnData, ExpnHash, ExpnId, Span};
-use rustc_target::spec::{PanicStrategy, TargetTriple};
+use rustc_target::spec::{CapabilityAbi, PanicStrategy, TargetTriple};
 
 use std::marker::PhantomData;
 use std::num::NonZeroUsize;
@@ -216,6 +216,7 @@ pub(crate) struct ProcMacroData {
 pub(crate) struct CrateRoot {
     name: Symbol,
     triple: TargetTriple,
+    capability_abi: CapabilityAbi,
     extra_filename: String,
     hash: Svh,
     stable_crate_id: StableCrateId,
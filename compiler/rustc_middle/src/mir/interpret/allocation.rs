@@ -37,7 +37,8 @@ pub struct Allocation<Prov = AllocId, Extra = ()> {
     /// Maps from byte addresses to extra provenance data for each pointer.
     /// Only the first byte of a pointer is inserted into the map; i.e.,
     /// every entry in this map applies to `pointer_size` consecutive bytes starting
-    /// at the given offset.
+    /// at the given offset. On capability targets that is the size of the whole capability,
+    /// not just of the address it carries.
     provenance: ProvenanceMap<Prov>,
     /// Denotes which part of this allocation is initialized.
     init_mask: InitMask,
@@ -279,8 +280,9 @@ pub fn adjust_from_tcx<Prov, Extra, Err>(
         // Compute new pointer provenance, which also adjusts the bytes.
         let mut bytes = self.bytes;
         let mut new_provenance = Vec::with_capacity(self.provenance.0.len());
-        // TODO: More complexity needed here. Unsure if this should be val_size or ty_size.
-        let ptr_size = cx.data_layout().ptr_layout(None).val_size.bytes_usize();
+        // The offset is stored across all the bytes of the pointer; on capability targets the
+        // bytes that don't hold the address are always zero.
+        let ptr_size = cx.data_layout().ptr_layout(None).ty_size.bytes_usize();
         let endian = cx.data_layout().endian;
         for &(offset, alloc_id) in self.provenance.iter() {
             let idx = offset.bytes_usize();
@@ -427,7 +429,6 @@ pub fn read_scalar(
         let bits = read_target_uint(cx.data_layout().endian, bytes).unwrap();
 
         if read_provenance {
-            // TODO: More complexity needed here. val vs ty_size.
             assert_eq!(range.total_size, cx.data_layout().ptr_layout(None).ty_size);
 
             // When reading data with provenance, the easy case is finding provenance exactly where we
@@ -520,11 +521,10 @@ impl<Prov: Copy, Extra> Allocation<Prov, Extra> {
     fn range_get_provenance(&self, cx: &impl HasDataLayout, range: AllocRange) -> &[(Size, Prov)] {
         // We have to go back `pointer_size - 1` bytes, as that one would still overlap with
         // the beginning of this range.
-        // TODO: More complexity needed here. val_size vs ty_size.
         let start = range
             .start
             .bytes()
-            .saturating_sub(cx.data_layout().ptr_layout(None).val_size.bytes() - 1);
+            .saturating_sub(cx.data_layout().ptr_layout(None).ty_size.bytes() - 1);
         self.provenance.range(Size::from_bytes(start)..range.end())
     }
 
@@ -553,10 +553,16 @@ pub fn range_has_provenance(&self, cx: &impl HasDataLayout, range: AllocRange) -
     /// uninitialized. This is a somewhat odd "spooky action at a distance",
     /// but it allows strictly more code to run than if we would just error
     /// immediately in that case.
+    ///
+    /// On CHERI purecap targets this is also what the hardware does: writing to part of a
+    /// capability clears its tag. The bits that are left hold the address of the pointer, which
+    /// isn't known during const evaluation, so the bytes are uninitialized there as well.
     fn clear_provenance(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> AllocResult
     where
         Prov: Provenance,
     {
+        let err_on_partial_overwrite =
+            Prov::ERR_ON_PARTIAL_PTR_OVERWRITE && !cx.data_layout().is_cheri_purecap;
         // Find the start and end of the given range and its outermost provenance.
         let (first, last) = {
             // Find all provenance overlapping the given range.
@@ -567,8 +573,7 @@ fn clear_provenance(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> Al
 
             (
                 provenance.first().unwrap().0,
-                // TODO: More complexity needed here. val_size vs ty_size.
-                provenance.last().unwrap().0 + cx.data_layout().ptr_layout(None).val_size,
+                provenance.last().unwrap().0 + cx.data_layout().ptr_layout(None).ty_size,
             )
         };
         let start = range.start;
@@ -578,7 +583,7 @@ fn clear_provenance(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> Al
         // FIXME: Miri should preserve partial provenance; see
         // https://github.com/rust-lang/miri/issues/2181.
         if first < start {
-            if Prov::ERR_ON_PARTIAL_PTR_OVERWRITE {
+            if err_on_partial_overwrite {
                 return Err(AllocError::PartialPointerOverwrite(first));
             }
             warn!(
@@ -587,10 +592,9 @@ fn clear_provenance(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> Al
             self.init_mask.set_range(first, start, false);
         }
         if last > end {
-            if Prov::ERR_ON_PARTIAL_PTR_OVERWRITE {
+            if err_on_partial_overwrite {
                 return Err(AllocError::PartialPointerOverwrite(
-                    // TODO: More complexity needed here. val_size vs ty_size.
-                    last - cx.data_layout().ptr_layout(None).val_size,
+                    last - cx.data_layout().ptr_layout(None).ty_size,
                 ));
             }
             warn!(
@@ -637,6 +641,9 @@ fn deref(&self) -> &Self::Target {
 /// Offsets are already adjusted to the destination allocation.
 pub struct AllocationProvenance<Prov> {
     dest_provenance: Vec<(Size, Prov)>,
+    /// The parts of pointers that only partially lie in the source range. They lose their
+    /// provenance and are uninitialized in the destination, see `clear_provenance`.
+    dest_partial: Vec<(Size, Size)>,
 }
 
 impl<Prov: Copy, Extra> Allocation<Prov, Extra> {
@@ -649,10 +656,38 @@ pub fn prepare_provenance_copy(
     ) -> AllocationProvenance<Prov> {
         let provenance = self.range_get_provenance(cx, src);
         if provenance.is_empty() {
-            return AllocationProvenance { dest_provenance: Vec::new() };
+            return AllocationProvenance { dest_provenance: Vec::new(), dest_partial: Vec::new() };
         }
 
         let size = src.total_size;
+        let ptr_size = cx.data_layout().ptr_layout(None).ty_size;
+        let mut dest_partial = Vec::new();
+        // Pointers can only overlap the edges of the range on CHERI purecap targets, where
+        // copying part of a capability is allowed but doesn't copy its provenance.
+        let provenance = match provenance {
+            [(first, _), ..] if *first < src.start => {
+                let end = (*first + ptr_size).min(src.end());
+                dest_partial.push((Size::ZERO, end - src.start));
+                &provenance[1..]
+            }
+            _ => provenance,
+        };
+        let provenance = match provenance {
+            [.., (last, _)] if *last + ptr_size > src.end() => {
+                dest_partial.push((*last - src.start, size));
+                &provenance[..provenance.len() - 1]
+            }
+            _ => provenance,
+        };
+        let dest_partial = (0..count)
+            .flat_map(|i| {
+                dest_partial.iter().map(move |&(start, end)| {
+                    let dest_offset = dest + size * i; // `Size` operations
+                    (dest_offset + start, dest_offset + end)
+                })
+            })
+            .collect();
+
         let mut new_provenance = Vec::with_capacity(provenance.len() * (count as usize));
 
         // If `count` is large, this is rather wasteful -- we are allocating a big array here, which
@@ -672,7 +707,7 @@ pub fn prepare_provenance_copy(
             }));
         }
 
-        AllocationProvenance { dest_provenance: new_provenance }
+        AllocationProvenance { dest_provenance: new_provenance, dest_partial }
     }
 
     /// Applies a provenance copy.
@@ -683,6 +718,9 @@ pub fn prepare_provenance_copy(
     /// It only exists to support an efficient implementation of `mem_copy_repeatedly`.
     pub fn mark_provenance_range(&mut self, provenance: AllocationProvenance<Prov>) {
         self.provenance.0.insert_presorted(provenance.dest_provenance);
+        for (start, end) in provenance.dest_partial {
+            self.init_mask.set_range(start, end, false);
+        }
     }
 }
 
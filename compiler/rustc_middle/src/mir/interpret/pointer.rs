@@ -13,15 +13,16 @@
 pub trait PointerArithmetic: HasDataLayout {
     // These are not supposed to be overridden.
 
+    /// The size of a pointer in memory. On capability targets this is the size of the whole
+    /// capability.
     #[inline(always)]
     fn pointer_ty_size(&self) -> Size {
-        // TODO: More complexity needed here.
         self.data_layout().ptr_layout(None).ty_size
     }
 
+    /// The size of the address a pointer carries, which is also the size of `usize`.
     #[inline(always)]
     fn pointer_val_size(&self) -> Size {
-        // TODO: More complexity needed here.
         self.data_layout().ptr_layout(None).val_size
     }
 
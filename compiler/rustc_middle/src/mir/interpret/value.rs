@@ -268,7 +268,7 @@ pub fn from_i64(i: i64) -> Self {
 
     #[inline]
     pub fn from_machine_isize(i: i64, cx: &impl HasDataLayout) -> Self {
-        Self::from_int(i, cx.pointer_ty_size())
+        Self::from_int(i, cx.pointer_val_size())
     }
 
     #[inline]
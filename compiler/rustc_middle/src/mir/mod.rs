@@ -2791,7 +2791,7 @@ fn pretty_print_const_value<'tcx>(
                 }
             }
             (ConstValue::ByRef { alloc, offset }, ty::Array(t, n)) if *t == u8_type => {
-                // TODO: More complexity needed here?
+                // The length is a `usize`, which is address-sized.
                 let n = n.kind().try_to_bits(tcx.data_layout.ptr_layout(None).val_size).unwrap();
                 // cast is ok because we already checked for pointer size (32 or 64 bit) above
                 let range = AllocRange {
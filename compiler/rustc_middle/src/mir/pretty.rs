@@ -865,8 +865,9 @@ fn write_allocation_bytes<'tcx, Prov: Provenance, Extra>(
     let mut i = Size::ZERO;
     let mut line_start = Size::ZERO;
 
-    // TODO: More complexity needed here. val_size vs ty_size.
-    let ptr_size = tcx.data_layout.ptr_layout(None).val_size;
+    // Pointers take up their whole type size in memory, which on CHERI targets is larger than
+    // the address they carry.
+    let ptr_size = tcx.data_layout.ptr_layout(None).ty_size;
 
     let mut ascii = String::new();
 
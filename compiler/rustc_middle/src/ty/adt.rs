@@ -51,6 +51,8 @@ pub struct AdtFlags: u32 {
         const IS_VARIANT_LIST_NON_EXHAUSTIVE = 1 << 8;
         /// Indicates whether the type is `UnsafeCell`.
         const IS_UNSAFE_CELL              = 1 << 9;
+        /// Indicates whether the type is a `#[cheri_capability]` struct.
+        const IS_CHERI_CAPABILITY         = 1 << 10;
     }
 }
 
@@ -249,6 +251,9 @@ pub(super) fn new(
         if Some(did) == tcx.lang_items().unsafe_cell_type() {
             flags |= AdtFlags::IS_UNSAFE_CELL;
         }
+        if kind == AdtKind::Struct && tcx.has_attr(did, sym::cheri_capability) {
+            flags |= AdtFlags::IS_CHERI_CAPABILITY;
+        }
 
         AdtDefData { did, variants, flags, repr }
     }
@@ -341,6 +346,13 @@ pub fn is_unsafe_cell(self) -> bool {
         self.flags().contains(AdtFlags::IS_UNSAFE_CELL)
     }
 
+    /// Returns `true` if this is a `#[cheri_capability]` struct, which is laid out as a
+    /// capability pointer rather than as its (zero-sized) fields.
+    #[inline]
+    pub fn is_cheri_capability(self) -> bool {
+        self.flags().contains(AdtFlags::IS_CHERI_CAPABILITY)
+    }
+
     /// Returns `true` if this is `ManuallyDrop<T>`.
     #[inline]
     pub fn is_manually_drop(self) -> bool {
@@ -284,7 +284,7 @@ pub fn to_bits(self, target_size: Size) -> Result<u128, Size> {
 
     #[inline]
     pub fn try_to_machine_usize<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Result<u64, Size> {
-        // TODO: More complexity needed here.
+        // `usize` is address-sized, which is narrower than a capability on CHERI targets.
         Ok(self.to_bits(tcx.data_layout.ptr_layout(None).val_size)? as u64)
     }
 
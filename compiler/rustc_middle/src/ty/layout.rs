@@ -60,7 +60,6 @@ fn from_attr<C: HasDataLayout>(cx: &C, ity: attr::IntType) -> Integer {
             attr::SignedInt(ast::IntTy::I64) | attr::UnsignedInt(ast::UintTy::U64) => I64,
             attr::SignedInt(ast::IntTy::I128) | attr::UnsignedInt(ast::UintTy::U128) => I128,
             attr::SignedInt(ast::IntTy::Isize) | attr::UnsignedInt(ast::UintTy::Usize) => {
-                // TODO: More complexity needed here?
                 dl.ptr_sized_integer(None)
             }
         }
@@ -75,7 +74,7 @@ fn from_int_ty<C: HasDataLayout>(cx: &C, ity: ty::IntTy) -> Integer {
             ty::IntTy::I32 => I32,
             ty::IntTy::I64 => I64,
             ty::IntTy::I128 => I128,
-            // TODO: More complexity needed here?
+            // `isize` is as wide as an address, not as a capability.
             ty::IntTy::Isize => dl.ptr_sized_integer(None),
         }
     }
@@ -88,7 +87,7 @@ fn from_uint_ty<C: HasDataLayout>(cx: &C, ity: ty::UintTy) -> Integer {
             ty::UintTy::U32 => I32,
             ty::UintTy::U64 => I64,
             ty::UintTy::U128 => I128,
-            // TODO: More complexity needed here?
+            // `usize` is as wide as an address, not as a capability.
             ty::UintTy::Usize => dl.ptr_sized_integer(None),
         }
     }
@@ -154,7 +153,7 @@ fn to_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
             Int(i, signed) => i.to_ty(tcx, signed),
             F32 => tcx.types.f32,
             F64 => tcx.types.f64,
-            Pointer => tcx.mk_mut_ptr(tcx.mk_unit()),
+            Pointer(_) => tcx.mk_mut_ptr(tcx.mk_unit()),
         }
     }
 
@@ -164,7 +163,7 @@ fn to_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
     fn to_int_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
         match *self {
             Int(i, signed) => i.to_ty(tcx, signed),
-            Pointer => tcx.types.usize,
+            Pointer(_) => tcx.types.usize,
             F32 | F64 => bug!("floats do not have an int type"),
         }
     }
@@ -902,7 +901,8 @@ fn ty_and_layout_pointee_info_at(
                 let mut result = None;
 
                 if let Some(variant) = data_variant {
-                    let ptr_end = offset + Pointer.ty_size(cx);
+                    let ptr_end =
+                        offset + Pointer(cx.data_layout().default_address_space).ty_size(cx);
                     for i in 0..variant.fields.count() {
                         let field_start = variant.fields.offset(i);
                         if field_start <= offset {
@@ -1276,7 +1276,7 @@ fn pretty_print_const_scalar_ptr(
                         if let ty::ConstKind::Value(ty::ValTree::Leaf(int)) = len.kind() {
                             match self.tcx().try_get_global_alloc(alloc_id) {
                                 Some(GlobalAlloc::Memory(alloc)) => {
-                                    // TODO: More complexity needed here.
+                                    // The length is a `usize`, which is address-sized.
                                     let len = int.assert_bits(
                                         self.tcx().data_layout.ptr_layout(None).val_size,
                                     );
@@ -1358,8 +1358,8 @@ fn pretty_print_const_scalar_int(
             }
             // Pointer types
             ty::Ref(..) | ty::RawPtr(_) | ty::FnPtr(_) => {
-                // TODO: More complexity needed here.
-                let data = int.assert_bits(self.tcx().data_layout.ptr_layout(None).val_size);
+                // An integer pointer takes up the whole capability on CHERI targets.
+                let data = int.assert_bits(self.tcx().data_layout.ptr_layout(None).ty_size);
                 self = self.typed_value(
                     |mut this| {
                         write!(this, "0x{:x}", data)?;
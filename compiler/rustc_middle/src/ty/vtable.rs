@@ -71,8 +71,8 @@ pub(super) fn vtable_allocation_provider<'tcx>(
     let align = layout.align.abi.bytes();
 
     // We are storing multiple pointers, so we need to be concerned with the type size of pointers
-    // rather than the value size.
-    // TODO: More complexity needed here.
+    // rather than the value size. On CHERI purecap targets every entry is a capability, including
+    // the size and alignment, whose values are stored as plain integers in the address.
     let ptr_size = tcx.data_layout.ptr_layout(None).ty_size;
     let val_size = tcx.data_layout.ptr_layout(None).val_size;
     let ptr_align = tcx.data_layout.ptr_layout(None).align.abi;
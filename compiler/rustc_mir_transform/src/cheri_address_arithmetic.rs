@@ -0,0 +1,173 @@
+//! Lints pointer arithmetic that is done on the address of a pointer, that is `p as usize + n`
+//! cast back to a pointer, on CHERI purecap targets. The address, the arithmetic and the cast
+//! back are often in separate statements, which is why this is done on MIR.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{Ty, TyCtxt};
+use rustc_session::lint::builtin::CHERI_ADDRESS_ARITHMETIC;
+use rustc_target::spec::CapabilityAbi;
+
+use crate::MirLint;
+
+pub struct CheriAddressArithmetic;
+
+impl<'tcx> MirLint<'tcx> for CheriAddressArithmetic {
+    fn run_lint(&self, tcx: TyCtxt<'tcx>, body: &Body<'tcx>) {
+        if tcx.sess.capability_abi() != CapabilityAbi::Purecap {
+            return;
+        }
+
+        let mut checker = AddressChecker { tcx, body, addresses: FxHashMap::default() };
+        for (_, data) in traversal::reverse_postorder(body) {
+            for statement in &data.statements {
+                if let StatementKind::Assign(box (place, rvalue)) = &statement.kind {
+                    checker.check_assign(*place, rvalue, statement.source_info);
+                }
+            }
+        }
+    }
+}
+
+/// A local that holds the address of a pointer, possibly offset by some integer.
+#[derive(Clone)]
+struct Address<'tcx> {
+    /// The source of the pointer, if it can be named in a suggestion.
+    ptr: Option<String>,
+    ptr_ty: Ty<'tcx>,
+    /// The method that applies the arithmetic to the pointer, and the source of its argument.
+    offset: Option<(&'static str, Option<String>)>,
+    /// Whether the local is the result of an overflow-checked operation, so that the address is
+    /// its first field.
+    checked: bool,
+}
+
+struct AddressChecker<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    body: &'a Body<'tcx>,
+    addresses: FxHashMap<Local, Address<'tcx>>,
+}
+
+impl<'tcx> AddressChecker<'_, 'tcx> {
+    fn check_assign(&mut self, place: Place<'tcx>, rvalue: &Rvalue<'tcx>, info: SourceInfo) {
+        let address = match rvalue {
+            Rvalue::Cast(CastKind::PointerExposeAddress, op, ty) if *ty == self.tcx.types.usize => {
+                let ptr_ty = op.ty(self.body, self.tcx);
+                ptr_ty.is_unsafe_ptr().then(|| Address {
+                    ptr: self.operand_source(op),
+                    ptr_ty,
+                    offset: None,
+                    checked: false,
+                })
+            }
+            Rvalue::Use(op) => self.address_of(op),
+            Rvalue::BinaryOp(op, box (lhs, rhs)) | Rvalue::CheckedBinaryOp(op, box (lhs, rhs)) => {
+                let (address, count, method) = match op {
+                    BinOp::Add => match self.address_of(lhs) {
+                        Some(address) => (Some(address), rhs, "wrapping_byte_add"),
+                        None => (self.address_of(rhs), lhs, "wrapping_byte_add"),
+                    },
+                    BinOp::Sub => (self.address_of(lhs), rhs, "wrapping_byte_sub"),
+                    _ => (None, rhs, ""),
+                };
+                address.filter(|address| address.offset.is_none()).map(|address| Address {
+                    offset: Some((method, self.operand_source(count))),
+                    checked: matches!(rvalue, Rvalue::CheckedBinaryOp(..)),
+                    ..address
+                })
+            }
+            Rvalue::Cast(CastKind::PointerFromExposedAddress, op, ty) => {
+                if let Some(address) = self.address_of(op) {
+                    self.lint(address, *ty, info);
+                }
+                None
+            }
+            _ => None,
+        };
+
+        // Only whole locals are tracked, so anything else that is assigned to is forgotten.
+        match address {
+            Some(address) if place.projection.is_empty() => {
+                self.addresses.insert(place.local, address);
+            }
+            _ => {
+                self.addresses.remove(&place.local);
+            }
+        }
+    }
+
+    /// Returns the address that `op` evaluates to, if it is tracked.
+    fn address_of(&self, op: &Operand<'tcx>) -> Option<Address<'tcx>> {
+        let place = op.place()?;
+        let address = self.addresses.get(&place.local)?;
+        let is_value = match place.projection[..] {
+            [] => !address.checked,
+            [ProjectionElem::Field(field, _)] => address.checked && field.index() == 0,
+            _ => false,
+        };
+        is_value.then(|| Address { checked: false, ..address.clone() })
+    }
+
+    /// Returns the source code of `op` if it is a constant, a temporary or a variable.
+    fn operand_source(&self, op: &Operand<'tcx>) -> Option<String> {
+        let source_map = self.tcx.sess.source_map();
+        let local = match op {
+            Operand::Constant(constant) => {
+                return source_map.span_to_snippet(constant.span).ok();
+            }
+            Operand::Copy(place) | Operand::Move(place) => place.as_local()?,
+        };
+        let decl = &self.body.local_decls[local];
+        if decl.is_user_variable() {
+            // The span of the declaration is the whole pattern, like `mut p`.
+            self.body.var_debug_info.iter().find_map(|info| match info.value {
+                VarDebugInfoContents::Place(place) if place.as_local() == Some(local) => {
+                    Some(info.name.to_string())
+                }
+                _ => None,
+            })
+        } else if decl.source_info.span.from_expansion() {
+            None
+        } else {
+            // For temporaries, this is the expression that they hold the value of.
+            source_map.span_to_snippet(decl.source_info.span).ok()
+        }
+    }
+
+    fn lint(&self, address: Address<'tcx>, ty: Ty<'tcx>, info: SourceInfo) {
+        let Some((method, count)) = address.offset else { return };
+        if info.span.from_expansion() {
+            return;
+        }
+        let lint_root =
+            self.body.source_scopes[info.scope].local_data.as_ref().assert_crate_local().lint_root;
+        self.tcx.struct_span_lint_hir(
+            CHERI_ADDRESS_ARITHMETIC,
+            lint_root,
+            info.span,
+            "pointer arithmetic on the address of a pointer cannot produce a valid capability",
+            |lint| {
+                lint.note(
+                    "the address doesn't carry the capability of the pointer, so the pointer cast \
+                     from it has its tag cleared",
+                );
+                if let (Some(ptr), Some(count)) = (address.ptr, count) {
+                    let ptr = if ptr.contains(' ') { format!("({ptr})") } else { ptr };
+                    let cast =
+                        if ty == address.ptr_ty { String::new() } else { format!(" as {ty}") };
+                    lint.span_suggestion(
+                        info.span,
+                        format!("use `{method}` to offset the pointer itself"),
+                        format!("{ptr}.{method}({count}){cast}"),
+                        // The pointer may have changed since its address was taken.
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    lint.help(format!("use `{method}` to offset the pointer itself"));
+                }
+                lint
+            },
+        );
+    }
+}
@@ -47,6 +47,7 @@
 mod check_const_item_mutation;
 mod check_packed_ref;
 pub mod check_unsafety;
+mod cheri_address_arithmetic;
 // This pass is public to allow external drivers to perform MIR cleanup
 pub mod cleanup_post_borrowck;
 mod const_debuginfo;
@@ -298,6 +299,7 @@ fn mir_const<'tcx>(
             &Lint(check_packed_ref::CheckPackedRef),
             &Lint(check_const_item_mutation::CheckConstItemMutation),
             &Lint(function_item_references::FunctionItemReferences),
+            &Lint(cheri_address_arithmetic::CheriAddressArithmetic),
             // What we need to do constant evaluation.
             &simplify::SimplifyCfg::new("initial"),
             &rustc_peek::SanityCheck, // Just a lint
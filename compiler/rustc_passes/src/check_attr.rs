@@ -136,6 +136,7 @@ fn check_attributes(
                 | sym::rustc_if_this_changed
                 | sym::rustc_then_this_would_need => self.check_rustc_dirty_clean(&attr),
                 sym::cmse_nonsecure_entry => self.check_cmse_nonsecure_entry(attr, span, target),
+                sym::cheri_capability => self.check_cheri_capability(hir_id, attr, span, target),
                 sym::collapse_debuginfo => self.check_collapse_debuginfo(attr, span, target),
                 sym::const_trait => self.check_const_trait(attr, span, target),
                 sym::must_not_suspend => self.check_must_not_suspend(&attr, span, target),
@@ -407,6 +408,42 @@ fn check_cmse_nonsecure_entry(&self, attr: &Attribute, span: Span, target: Targe
         }
     }
 
+    /// Checks if `#[cheri_capability]` is applied to a struct with only `PhantomData` fields, on a
+    /// target that has capability pointers.
+    fn check_cheri_capability(
+        &self,
+        hir_id: HirId,
+        attr: &Attribute,
+        span: Span,
+        target: Target,
+    ) -> bool {
+        if target != Target::Struct {
+            self.tcx.sess.emit_err(errors::CheriCapabilityNotStruct { attr_span: attr.span, span });
+            return false;
+        }
+
+        let mut is_valid = true;
+        let adt_def = self.tcx.adt_def(self.tcx.hir().local_def_id(hir_id));
+        for field in adt_def.all_fields() {
+            if !self.tcx.type_of(field.did).is_phantom_data() {
+                self.tcx.sess.emit_err(errors::CheriCapabilityNonPhantomField {
+                    attr_span: attr.span,
+                    field_span: self.tcx.def_span(field.did),
+                });
+                is_valid = false;
+            }
+        }
+
+        if self.tcx.data_layout.capability_address_space().is_none() {
+            self.tcx
+                .sess
+                .emit_err(errors::CheriCapabilityUnsupportedTarget { attr_span: attr.span });
+            is_valid = false;
+        }
+
+        is_valid
+    }
+
     /// Debugging aid for `object_lifetime_default` query.
     fn check_object_lifetime_default(&self, hir_id: HirId) {
         let tcx = self.tcx;
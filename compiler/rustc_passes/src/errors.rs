@@ -420,6 +420,32 @@ pub struct RustcLayoutScalarValidRangeArg {
     pub attr_span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_cheri_capability_not_struct)]
+pub struct CheriCapabilityNotStruct {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_cheri_capability_non_phantom_field)]
+pub struct CheriCapabilityNonPhantomField {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub field_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_cheri_capability_unsupported_target)]
+#[note]
+pub struct CheriCapabilityUnsupportedTarget {
+    #[primary_span]
+    pub attr_span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_rustc_legacy_const_generics_only)]
 pub struct RustcLegacyConstGenericsOnly {
@@ -1051,6 +1077,27 @@ pub struct NakedFunctionsMustUseNoreturn {
     pub last_span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_naked_functions_purecap_stack_pointer)]
+#[note]
+pub struct NakedFunctionsPurecapStackPointer<'a> {
+    #[primary_span]
+    pub span: Span,
+    pub reg: &'a str,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_naked_functions_purecap_branch)]
+#[note]
+#[help]
+pub struct NakedFunctionsPurecapBranch<'a> {
+    #[primary_span]
+    pub span: Span,
+    pub insn: &'a str,
+    pub reg: &'a str,
+    pub suggestion: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_attr_only_on_main)]
 pub struct AttrOnlyOnMain {
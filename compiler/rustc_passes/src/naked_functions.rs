@@ -1,6 +1,6 @@
 //! Checks validity of naked functions.
 
-use rustc_ast::InlineAsmOptions;
+use rustc_ast::{InlineAsmOptions, InlineAsmTemplatePiece};
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::LocalDefId;
@@ -11,12 +11,13 @@
 use rustc_session::lint::builtin::UNDEFINED_NAKED_FUNCTION_ABI;
 use rustc_span::symbol::sym;
 use rustc_span::Span;
+use rustc_target::asm::InlineAsmArch;
 use rustc_target::spec::abi::Abi;
 
 use crate::errors::{
     CannotInlineNakedFunction, NakedFunctionsAsmBlock, NakedFunctionsAsmOptions,
-    NakedFunctionsMustUseNoreturn, NakedFunctionsOperands, NoPatterns, ParamsNotAllowed,
-    UndefinedNakedFunctionAbi,
+    NakedFunctionsMustUseNoreturn, NakedFunctionsOperands, NakedFunctionsPurecapBranch,
+    NakedFunctionsPurecapStackPointer, NoPatterns, ParamsNotAllowed, UndefinedNakedFunctionAbi,
 };
 
 pub(crate) fn provide(providers: &mut Providers) {
@@ -271,6 +272,65 @@ fn check_inline_asm(&self, asm: &'tcx hir::InlineAsm<'tcx>, span: Span) {
 
             self.tcx.sess.emit_err(NakedFunctionsMustUseNoreturn { span, last_span });
         }
+
+        self.check_purecap_registers(asm, span);
+    }
+
+    /// On Morello purecap targets the stack pointer and return address are
+    /// capabilities. A naked function has no compiler-generated prologue or
+    /// epilogue to get this right, so reject asm that uses the integer halves
+    /// of `csp` and `clr` where the whole capability is needed.
+    fn check_purecap_registers(&self, asm: &'tcx hir::InlineAsm<'tcx>, span: Span) {
+        let sess = self.tcx.sess;
+        if sess.asm_arch != Some(InlineAsmArch::AArch64) || sess.target.pointer_type_width.is_none()
+        {
+            return;
+        }
+
+        let mut template = String::new();
+        for piece in asm.template {
+            match piece {
+                InlineAsmTemplatePiece::String(s) => template.push_str(s),
+                InlineAsmTemplatePiece::Placeholder { .. } => template.push_str("{}"),
+            }
+        }
+
+        for line in template.to_ascii_lowercase().lines() {
+            let line = line.split("//").next().unwrap_or_default();
+            for stmt in line.split(';') {
+                // Skip a leading label, if any.
+                let stmt = match stmt.split_once(':') {
+                    Some((label, rest))
+                        if !label.trim().is_empty()
+                            && !label.trim().contains(char::is_whitespace) =>
+                    {
+                        rest
+                    }
+                    _ => stmt,
+                };
+                let mut tokens = stmt
+                    .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                    .filter(|t| !t.is_empty());
+                let Some(insn) = tokens.next() else { continue };
+                let operands: Vec<&str> = tokens.collect();
+
+                if let Some(&reg) = operands.iter().find(|t| matches!(**t, "sp" | "wsp")) {
+                    sess.emit_err(NakedFunctionsPurecapStackPointer { span, reg });
+                }
+
+                if matches!(insn, "ret" | "br" | "blr") {
+                    let Some(&reg) = operands.first() else { continue };
+                    let suggestion = match reg {
+                        "lr" => "clr".to_string(),
+                        _ if reg.starts_with('x') && reg[1..].parse::<u8>().is_ok() => {
+                            format!("c{}", &reg[1..])
+                        }
+                        _ => continue,
+                    };
+                    sess.emit_err(NakedFunctionsPurecapBranch { span, insn, reg, suggestion });
+                }
+            }
+        }
     }
 }
 
@@ -33,6 +33,8 @@ pub enum DataTypeKind {
     Union,
     Enum,
     Closure,
+    /// A pointer with metadata, broken down into its data pointer and metadata.
+    WidePointer,
 }
 
 #[derive(PartialEq, Eq, Hash, Debug)]
@@ -43,6 +45,8 @@ pub struct TypeSizeInfo {
     pub overall_size: u64,
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
+    /// The offset of the CHERI capability whose null address encodes the discriminant, if any.
+    pub capability_niche: Option<u64>,
     pub variants: Vec<VariantInfo>,
 }
 
@@ -60,6 +64,7 @@ pub fn record_type_size<S: ToString>(
         overall_size: Size,
         packed: bool,
         opt_discr_size: Option<Size>,
+        capability_niche: Option<Size>,
         mut variants: Vec<VariantInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
@@ -73,6 +78,7 @@ pub fn record_type_size<S: ToString>(
             overall_size: overall_size.bytes(),
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
+            capability_niche: capability_niche.map(|s| s.bytes()),
             variants,
         };
         self.type_sizes.borrow_mut().insert(info);
@@ -106,6 +112,13 @@ pub fn print_type_sizes(&self) {
                 0
             };
 
+            if let Some(offset) = info.capability_niche {
+                println!(
+                    "print-type-size {indent}niche: null address of the capability at offset \
+                     {offset} bytes"
+                );
+            }
+
             // We start this at discr_size (rather than 0) because
             // things like C-enums do not have variants but we still
             // want the max_variant_size at the end of the loop below
@@ -113,7 +126,7 @@ pub fn print_type_sizes(&self) {
             let mut max_variant_size = discr_size;
 
             let struct_like = match kind {
-                DataTypeKind::Struct | DataTypeKind::Closure => true,
+                DataTypeKind::Struct | DataTypeKind::Closure | DataTypeKind::WidePointer => true,
                 DataTypeKind::Enum | DataTypeKind::Union => false,
             };
             for (i, variant_info) in variants.into_iter().enumerate() {
@@ -51,6 +51,58 @@ pub enum Strip {
     Symbols,
 }
 
+/// The different settings that the `-Z strict-capability` flag can have.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum StrictCapability {
+    /// Report operations that need an ambient capability through the
+    /// `purecap_exposed_provenance` and `cheri_int_to_ptr_cast` lints.
+    Lint,
+
+    /// Report operations that need an ambient capability as hard errors.
+    Error,
+}
+
+/// The different settings that the `-Z cheri-stack-bounds` flag can have.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum CheriStackBounds {
+    /// Leave capabilities derived from stack allocations with the bounds of
+    /// the whole stack.
+    Off,
+
+    /// Narrow every capability derived from a stack allocation to that
+    /// allocation if any of them needs bounds.
+    AllOrNothing,
+
+    /// Only narrow the capabilities to stack allocations that may escape the
+    /// function that made them.
+    IfEscapes,
+}
+
+/// The different settings that the `-Z cheri-subobject-bounds` flag can have.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum CheriSubobjectBounds {
+    /// Give borrows of fields and elements the bounds of the whole object.
+    Off,
+
+    /// Narrow references to fields and elements, except where code commonly
+    /// gets back to the enclosing object from them.
+    Safe,
+
+    /// Narrow every reference and raw pointer to a field or element.
+    Aggressive,
+}
+
+/// The different settings that the `-Z check-provenance-casts` flag can have.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum CheckProvenanceCasts {
+    /// Leave the pointers cast from integers unchecked.
+    Off,
+
+    /// Check that the capability of a pointer cast from an integer is tagged
+    /// and in bounds before each access through it.
+    Runtime,
+}
+
 /// The different settings that the `-C control-flow-guard` flag can have.
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum CFGuard {
@@ -857,6 +909,20 @@ pub fn extend(&mut self, passes: impl IntoIterator<Item = String>) {
             Passes::All => {}
         }
     }
+
+    /// Whether remarks from the pass called `pass_name` were asked for. `cheri` asks for the
+    /// remarks of all of the passes CHERI LLVM adds, whose names all start with `cheri` in some
+    /// mix of cases, like `cheri-bound-allocas` and `CHERICapFoldIntrinsics`.
+    pub fn contains(&self, pass_name: &str) -> bool {
+        let is_cheri_pass =
+            || pass_name.get(..5).map_or(false, |prefix| prefix.eq_ignore_ascii_case("cheri"));
+        match *self {
+            Passes::Some(ref v) => {
+                v.iter().any(|s| *s == pass_name || (s == "cheri" && is_cheri_pass()))
+            }
+            Passes::All => true,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Hash, Debug, PartialEq)]
@@ -947,7 +1013,8 @@ fn default_configuration(sess: &Session) -> CrateConfig {
             let s = i.to_string();
             insert_atomic(&s, align);
             if s == wordsz {
-                // TODO: More complexity needed here.
+                // `"ptr"` gates `AtomicUsize` and `AtomicIsize`, so it follows the address width.
+                // `AtomicPtr` additionally needs capability-width atomics on CHERI targets.
                 insert_atomic("ptr", layout.ptr_layout(None).align.abi);
             }
         }
@@ -2766,10 +2833,11 @@ pub fn needs_analysis(&self) -> bool {
 /// how the hash should be calculated when adding a new command-line argument.
 pub(crate) mod dep_tracking {
     use super::{
-        BranchProtection, CFGuard, CFProtection, CrateType, DebugInfo, ErrorOutputType,
-        InstrumentCoverage, LdImpl, LinkerPluginLto, LocationDetail, LtoCli, OomStrategy, OptLevel,
-        OutputType, OutputTypes, Passes, SourceFileHashAlgorithm, SplitDwarfKind,
-        SwitchWithOptPath, SymbolManglingVersion, TrimmedDefPaths,
+        BranchProtection, CFGuard, CFProtection, CheckProvenanceCasts, CheriStackBounds,
+        CheriSubobjectBounds, CrateType, DebugInfo, ErrorOutputType, InstrumentCoverage, LdImpl,
+        LinkerPluginLto, LocationDetail, LtoCli, OomStrategy, OptLevel, OutputType, OutputTypes,
+        Passes, SourceFileHashAlgorithm, SplitDwarfKind, StrictCapability, SwitchWithOptPath,
+        SymbolManglingVersion, TrimmedDefPaths,
     };
     use crate::lint;
     use crate::options::WasiExecModel;
@@ -2852,12 +2920,16 @@ fn hash(
         SanitizerSet,
         CFGuard,
         CFProtection,
+        CheckProvenanceCasts,
+        CheriStackBounds,
+        CheriSubobjectBounds,
         TargetTriple,
         Edition,
         LinkerPluginLto,
         SplitDebuginfo,
         SplitDwarfKind,
         StackProtector,
+        StrictCapability,
         SwitchWithOptPath,
         SymbolManglingVersion,
         SourceFileHashAlgorithm,
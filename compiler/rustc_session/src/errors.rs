@@ -118,6 +118,12 @@ pub struct SplitDebugInfoUnstablePlatform {
     pub debuginfo: SplitDebuginfo,
 }
 
+#[derive(Diagnostic)]
+#[diag(session_branch_protection_purecap)]
+#[note]
+#[help]
+pub struct BranchProtectionPurecap;
+
 #[derive(Diagnostic)]
 #[diag(session_file_is_not_writeable)]
 pub struct FileIsNotWriteable<'a> {
@@ -368,12 +368,16 @@ mod desc {
     pub const parse_opt_panic_strategy: &str = parse_panic_strategy;
     pub const parse_oom_strategy: &str = "either `panic` or `abort`";
     pub const parse_relro_level: &str = "one of: `full`, `partial`, or `off`";
-    pub const parse_sanitizers: &str = "comma separated list of sanitizers: `address`, `cfi`, `hwaddress`, `leak`, `memory`, `memtag`, `shadow-call-stack`, or `thread`";
+    pub const parse_sanitizers: &str = "comma separated list of sanitizers: `address`, `cfi`, `cheri`, `hwaddress`, `leak`, `memory`, `memtag`, `shadow-call-stack`, or `thread`";
     pub const parse_sanitizer_memory_track_origins: &str = "0, 1, or 2";
     pub const parse_cfguard: &str =
         "either a boolean (`yes`, `no`, `on`, `off`, etc), `checks`, or `nochecks`";
     pub const parse_cfprotection: &str = "`none`|`no`|`n` (default), `branch`, `return`, or `full`|`yes`|`y` (equivalent to `branch` and `return`)";
     pub const parse_strip: &str = "either `none`, `debuginfo`, or `symbols`";
+    pub const parse_strict_capability: &str = "either `lint` or `error`";
+    pub const parse_cheri_stack_bounds: &str = "one of: `all-or-nothing`, `if-escapes`, or `off`";
+    pub const parse_cheri_subobject_bounds: &str = "one of: `safe`, `aggressive`, or `off`";
+    pub const parse_check_provenance_casts: &str = "either `runtime` or `off`";
     pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavorCli::one_of();
     pub const parse_optimization_fuel: &str = "crate=integer";
     pub const parse_mir_spanview: &str = "`statement` (default), `terminator`, or `block`";
@@ -675,6 +679,7 @@ pub(crate) fn parse_sanitizers(slot: &mut SanitizerSet, v: Option<&str>) -> bool
                 *slot |= match s {
                     "address" => SanitizerSet::ADDRESS,
                     "cfi" => SanitizerSet::CFI,
+                    "cheri" => SanitizerSet::CHERI,
                     "leak" => SanitizerSet::LEAK,
                     "memory" => SanitizerSet::MEMORY,
                     "memtag" => SanitizerSet::MEMTAG,
@@ -718,6 +723,50 @@ pub(crate) fn parse_strip(slot: &mut Strip, v: Option<&str>) -> bool {
         true
     }
 
+    pub(crate) fn parse_strict_capability(slot: &mut StrictCapability, v: Option<&str>) -> bool {
+        match v {
+            Some("lint") => *slot = StrictCapability::Lint,
+            Some("error") => *slot = StrictCapability::Error,
+            _ => return false,
+        }
+        true
+    }
+
+    pub(crate) fn parse_cheri_stack_bounds(slot: &mut CheriStackBounds, v: Option<&str>) -> bool {
+        match v {
+            Some("all-or-nothing") => *slot = CheriStackBounds::AllOrNothing,
+            Some("if-escapes") => *slot = CheriStackBounds::IfEscapes,
+            Some("off") => *slot = CheriStackBounds::Off,
+            _ => return false,
+        }
+        true
+    }
+
+    pub(crate) fn parse_cheri_subobject_bounds(
+        slot: &mut CheriSubobjectBounds,
+        v: Option<&str>,
+    ) -> bool {
+        match v {
+            Some("safe") => *slot = CheriSubobjectBounds::Safe,
+            Some("aggressive") => *slot = CheriSubobjectBounds::Aggressive,
+            Some("off") => *slot = CheriSubobjectBounds::Off,
+            _ => return false,
+        }
+        true
+    }
+
+    pub(crate) fn parse_check_provenance_casts(
+        slot: &mut CheckProvenanceCasts,
+        v: Option<&str>,
+    ) -> bool {
+        match v {
+            Some("runtime") => *slot = CheckProvenanceCasts::Runtime,
+            Some("off") => *slot = CheckProvenanceCasts::Off,
+            _ => return false,
+        }
+        true
+    }
+
     pub(crate) fn parse_cfguard(slot: &mut CFGuard, v: Option<&str>) -> bool {
         if v.is_some() {
             let mut bool_arg = None;
@@ -1235,6 +1284,23 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "the codegen unit partitioning strategy to use"),
     chalk: bool = (false, parse_bool, [TRACKED],
         "enable the experimental Chalk-based trait solving engine"),
+    check_provenance_casts: CheckProvenanceCasts = (CheckProvenanceCasts::Off,
+        parse_check_provenance_casts, [TRACKED],
+        "check pointers cast from integers before accesses through them on CHERI purecap \
+        targets (`runtime` or `off` (default))"),
+    cheri_debuginfo_members: bool = (false, parse_bool, [TRACKED],
+        "describe pointers as structs exposing their capability metadata in debuginfo on Morello \
+        purecap targets (default: no)"),
+    cheri_sealed_vtables: bool = (false, parse_bool, [TRACKED],
+        "seal the vtable pointers of trait objects on CHERI purecap targets (default: no)"),
+    cheri_stack_bounds: CheriStackBounds = (CheriStackBounds::AllOrNothing,
+        parse_cheri_stack_bounds, [TRACKED],
+        "narrow the bounds of capabilities derived from stack allocations on CHERI purecap \
+        targets (`all-or-nothing` (default), `if-escapes`, or `off`)"),
+    cheri_subobject_bounds: CheriSubobjectBounds = (CheriSubobjectBounds::Off,
+        parse_cheri_subobject_bounds, [TRACKED],
+        "narrow the bounds of borrows of fields and array elements on CHERI purecap targets \
+        (`safe`, `aggressive`, or `off` (default))"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
@@ -1552,6 +1618,10 @@ pub(crate) fn parse_proc_macro_execution_strategy(
     #[rustc_lint_opt_deny_field_access("use `Session::stack_protector` instead of this field")]
     stack_protector: StackProtector = (StackProtector::None, parse_stack_protector, [TRACKED],
         "control stack smash protection strategy (`rustc --print stack-protector-strategies` for details)"),
+    strict_capability: StrictCapability = (StrictCapability::Lint, parse_strict_capability,
+        [TRACKED],
+        "how to report operations that need an ambient capability on CHERI purecap targets \
+        (`lint` (default) or `error`)"),
     strict_init_checks: bool = (false, parse_bool, [TRACKED],
         "control if mem::uninitialized and mem::zeroed panic on more UB"),
     strip: Strip = (Strip::None, parse_strip, [UNTRACKED],
@@ -1,12 +1,15 @@
 use crate::cgu_reuse_tracker::CguReuseTracker;
 use crate::code_stats::CodeStats;
 pub use crate::code_stats::{DataTypeKind, FieldInfo, SizeKind, VariantInfo};
-use crate::config::{self, CrateType, InstrumentCoverage, OptLevel, OutputType, SwitchWithOptPath};
+use crate::config::{
+    self, CheriSubobjectBounds, CrateType, InstrumentCoverage, OptLevel, OutputType,
+    SwitchWithOptPath,
+};
 use crate::errors::{
-    CannotEnableCrtStaticLinux, CannotMixAndMatchSanitizers, LinkerPluginToWindowsNotSupported,
-    NotCircumventFeature, ProfileSampleUseFileDoesNotExist, ProfileUseFileDoesNotExist,
-    SanitizerCfiEnabled, SanitizerNotSupported, SanitizersNotSupported, SkippingConstChecks,
-    SplitDebugInfoUnstablePlatform, StackProtectorNotSupportedForTarget,
+    BranchProtectionPurecap, CannotEnableCrtStaticLinux, CannotMixAndMatchSanitizers,
+    LinkerPluginToWindowsNotSupported, NotCircumventFeature, ProfileSampleUseFileDoesNotExist,
+    ProfileUseFileDoesNotExist, SanitizerCfiEnabled, SanitizerNotSupported, SanitizersNotSupported,
+    SkippingConstChecks, SplitDebugInfoUnstablePlatform, StackProtectorNotSupportedForTarget,
     TargetRequiresUnwindTables, UnleashedFeatureHelp, UnstableVirtualFunctionElimination,
     UnsupportedDwarfVersion,
 };
@@ -37,7 +40,7 @@
 use rustc_span::source_map::{FileLoader, RealFileLoader, SourceMap, Span};
 use rustc_span::{sym, SourceFileHashAlgorithm, Symbol};
 use rustc_target::asm::InlineAsmArch;
-use rustc_target::spec::{CodeModel, PanicStrategy, RelocModel, RelroLevel};
+use rustc_target::spec::{CapabilityAbi, CodeModel, PanicStrategy, RelocModel, RelroLevel};
 use rustc_target::spec::{
     DebuginfoKind, SanitizerSet, SplitDebuginfo, StackProtector, Target, TargetTriple, TlsModel,
 };
@@ -161,6 +164,10 @@ pub struct Session {
 
     features: OnceCell<rustc_feature::Features>,
 
+    /// How the crate uses CHERI capabilities, computed from the target and `-C target-feature`
+    /// the first time it is asked for.
+    capability_abi: OnceCell<CapabilityAbi>,
+
     incr_comp_session: OneThread<RefCell<IncrCompSession>>,
     /// Used for incremental compilation tests. Will only be populated if
     /// `-Zquery-dep-graph` is specified.
@@ -1065,6 +1072,46 @@ pub fn panic_strategy(&self) -> PanicStrategy {
         self.opts.cg.panic.unwrap_or(self.target.panic_strategy)
     }
 
+    /// Returns how the crate being compiled uses CHERI capabilities, taking `-C target-feature`
+    /// into account for hybrid code.
+    pub fn capability_abi(&self) -> CapabilityAbi {
+        *self.capability_abi.get_or_init(|| {
+            if self.target.pointer_type_width.is_some() {
+                return CapabilityAbi::Purecap;
+            }
+            let (mut morello, mut xcheri) = (false, false);
+            let features =
+                self.target.features.split(',').chain(self.opts.cg.target_feature.split(','));
+            for feature in features {
+                match feature.trim() {
+                    "+morello" => morello = true,
+                    "-morello" => morello = false,
+                    "+xcheri" => xcheri = true,
+                    "-xcheri" => xcheri = false,
+                    _ => {}
+                }
+            }
+            if morello || xcheri { CapabilityAbi::Hybrid } else { CapabilityAbi::None }
+        })
+    }
+
+    /// Returns `true` if the vtable pointers of trait objects are sealed, which is only the case
+    /// for purecap code compiled with `-Z cheri-sealed-vtables`.
+    pub fn cheri_sealed_vtables(&self) -> bool {
+        self.opts.unstable_opts.cheri_sealed_vtables
+            && self.capability_abi() == CapabilityAbi::Purecap
+    }
+
+    /// Returns how far borrows of fields and array elements have their bounds narrowed, which is
+    /// only ever done for purecap code.
+    pub fn cheri_subobject_bounds(&self) -> CheriSubobjectBounds {
+        if self.capability_abi() == CapabilityAbi::Purecap {
+            self.opts.unstable_opts.cheri_subobject_bounds
+        } else {
+            CheriSubobjectBounds::Off
+        }
+    }
+
     pub fn fewer_names(&self) -> bool {
         if let Some(fewer_names) = self.opts.unstable_opts.fewer_names {
             fewer_names
@@ -1098,7 +1145,16 @@ pub fn code_model(&self) -> Option<CodeModel> {
     }
 
     pub fn tls_model(&self) -> TlsModel {
-        self.opts.unstable_opts.tls_model.unwrap_or(self.target.tls_model)
+        let tls_model = self.opts.unstable_opts.tls_model.unwrap_or(self.target.tls_model);
+        // The Morello purecap ABI has no local-dynamic relocations, only TLS descriptors for
+        // general-dynamic accesses, which cost the same.
+        if tls_model == TlsModel::LocalDynamic
+            && self.target.arch == "morello+c64"
+            && self.capability_abi() == CapabilityAbi::Purecap
+        {
+            return TlsModel::GeneralDynamic;
+        }
+        tls_model
     }
 
     pub fn split_debuginfo(&self) -> SplitDebuginfo {
@@ -1422,6 +1478,7 @@ pub fn build_session(
         crate_types: OnceCell::new(),
         stable_crate_id: OnceCell::new(),
         features: OnceCell::new(),
+        capability_abi: OnceCell::new(),
         incr_comp_session: OneThread::new(RefCell::new(IncrCompSession::NotInitialized)),
         cgu_reuse_tracker,
         prof,
@@ -1547,6 +1604,10 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
     {
         sess.emit_err(SplitDebugInfoUnstablePlatform { debuginfo: sess.split_debuginfo() });
     }
+
+    if sess.opts.unstable_opts.branch_protection.is_some() && sess.target.arch == "morello+c64" {
+        sess.emit_err(BranchProtectionPurecap);
+    }
 }
 
 /// Holds data on the current incremental compilation session, if there is one.
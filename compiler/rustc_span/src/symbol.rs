@@ -281,6 +281,7 @@
         RwLockWriteGuard,
         Send,
         SeqCst,
+        Serialize,
         SliceIndex,
         Some,
         String,
@@ -435,6 +436,8 @@
         borrowck_graphviz_postflow,
         borrowck_graphviz_preflow,
         box_free,
+        box_from_raw,
+        box_into_raw,
         box_patterns,
         box_syntax,
         bpf_target_feature,
@@ -476,6 +479,15 @@
         cfg_version,
         cfi,
         char,
+        cheri,
+        cheri_cap_build,
+        cheri_cap_length_get,
+        cheri_capability,
+        cheri_check_access,
+        cheri_check_provenance_cast,
+        cheri_representable_alignment_mask,
+        cheri_representable_length,
+        cheri_target_feature,
         client,
         clippy,
         clobber_abi,
@@ -574,6 +586,7 @@
         crate_name,
         crate_type,
         crate_visibility_modifier,
+        creg,
         crt_dash_static: "crt-static",
         cstring_type,
         ctlz,
@@ -692,6 +705,7 @@
         expf64,
         explicit_generic_args_with_impl_trait,
         export_name,
+        expose_addr,
         expr,
         extended_key_value_attributes,
         extended_varargs_abi_support,
@@ -966,6 +980,7 @@
         module_path_macro,
         more_qualified_paths,
         more_struct_aliases,
+        morello,
         movbe_target_feature,
         move_ref_pattern,
         move_size_limit,
@@ -1140,12 +1155,17 @@
         profiler_builtins,
         profiler_runtime,
         ptr,
+        ptr_addr,
+        ptr_eq_exact,
+        ptr_from_exposed_addr,
+        ptr_from_exposed_addr_mut,
         ptr_guaranteed_cmp,
         ptr_mask,
         ptr_null,
         ptr_null_mut,
         ptr_offset_from,
         ptr_offset_from_unsigned,
+        ptr_with_addr,
         pub_macro_rules,
         pub_restricted,
         public,
@@ -1459,6 +1479,7 @@
         sub_assign,
         sub_with_overflow,
         suggestion,
+        swap,
         sym,
         sync,
         t32,
@@ -1643,6 +1664,7 @@
         write_str,
         writeln_macro,
         x87_reg,
+        xcheri,
         xer,
         xmm_reg,
         yeet_desugar_details,
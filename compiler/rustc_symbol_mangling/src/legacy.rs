@@ -5,6 +5,7 @@
 use rustc_middle::ty::subst::{GenericArg, GenericArgKind};
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt, TypeVisitable};
 use rustc_middle::util::common::record_time;
+use rustc_target::spec::CapabilityAbi;
 
 use std::fmt::{self, Write};
 use std::mem::{self, discriminant};
@@ -130,6 +131,14 @@ fn get_symbol_hash<'tcx>(
                 // Especially, `VTableShim`s and `ReifyShim`s may overlap with their original
                 // instances without this.
                 discriminant(&instance.def).hash_stable(hcx, &mut hasher);
+
+                // Purecap code disagrees with other code on the representation of pointers, so
+                // make sure it can't be linked with it. This leaves the symbols of hybrid and
+                // non-CHERI code unchanged, as they can be linked together.
+                let capability_abi = tcx.sess.capability_abi();
+                if capability_abi == CapabilityAbi::Purecap {
+                    capability_abi.hash_stable(hcx, &mut hasher);
+                }
             });
         });
 
@@ -1,6 +1,7 @@
 use rustc_data_structures::base_n;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::intern::Interned;
+use rustc_data_structures::stable_hasher::StableHasher;
 use rustc_hir as hir;
 use rustc_hir::def::CtorKind;
 use rustc_hir::def_id::{CrateNum, DefId};
@@ -14,8 +15,10 @@
 use rustc_span::symbol::kw;
 use rustc_target::abi::Integer;
 use rustc_target::spec::abi::Abi;
+use rustc_target::spec::CapabilityAbi;
 
 use std::fmt::Write;
+use std::hash::Hash;
 use std::iter;
 use std::ops::Range;
 
@@ -737,7 +740,19 @@ fn print_const(mut self, ct: ty::Const<'tcx>) -> Result<Self::Const, Self::Error
     fn path_crate(self, cnum: CrateNum) -> Result<Self::Path, Self::Error> {
         self.push("C");
         let stable_crate_id = self.tcx.def_path_hash(cnum.as_def_id()).stable_crate_id();
-        self.push_disambiguator(stable_crate_id.to_u64());
+        let mut disambiguator = stable_crate_id.to_u64();
+        // Fold the purecap ABI into every crate's disambiguator, so that purecap code refers to
+        // symbols that don't exist in other code, and linking the two together fails instead of
+        // crashing at runtime. Hybrid and non-CHERI symbols keep their usual names, as that code
+        // can be linked together.
+        let capability_abi = self.tcx.sess.capability_abi();
+        if capability_abi == CapabilityAbi::Purecap {
+            let mut hasher = StableHasher::new();
+            disambiguator.hash(&mut hasher);
+            capability_abi.desc().hash(&mut hasher);
+            disambiguator = hasher.finish();
+        }
+        self.push_disambiguator(disambiguator);
         let name = self.tcx.crate_name(cnum);
         self.push_ident(name.as_str());
         Ok(self)
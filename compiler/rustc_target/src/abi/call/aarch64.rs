@@ -36,7 +36,7 @@ fn is_homogeneous_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> Opti
         }
 
         let valid_unit = match unit.kind {
-            RegKind::Integer => false,
+            RegKind::Integer | RegKind::Capability => false,
             RegKind::Float => true,
             RegKind::Vector => ty_size.bits() == 64 || ty_size.bits() == 128,
         };
@@ -45,6 +45,29 @@ fn is_homogeneous_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> Opti
     })
 }
 
+/// Composite types containing capabilities are passed and returned in capability registers when
+/// they fit in two of them, and by reference otherwise. Any integer data sharing a register with a
+/// capability travels with its tag cleared, so the composite is never split through integer
+/// registers and tags of its capabilities are preserved.
+fn classify_capability_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> bool
+where
+    Ty: TyAbiInterface<'a, C> + Copy,
+    C: HasDataLayout,
+{
+    if !arg.layout.contains_capability(cx) {
+        return false;
+    }
+    let unit = Reg::capability(cx);
+    let ty_size = arg.layout.ty_size;
+    let fits_in_regs = ty_size <= unit.size.checked_mul(2, cx).unwrap();
+    if fits_in_regs && ty_size.bytes() % unit.size.bytes() == 0 {
+        arg.cast_to(Uniform { unit, total: ty_size });
+    } else {
+        arg.make_indirect();
+    }
+    true
+}
+
 fn classify_ret<'a, Ty, C>(cx: &C, ret: &mut ArgAbi<'a, Ty>, param_policy: ParamExtension)
 where
     Ty: TyAbiInterface<'a, C> + Copy,
@@ -57,6 +80,9 @@ fn classify_ret<'a, Ty, C>(cx: &C, ret: &mut ArgAbi<'a, Ty>, param_policy: Param
         }
         return;
     }
+    if classify_capability_aggregate(cx, ret) {
+        return;
+    }
     if let Some(uniform) = is_homogeneous_aggregate(cx, ret) {
         ret.cast_to(uniform);
         return;
@@ -82,6 +108,9 @@ fn classify_arg<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>, param_policy: Param
         }
         return;
     }
+    if classify_capability_aggregate(cx, arg) {
+        return;
+    }
     if let Some(uniform) = is_homogeneous_aggregate(cx, arg) {
         arg.cast_to(uniform);
         return;
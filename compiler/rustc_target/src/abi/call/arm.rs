@@ -16,7 +16,7 @@ fn is_homogeneous_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> Opti
         }
 
         let valid_unit = match unit.kind {
-            RegKind::Integer => false,
+            RegKind::Integer | RegKind::Capability => false,
             RegKind::Float => true,
             RegKind::Vector => ty_size.bits() == 64 || ty_size.bits() == 128,
         };
@@ -137,6 +137,8 @@ pub enum RegKind {
     Integer,
     Float,
     Vector,
+    /// A CHERI capability register, e.g. `c0` on Morello or `ca0` on CHERI-RISC-V.
+    Capability,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, HashStable_Generic)]
@@ -162,6 +164,13 @@ impl Reg {
 
     reg_ctor!(f32, Float, 32);
     reg_ctor!(f64, Float, 64);
+
+    /// A capability register sized for the target's capability address space.
+    pub fn capability<C: HasDataLayout>(cx: &C) -> Reg {
+        let dl = cx.data_layout();
+        let addr_space = dl.capability_address_space().expect("target has no capabilities");
+        Reg { kind: RegKind::Capability, size: dl.ptr_layout(Some(addr_space)).ty_size }
+    }
 }
 
 impl Reg {
@@ -183,6 +192,7 @@ pub fn align<C: HasDataLayout>(&self, cx: &C) -> Align {
                 _ => panic!("unsupported float: {:?}", self),
             },
             RegKind::Vector => dl.vector_align(self.size).abi,
+            RegKind::Capability => dl.ptr_layout(dl.capability_address_space()).align.abi,
         }
     }
 }
@@ -349,7 +359,7 @@ pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, H
             // The primitive for this algorithm.
             Abi::Scalar(scalar) => {
                 let kind = match scalar.primitive() {
-                    abi::Int(..) | abi::Pointer => RegKind::Integer,
+                    abi::Int(..) | abi::Pointer(_) => RegKind::Integer,
                     abi::F32 | abi::F64 => RegKind::Float,
                 };
                 Ok(HomogeneousAggregate::Homogeneous(Reg { kind, size: self.ty_size }))
@@ -457,6 +467,44 @@ pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, H
             }
         }
     }
+
+    /// Returns `true` if a CHERI capability, i.e. a pointer in the capability address space, is
+    /// stored anywhere in this layout. Such values must only be moved through capability
+    /// registers or capability-aligned memory, or their tags are lost.
+    pub fn contains_capability<C>(&self, cx: &C) -> bool
+    where
+        Ty: TyAbiInterface<'a, C> + Copy,
+        C: HasDataLayout,
+    {
+        let Some(cap_addr_space) = cx.data_layout().capability_address_space() else {
+            return false;
+        };
+        let is_capability =
+            |scalar: abi::Scalar| scalar.primitive() == abi::Pointer(cap_addr_space);
+        match self.abi {
+            Abi::Uninhabited | Abi::Vector { .. } => false,
+            Abi::Scalar(scalar) => is_capability(scalar),
+            Abi::ScalarPair(a, b) => is_capability(a) || is_capability(b),
+            Abi::Aggregate { .. } => {
+                let in_fields = match self.fields {
+                    FieldsShape::Primitive => false,
+                    FieldsShape::Array { count, .. } => {
+                        count > 0 && self.field(cx, 0).contains_capability(cx)
+                    }
+                    FieldsShape::Union(_) | FieldsShape::Arbitrary { .. } => {
+                        (0..self.fields.count()).any(|i| self.field(cx, i).contains_capability(cx))
+                    }
+                };
+                in_fields
+                    || match &self.variants {
+                        abi::Variants::Single { .. } => false,
+                        abi::Variants::Multiple { variants, .. } => variants
+                            .indices()
+                            .any(|idx| self.for_variant(cx, idx).contains_capability(cx)),
+                    }
+            }
+        }
+    }
 }
 
 /// Information about how to pass an argument to,
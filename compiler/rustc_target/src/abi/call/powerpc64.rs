@@ -32,7 +32,7 @@ fn is_homogeneous_aggregate<'a, Ty, C>(
         }
 
         let valid_unit = match unit.kind {
-            RegKind::Integer => false,
+            RegKind::Integer | RegKind::Capability => false,
             RegKind::Float => true,
             RegKind::Vector => arg.layout.ty_size.bits() == 128,
         };
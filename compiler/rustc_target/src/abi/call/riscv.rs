@@ -45,7 +45,7 @@ fn should_use_fp_conv_helper<'a, Ty, C>(
 {
     match arg_layout.abi {
         Abi::Scalar(scalar) => match scalar.primitive() {
-            abi::Int(..) | abi::Pointer => {
+            abi::Int(..) | abi::Pointer(_) => {
                 if arg_layout.ty_size.bits() > xlen {
                     return Err(CannotUseFpConv);
                 }
@@ -146,7 +146,13 @@ fn should_use_fp_conv<'a, Ty, C>(
 fn classify_ret<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>, xlen: u64, flen: u64) -> bool
 where
     Ty: TyAbiInterface<'a, C> + Copy,
+    C: HasDataLayout,
 {
+    if arg.layout.contains_capability(cx) {
+        classify_capability(cx, arg);
+        return arg.is_indirect();
+    }
+
     if let Some(conv) = should_use_fp_conv(cx, &arg.layout, xlen, flen) {
         match conv {
             FloatConv::Float(f) => {
@@ -198,6 +204,31 @@ fn classify_ret<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>, xlen: u64, flen: u6
     false
 }
 
+/// CHERI capabilities, and aggregates containing them, are passed and returned in capability
+/// registers, which share the integer register file. Aggregates of up to two capabilities are kept
+/// in registers so that their tags are preserved, and larger ones are passed by reference.
+///
+/// Returns the number of registers used, counting one for the address of an indirect aggregate.
+fn classify_capability<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> u64
+where
+    Ty: TyAbiInterface<'a, C> + Copy,
+    C: HasDataLayout,
+{
+    let unit = Reg::capability(cx);
+    let total = arg.layout.ty_size;
+    if !is_riscv_aggregate(arg) {
+        1
+    } else if total <= unit.size.checked_mul(2, cx).unwrap()
+        && total.bytes() % unit.size.bytes() == 0
+    {
+        arg.cast_to(Uniform { unit, total });
+        total.bytes() / unit.size.bytes()
+    } else {
+        arg.make_indirect();
+        1
+    }
+}
+
 fn classify_arg<'a, Ty, C>(
     cx: &C,
     arg: &mut ArgAbi<'a, Ty>,
@@ -208,7 +239,13 @@ fn classify_arg<'a, Ty, C>(
     avail_fprs: &mut u64,
 ) where
     Ty: TyAbiInterface<'a, C> + Copy,
+    C: HasDataLayout,
 {
+    if arg.layout.contains_capability(cx) {
+        *avail_gprs = avail_gprs.saturating_sub(classify_capability(cx, arg));
+        return;
+    }
+
     if !is_vararg {
         match should_use_fp_conv(cx, &arg.layout, xlen, flen) {
             Some(FloatConv::Float(f)) if *avail_fprs >= 1 => {
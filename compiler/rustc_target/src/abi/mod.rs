@@ -89,6 +89,10 @@ pub enum TargetDataLayoutErrors<'a> {
     InconsistentTargetArchitecture { dl: &'a str, target: &'a str },
     InconsistentTargetPointerWidth { pointer_size: u64, target: u32 },
     InvalidBitsSize { err: String },
+    MissingPointerLayout { addr_space: u32, cause: &'a str },
+    InconsistentTargetPointerTypeWidth { pointer_size: u64, target: u32 },
+    MissingTargetPointerTypeWidth { pointer_size: u64 },
+    InconsistentCapabilityAddressSpace { addr_space: u32, cause: &'a str, default: u32 },
 }
 
 impl TargetDataLayout {
@@ -247,6 +251,15 @@ pub fn parse<'a>(target: &'a Target) -> Result<TargetDataLayout, TargetDataLayou
             });
         }
 
+        // The address space of globals is used for all pointers that don't otherwise specify one,
+        // so it must have a pointer layout, otherwise we'd have no idea how large pointers are.
+        if !dl.pointer_layouts.iter().any(|e| e.0 == dl.default_address_space) {
+            return Err(TargetDataLayoutErrors::MissingPointerLayout {
+                addr_space: dl.default_address_space.0,
+                cause: "G",
+            });
+        }
+
         let target_pointer_width: u64 = target.pointer_width.into();
         let dl_pointer_size = dl.ptr_layout(None).val_size.bits();
         if dl_pointer_size != target_pointer_width {
@@ -256,6 +269,42 @@ pub fn parse<'a>(target: &'a Target) -> Result<TargetDataLayout, TargetDataLayou
             });
         }
 
+        // Capability targets (such as CHERI purecap ones) declare the width of the pointer type
+        // separately from the width of the address, and the two have to agree with the fat
+        // pointer layout of the default address space.
+        let dl_pointer_layout = dl.ptr_layout(None);
+        let dl_pointer_type_size = dl_pointer_layout.ty_size.bits();
+        match target.pointer_type_width {
+            Some(pointer_type_width) if dl_pointer_type_size != u64::from(pointer_type_width) => {
+                return Err(TargetDataLayoutErrors::InconsistentTargetPointerTypeWidth {
+                    pointer_size: dl_pointer_type_size,
+                    target: pointer_type_width,
+                });
+            }
+            None if dl_pointer_layout.is_fat_ty => {
+                return Err(TargetDataLayoutErrors::MissingTargetPointerTypeWidth {
+                    pointer_size: dl_pointer_type_size,
+                });
+            }
+            _ => {}
+        }
+
+        if dl_pointer_layout.is_fat_ty {
+            // All pointers are capabilities, so code and stack objects must live in the same
+            // address space as globals.
+            for (addr_space, cause) in
+                [(dl.alloca_address_space, "A"), (dl.instruction_address_space, "P")]
+            {
+                if addr_space != dl.default_address_space {
+                    return Err(TargetDataLayoutErrors::InconsistentCapabilityAddressSpace {
+                        addr_space: addr_space.0,
+                        cause,
+                        default: dl.default_address_space.0,
+                    });
+                }
+            }
+        }
+
         dl.c_enum_min_size = match Integer::from_size(Size::from_bits(target.c_enum_min_bits)) {
             Ok(bits) => bits,
             Err(err) => return Err(TargetDataLayoutErrors::InvalidBitsSize { err }),
@@ -276,6 +325,14 @@ pub fn ptr_layout(&self, address_space: Option<AddressSpace>) -> PointerLayout {
         }
     }
 
+    /// Returns the address space whose pointers are capabilities, if the data layout declares
+    /// one. On purecap targets this is the default address space, while hybrid targets keep
+    /// ordinary pointers in address space 0 and only use this one for explicit capabilities.
+    #[inline]
+    pub fn capability_address_space(&self) -> Option<AddressSpace> {
+        self.pointer_layouts.iter().find(|e| e.1.is_fat_ty).map(|e| e.0)
+    }
+
     /// Returns exclusive upper bound on object size.
     ///
     /// The theoretical maximum object size is defined as the maximum positive `isize` value.
@@ -845,7 +902,9 @@ pub enum Primitive {
     Int(Integer, bool),
     F32,
     F64,
-    Pointer,
+    /// A pointer in the given address space. On CHERI targets pointers in the capability address
+    /// space are capabilities, and so are larger than the integer address they carry.
+    Pointer(AddressSpace),
 }
 
 impl Primitive {
@@ -856,8 +915,7 @@ pub fn ty_size<C: HasDataLayout>(self, cx: &C) -> Size {
             Int(i, _) => i.size(),
             F32 => Size::from_bits(32),
             F64 => Size::from_bits(64),
-            // TODO: More complexity is needed here.
-            Pointer => dl.ptr_layout(None).ty_size,
+            Pointer(a) => dl.ptr_layout(Some(a)).ty_size,
         }
     }
 
@@ -868,8 +926,7 @@ pub fn val_size<C: HasDataLayout>(self, cx: &C) -> Size {
             Int(i, _) => i.size(),
             F32 => Size::from_bits(32),
             F64 => Size::from_bits(64),
-            // TODO: More complexity is needed here.
-            Pointer => dl.ptr_layout(None).val_size,
+            Pointer(a) => dl.ptr_layout(Some(a)).val_size,
         }
     }
 
@@ -880,8 +937,7 @@ pub fn align<C: HasDataLayout>(self, cx: &C) -> AbiAndPrefAlign {
             Int(i, _) => i.align(dl),
             F32 => dl.f32_align,
             F64 => dl.f64_align,
-            // TODO: More complexity is needed here.
-            Pointer => dl.ptr_layout(None).align,
+            Pointer(a) => dl.ptr_layout(Some(a)).align,
         }
     }
 
@@ -899,7 +955,7 @@ pub fn is_int(self) -> bool {
 
     #[inline]
     pub fn is_ptr(self) -> bool {
-        matches!(self, Pointer)
+        matches!(self, Pointer(_))
     }
 }
 
@@ -1189,12 +1245,15 @@ pub fn index_by_increasing_offset<'a>(&'a self) -> impl Iterator<Item = usize> +
 /// An identifier that specifies the address space that some operation
 /// should operate on. Special address spaces have an effect on code generation,
 /// depending on the target and the address spaces it implements.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, HashStable_Generic)]
 pub struct AddressSpace(pub u32);
 
 impl AddressSpace {
     /// The default address space, corresponding to data space.
     pub const DATA: Self = AddressSpace(0);
+
+    /// The address space used by CHERI LLVM for capabilities.
+    pub const CHERI_CAPABILITY: Self = AddressSpace(200);
 }
 
 /// Describes how values of the type are passed by target ABIs,
@@ -1311,8 +1370,22 @@ pub fn from_scalar<C: HasDataLayout>(cx: &C, offset: Size, scalar: Scalar) -> Op
         if niche.available(cx) > 0 { Some(niche) } else { None }
     }
 
+    /// Returns `true` if the niche is in a CHERI capability. Only the null address of a
+    /// capability is ever used as a niche: other niche values would have to be encoded in the
+    /// address, where they would be taken for real addresses, or in the metadata and tag, which
+    /// are not plain data.
+    fn is_capability<C: HasDataLayout>(&self, cx: &C) -> bool {
+        match self.value {
+            Pointer(address_space) => cx.data_layout().ptr_layout(Some(address_space)).is_fat_ty,
+            _ => false,
+        }
+    }
+
     pub fn available<C: HasDataLayout>(&self, cx: &C) -> u128 {
         let Self { value, valid_range: v, .. } = *self;
+        if self.is_capability(cx) {
+            return if v.contains(0) { 0 } else { 1 };
+        }
         let ty_size = value.ty_size(cx);
         assert!(ty_size.bits() <= 128);
         let max_value = ty_size.unsigned_int_max();
@@ -1326,6 +1399,15 @@ pub fn reserve<C: HasDataLayout>(&self, cx: &C, count: u128) -> Option<(u128, Sc
         assert!(count > 0);
 
         let Self { value, valid_range: v, .. } = *self;
+        if self.is_capability(cx) {
+            if count > 1 || v.contains(0) {
+                return None;
+            }
+            // Any other invalid addresses are given up, so that the null address is the only
+            // value outside the valid range.
+            let valid_range = WrappingRange::full(value.ty_size(cx));
+            return Some((0, Scalar::Initialized { value, valid_range }));
+        }
         let ty_size = value.ty_size(cx);
         assert!(ty_size.bits() <= 128);
         let max_value = ty_size.unsigned_int_max();
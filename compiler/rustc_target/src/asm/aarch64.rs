@@ -2,7 +2,7 @@
 use crate::spec::{RelocModel, Target};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_macros::HashStable_Generic;
-use rustc_span::Symbol;
+use rustc_span::{sym, Symbol};
 use std::fmt;
 
 def_reg_class! {
@@ -11,6 +11,7 @@
         vreg,
         vreg_low16,
         preg,
+        creg,
     }
 }
 
@@ -20,11 +21,15 @@ pub fn valid_modifiers(self, _arch: super::InlineAsmArch) -> &'static [char] {
             Self::reg => &['w', 'x'],
             Self::vreg | Self::vreg_low16 => &['b', 'h', 's', 'd', 'q', 'v'],
             Self::preg => &[],
+            Self::creg => &['w', 'x'],
         }
     }
 
-    pub fn suggest_class(self, _arch: InlineAsmArch, _ty: InlineAsmType) -> Option<Self> {
-        None
+    pub fn suggest_class(self, _arch: InlineAsmArch, ty: InlineAsmType) -> Option<Self> {
+        match (self, ty) {
+            (Self::reg, InlineAsmType::Cap(_)) => Some(Self::creg),
+            _ => None,
+        }
     }
 
     pub fn suggest_modifier(
@@ -45,7 +50,7 @@ pub fn suggest_modifier(
                 128 => Some(('q', "q0")),
                 _ => None,
             },
-            Self::preg => None,
+            Self::preg | Self::creg => None,
         }
     }
 
@@ -53,7 +58,7 @@ pub fn default_modifier(self, _arch: InlineAsmArch) -> Option<(char, &'static st
         match self {
             Self::reg => Some(('x', "x0")),
             Self::vreg | Self::vreg_low16 => Some(('v', "v0")),
-            Self::preg => None,
+            Self::preg | Self::creg => None,
         }
     }
 
@@ -69,6 +74,7 @@ pub fn supported_types(
                     VecI8(16), VecI16(8), VecI32(4), VecI64(2), VecF32(4), VecF64(2);
             },
             Self::preg => &[],
+            Self::creg => types! { morello: Cap(16); },
         }
     }
 }
@@ -91,6 +97,31 @@ fn reserved_x18(
     }
 }
 
+fn morello_only(
+    _arch: InlineAsmArch,
+    _reloc_model: RelocModel,
+    target_features: &FxHashSet<Symbol>,
+    _target: &Target,
+    _is_clobber: bool,
+) -> Result<(), &'static str> {
+    if target_features.contains(&sym::morello) {
+        Ok(())
+    } else {
+        Err("capability registers require the `morello` target feature")
+    }
+}
+
+fn reserved_c18(
+    arch: InlineAsmArch,
+    reloc_model: RelocModel,
+    target_features: &FxHashSet<Symbol>,
+    target: &Target,
+    is_clobber: bool,
+) -> Result<(), &'static str> {
+    morello_only(arch, reloc_model, target_features, target, is_clobber)?;
+    reserved_x18(arch, reloc_model, target_features, target, is_clobber)
+}
+
 def_regs! {
     AArch64 AArch64InlineAsmReg AArch64InlineAsmRegClass {
         x0: reg = ["x0", "w0"],
@@ -171,11 +202,40 @@ fn reserved_x18(
         p14: preg = ["p14"],
         p15: preg = ["p15"],
         ffr: preg = ["ffr"],
-        #error = ["x19", "w19"] =>
+        c0: creg = ["c0"] % morello_only,
+        c1: creg = ["c1"] % morello_only,
+        c2: creg = ["c2"] % morello_only,
+        c3: creg = ["c3"] % morello_only,
+        c4: creg = ["c4"] % morello_only,
+        c5: creg = ["c5"] % morello_only,
+        c6: creg = ["c6"] % morello_only,
+        c7: creg = ["c7"] % morello_only,
+        c8: creg = ["c8"] % morello_only,
+        c9: creg = ["c9"] % morello_only,
+        c10: creg = ["c10"] % morello_only,
+        c11: creg = ["c11"] % morello_only,
+        c12: creg = ["c12"] % morello_only,
+        c13: creg = ["c13"] % morello_only,
+        c14: creg = ["c14"] % morello_only,
+        c15: creg = ["c15"] % morello_only,
+        c16: creg = ["c16"] % morello_only,
+        c17: creg = ["c17"] % morello_only,
+        c18: creg = ["c18"] % reserved_c18,
+        c20: creg = ["c20"] % morello_only,
+        c21: creg = ["c21"] % morello_only,
+        c22: creg = ["c22"] % morello_only,
+        c23: creg = ["c23"] % morello_only,
+        c24: creg = ["c24"] % morello_only,
+        c25: creg = ["c25"] % morello_only,
+        c26: creg = ["c26"] % morello_only,
+        c27: creg = ["c27"] % morello_only,
+        c28: creg = ["c28"] % morello_only,
+        c30: creg = ["c30", "clr"] % morello_only,
+        #error = ["x19", "w19", "c19"] =>
             "x19 is used internally by LLVM and cannot be used as an operand for inline asm",
-        #error = ["x29", "w29", "fp", "wfp"] =>
+        #error = ["x29", "w29", "fp", "wfp", "c29", "cfp"] =>
             "the frame pointer cannot be used as an operand for inline asm",
-        #error = ["sp", "wsp"] =>
+        #error = ["sp", "wsp", "csp"] =>
             "the stack pointer cannot be used as an operand for inline asm",
         #error = ["xzr", "wzr"] =>
             "the zero register cannot be used as an operand for inline asm",
@@ -189,6 +249,12 @@ pub fn emit(
         _arch: InlineAsmArch,
         modifier: Option<char>,
     ) -> fmt::Result {
+        if self.reg_class() == AArch64InlineAsmRegClass::creg {
+            // Capability registers keep their own name unless a modifier asks
+            // for the general-purpose register holding the address.
+            let index = &self.name()[1..];
+            return write!(out, "{}{}", modifier.unwrap_or('c'), index);
+        }
         let (prefix, index) = if (self as u32) < Self::v0 as u32 {
             (modifier.unwrap_or('x'), self as u32 - Self::x0 as u32)
         } else {
@@ -197,4 +263,34 @@ pub fn emit(
         assert!(index < 32);
         write!(out, "{}{}", prefix, index)
     }
+
+    pub fn overlapping_regs(self, mut cb: impl FnMut(AArch64InlineAsmReg)) {
+        macro_rules! reg_conflicts {
+            (
+                $(
+                    $x:ident : $c:ident
+                ),*;
+            ) => {
+                match self {
+                    $(
+                        Self::$x | Self::$c => {
+                            cb(Self::$x);
+                            cb(Self::$c);
+                        }
+                    )*
+                    r => cb(r),
+                }
+            };
+        }
+
+        // The lower 64 bits of each capability register are the corresponding
+        // general-purpose register.
+        reg_conflicts! {
+            x0 : c0, x1 : c1, x2 : c2, x3 : c3, x4 : c4, x5 : c5, x6 : c6, x7 : c7,
+            x8 : c8, x9 : c9, x10 : c10, x11 : c11, x12 : c12, x13 : c13, x14 : c14,
+            x15 : c15, x16 : c16, x17 : c17, x18 : c18, x20 : c20, x21 : c21,
+            x22 : c22, x23 : c23, x24 : c24, x25 : c25, x26 : c26, x27 : c27,
+            x28 : c28, x30 : c30;
+        }
+    }
 }
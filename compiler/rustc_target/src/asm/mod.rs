@@ -383,8 +383,8 @@ pub fn overlapping_regs(self, mut cb: impl FnMut(InlineAsmReg)) {
         match self {
             Self::X86(r) => r.overlapping_regs(|r| cb(Self::X86(r))),
             Self::Arm(r) => r.overlapping_regs(|r| cb(Self::Arm(r))),
-            Self::AArch64(_) => cb(self),
-            Self::RiscV(_) => cb(self),
+            Self::AArch64(r) => r.overlapping_regs(|r| cb(Self::AArch64(r))),
+            Self::RiscV(r) => r.overlapping_regs(|r| cb(Self::RiscV(r))),
             Self::PowerPC(r) => r.overlapping_regs(|r| cb(Self::PowerPC(r))),
             Self::Hexagon(r) => r.overlapping_regs(|r| cb(Self::Hexagon(r))),
             Self::Mips(_) => cb(self),
@@ -643,6 +643,8 @@ pub enum InlineAsmType {
     VecI128(u64),
     VecF32(u64),
     VecF64(u64),
+    /// A CHERI capability of the given size in bytes.
+    Cap(u64),
 }
 
 impl InlineAsmType {
@@ -666,6 +668,7 @@ pub fn size(self) -> Size {
             Self::VecI128(n) => n * 16,
             Self::VecF32(n) => n * 4,
             Self::VecF64(n) => n * 8,
+            Self::Cap(n) => n,
         })
     }
 }
@@ -687,6 +690,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::VecI128(n) => write!(f, "i128x{}", n),
             Self::VecF32(n) => write!(f, "f32x{}", n),
             Self::VecF64(n) => write!(f, "f64x{}", n),
+            Self::Cap(n) => write!(f, "c{}", n * 8),
         }
     }
 }
@@ -788,6 +792,8 @@ pub enum InlineAsmClobberAbi {
     Arm,
     AArch64,
     AArch64NoX18,
+    AArch64Purecap,
+    AArch64PurecapNoX18,
     RiscV,
 }
 
@@ -819,11 +825,18 @@ pub fn parse(
                 _ => Err(&["C", "system", "efiapi", "aapcs"]),
             },
             InlineAsmArch::AArch64 => match name {
-                "C" | "system" | "efiapi" => Ok(if aarch64::target_reserves_x18(target) {
-                    InlineAsmClobberAbi::AArch64NoX18
-                } else {
-                    InlineAsmClobberAbi::AArch64
-                }),
+                "C" | "system" | "efiapi" => {
+                    // The purecap AAPCS passes and returns pointers in
+                    // capability registers, so the whole capability must be
+                    // clobbered rather than just its address.
+                    let purecap = target.pointer_type_width.is_some();
+                    Ok(match (purecap, aarch64::target_reserves_x18(target)) {
+                        (false, false) => InlineAsmClobberAbi::AArch64,
+                        (false, true) => InlineAsmClobberAbi::AArch64NoX18,
+                        (true, false) => InlineAsmClobberAbi::AArch64Purecap,
+                        (true, true) => InlineAsmClobberAbi::AArch64PurecapNoX18,
+                    })
+                }
                 _ => Err(&["C", "system", "efiapi"]),
             },
             InlineAsmArch::RiscV32 | InlineAsmArch::RiscV64 => match name {
@@ -934,6 +947,46 @@ macro_rules! clobbered_regs {
 
                 }
             },
+            InlineAsmClobberAbi::AArch64Purecap => clobbered_regs! {
+                AArch64 AArch64InlineAsmReg {
+                    // c19-c29 are callee-saved in full, including their
+                    // capability metadata.
+                    c0, c1, c2, c3, c4, c5, c6, c7,
+                    c8, c9, c10, c11, c12, c13, c14, c15,
+                    c16, c17, c18, c30,
+
+                    // Technically the low 64 bits of v8-v15 are preserved, but
+                    // we have no way of expressing this using clobbers.
+                    v0, v1, v2, v3, v4, v5, v6, v7,
+                    v8, v9, v10, v11, v12, v13, v14, v15,
+                    v16, v17, v18, v19, v20, v21, v22, v23,
+                    v24, v25, v26, v27, v28, v29, v30, v31,
+
+                    p0, p1, p2, p3, p4, p5, p6, p7,
+                    p8, p9, p10, p11, p12, p13, p14, p15,
+                    ffr,
+
+                }
+            },
+            InlineAsmClobberAbi::AArch64PurecapNoX18 => clobbered_regs! {
+                AArch64 AArch64InlineAsmReg {
+                    c0, c1, c2, c3, c4, c5, c6, c7,
+                    c8, c9, c10, c11, c12, c13, c14, c15,
+                    c16, c17, c30,
+
+                    // Technically the low 64 bits of v8-v15 are preserved, but
+                    // we have no way of expressing this using clobbers.
+                    v0, v1, v2, v3, v4, v5, v6, v7,
+                    v8, v9, v10, v11, v12, v13, v14, v15,
+                    v16, v17, v18, v19, v20, v21, v22, v23,
+                    v24, v25, v26, v27, v28, v29, v30, v31,
+
+                    p0, p1, p2, p3, p4, p5, p6, p7,
+                    p8, p9, p10, p11, p12, p13, p14, p15,
+                    ffr,
+
+                }
+            },
             InlineAsmClobberAbi::Arm => clobbered_regs! {
                 Arm ArmInlineAsmReg {
                     // r9 is either platform-reserved or callee-saved. Either
@@ -10,6 +10,7 @@
         reg,
         freg,
         vreg,
+        creg,
     }
 }
 
@@ -18,8 +19,11 @@ pub fn valid_modifiers(self, _arch: super::InlineAsmArch) -> &'static [char] {
         &[]
     }
 
-    pub fn suggest_class(self, _arch: InlineAsmArch, _ty: InlineAsmType) -> Option<Self> {
-        None
+    pub fn suggest_class(self, _arch: InlineAsmArch, ty: InlineAsmType) -> Option<Self> {
+        match (self, ty) {
+            (Self::reg, InlineAsmType::Cap(_)) => Some(Self::creg),
+            _ => None,
+        }
     }
 
     pub fn suggest_modifier(
@@ -48,6 +52,13 @@ pub fn supported_types(
             }
             Self::freg => types! { f: F32; d: F64; },
             Self::vreg => &[],
+            Self::creg => {
+                if arch == InlineAsmArch::RiscV64 {
+                    types! { xcheri: Cap(16); }
+                } else {
+                    types! { xcheri: Cap(8); }
+                }
+            }
         }
     }
 }
@@ -66,6 +77,31 @@ fn not_e(
     }
 }
 
+fn xcheri(
+    _arch: InlineAsmArch,
+    _reloc_model: RelocModel,
+    target_features: &FxHashSet<Symbol>,
+    _target: &Target,
+    _is_clobber: bool,
+) -> Result<(), &'static str> {
+    if target_features.contains(&sym::xcheri) {
+        Ok(())
+    } else {
+        Err("capability registers require the `xcheri` target feature")
+    }
+}
+
+fn xcheri_not_e(
+    arch: InlineAsmArch,
+    reloc_model: RelocModel,
+    target_features: &FxHashSet<Symbol>,
+    target: &Target,
+    is_clobber: bool,
+) -> Result<(), &'static str> {
+    xcheri(arch, reloc_model, target_features, target, is_clobber)?;
+    not_e(arch, reloc_model, target_features, target, is_clobber)
+}
+
 def_regs! {
     RiscV RiscVInlineAsmReg RiscVInlineAsmRegClass {
         x1: reg = ["x1", "ra"],
@@ -158,17 +194,43 @@ fn not_e(
         v29: vreg = ["v29"],
         v30: vreg = ["v30"],
         v31: vreg = ["v31"],
-        #error = ["x9", "s1"] =>
+        c1: creg = ["c1", "cra"] % xcheri,
+        c5: creg = ["c5", "ct0"] % xcheri,
+        c6: creg = ["c6", "ct1"] % xcheri,
+        c7: creg = ["c7", "ct2"] % xcheri,
+        c10: creg = ["c10", "ca0"] % xcheri,
+        c11: creg = ["c11", "ca1"] % xcheri,
+        c12: creg = ["c12", "ca2"] % xcheri,
+        c13: creg = ["c13", "ca3"] % xcheri,
+        c14: creg = ["c14", "ca4"] % xcheri,
+        c15: creg = ["c15", "ca5"] % xcheri,
+        c16: creg = ["c16", "ca6"] % xcheri_not_e,
+        c17: creg = ["c17", "ca7"] % xcheri_not_e,
+        c18: creg = ["c18", "cs2"] % xcheri_not_e,
+        c19: creg = ["c19", "cs3"] % xcheri_not_e,
+        c20: creg = ["c20", "cs4"] % xcheri_not_e,
+        c21: creg = ["c21", "cs5"] % xcheri_not_e,
+        c22: creg = ["c22", "cs6"] % xcheri_not_e,
+        c23: creg = ["c23", "cs7"] % xcheri_not_e,
+        c24: creg = ["c24", "cs8"] % xcheri_not_e,
+        c25: creg = ["c25", "cs9"] % xcheri_not_e,
+        c26: creg = ["c26", "cs10"] % xcheri_not_e,
+        c27: creg = ["c27", "cs11"] % xcheri_not_e,
+        c28: creg = ["c28", "ct3"] % xcheri_not_e,
+        c29: creg = ["c29", "ct4"] % xcheri_not_e,
+        c30: creg = ["c30", "ct5"] % xcheri_not_e,
+        c31: creg = ["c31", "ct6"] % xcheri_not_e,
+        #error = ["x9", "s1", "c9", "cs1"] =>
             "s1 is used internally by LLVM and cannot be used as an operand for inline asm",
-        #error = ["x8", "s0", "fp"] =>
+        #error = ["x8", "s0", "fp", "c8", "cs0", "cfp"] =>
             "the frame pointer cannot be used as an operand for inline asm",
-        #error = ["x2", "sp"] =>
+        #error = ["x2", "sp", "c2", "csp"] =>
             "the stack pointer cannot be used as an operand for inline asm",
-        #error = ["x3", "gp"] =>
+        #error = ["x3", "gp", "c3", "cgp"] =>
             "the global pointer cannot be used as an operand for inline asm",
-        #error = ["x4", "tp"] =>
+        #error = ["x4", "tp", "c4", "ctp"] =>
             "the thread pointer cannot be used as an operand for inline asm" ,
-        #error = ["x0", "zero"] =>
+        #error = ["x0", "zero", "c0", "cnull"] =>
             "the zero register cannot be used as an operand for inline asm",
     }
 }
@@ -182,4 +244,33 @@ pub fn emit(
     ) -> fmt::Result {
         out.write_str(self.name())
     }
+
+    pub fn overlapping_regs(self, mut cb: impl FnMut(RiscVInlineAsmReg)) {
+        macro_rules! reg_conflicts {
+            (
+                $(
+                    $x:ident : $c:ident
+                ),*;
+            ) => {
+                match self {
+                    $(
+                        Self::$x | Self::$c => {
+                            cb(Self::$x);
+                            cb(Self::$c);
+                        }
+                    )*
+                    r => cb(r),
+                }
+            };
+        }
+
+        // Each capability register extends the integer register with the
+        // same number, which holds its address.
+        reg_conflicts! {
+            x1 : c1, x5 : c5, x6 : c6, x7 : c7, x10 : c10, x11 : c11, x12 : c12, x13 : c13,
+            x14 : c14, x15 : c15, x16 : c16, x17 : c17, x18 : c18, x19 : c19, x20 : c20, x21 : c21,
+            x22 : c22, x23 : c23, x24 : c24, x25 : c25, x26 : c26, x27 : c27, x28 : c28, x29 : c29,
+            x30 : c30, x31 : c31;
+        }
+    }
 }
@@ -351,6 +351,35 @@ fn to_json(&self) -> Json {
     }
 }
 
+/// How code uses CHERI capabilities. Purecap crates can't be linked with crates compiled for
+/// another capability ABI, as they disagree on the representation of pointers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Encodable, Decodable, HashStable_Generic)]
+pub enum CapabilityAbi {
+    /// Capabilities aren't available at all.
+    None,
+    /// Pointers are integers, but capabilities can be used explicitly.
+    Hybrid,
+    /// Every pointer is a capability.
+    Purecap,
+}
+
+impl CapabilityAbi {
+    pub fn desc(&self) -> &str {
+        match *self {
+            CapabilityAbi::None => "non-CHERI",
+            CapabilityAbi::Hybrid => "hybrid",
+            CapabilityAbi::Purecap => "purecap",
+        }
+    }
+
+    /// Returns `true` if code compiled for this capability ABI can be linked with code compiled
+    /// for `other`. Pointers in hybrid code are integers as they are in non-CHERI code, so only
+    /// purecap code has to be kept apart.
+    pub fn is_link_compatible(self, other: CapabilityAbi) -> bool {
+        (self == CapabilityAbi::Purecap) == (other == CapabilityAbi::Purecap)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Hash)]
 pub enum RelroLevel {
     Full,
@@ -803,7 +832,7 @@ fn to_json(&self) -> Json {
 
 bitflags::bitflags! {
     #[derive(Default, Encodable, Decodable)]
-    pub struct SanitizerSet: u8 {
+    pub struct SanitizerSet: u16 {
         const ADDRESS = 1 << 0;
         const LEAK    = 1 << 1;
         const MEMORY  = 1 << 2;
@@ -812,6 +841,7 @@ pub struct SanitizerSet: u8 {
         const CFI     = 1 << 5;
         const MEMTAG  = 1 << 6;
         const SHADOWCALLSTACK = 1 << 7;
+        const CHERI   = 1 << 8;
     }
 }
 
@@ -823,6 +853,7 @@ pub fn as_str(self) -> Option<&'static str> {
         Some(match self {
             SanitizerSet::ADDRESS => "address",
             SanitizerSet::CFI => "cfi",
+            SanitizerSet::CHERI => "cheri",
             SanitizerSet::LEAK => "leak",
             SanitizerSet::MEMORY => "memory",
             SanitizerSet::MEMTAG => "memtag",
@@ -858,6 +889,7 @@ fn into_iter(self) -> Self::IntoIter {
         [
             SanitizerSet::ADDRESS,
             SanitizerSet::CFI,
+            SanitizerSet::CHERI,
             SanitizerSet::LEAK,
             SanitizerSet::MEMORY,
             SanitizerSet::MEMTAG,
@@ -1198,6 +1230,7 @@ fn $module() {
     ("riscv32gc-unknown-linux-musl", riscv32gc_unknown_linux_musl),
     ("riscv64imac-unknown-none-elf", riscv64imac_unknown_none_elf),
     ("riscv64gc-unknown-none-elf", riscv64gc_unknown_none_elf),
+    ("riscv64imafdcxcheri-unknown-none-purecap", riscv64imafdcxcheri_unknown_none_purecap),
     ("riscv64gc-unknown-linux-gnu", riscv64gc_unknown_linux_gnu),
     ("riscv64gc-unknown-linux-musl", riscv64gc_unknown_linux_musl),
 
@@ -2091,6 +2124,12 @@ macro_rules! key {
                     base.$key_name = Some(s as u32);
                 }
             } );
+            ($key_name:ident = $json_name:expr, Option<u32>) => ( {
+                let name = $json_name;
+                if let Some(s) = obj.remove(name).and_then(|b| b.as_u64()) {
+                    base.$key_name = Some(s as u32);
+                }
+            } );
             ($key_name:ident, Option<u64>) => ( {
                 let name = (stringify!($key_name)).replace("_", "-");
                 if let Some(s) = obj.remove(&name).and_then(|b| b.as_u64()) {
@@ -2292,6 +2331,7 @@ macro_rules! key {
                             base.$key_name |= match s.as_str() {
                                 Some("address") => SanitizerSet::ADDRESS,
                                 Some("cfi") => SanitizerSet::CFI,
+                                Some("cheri") => SanitizerSet::CHERI,
                                 Some("leak") => SanitizerSet::LEAK,
                                 Some("memory") => SanitizerSet::MEMORY,
                                 Some("memtag") => SanitizerSet::MEMTAG,
@@ -2436,7 +2476,7 @@ macro_rules! key {
         }
 
         key!(is_builtin, bool);
-        key!(pointer_type_width, Option<u32>);
+        key!(pointer_type_width = "target-pointer-type-width", Option<u32>);
         key!(c_int_width = "target-c-int-width");
         key!(os);
         key!(env);
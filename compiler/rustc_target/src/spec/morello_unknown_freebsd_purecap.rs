@@ -1,8 +1,30 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, Target, TargetOptions};
+use crate::spec::{
+    Cc, CodeModel, LinkerFlavor, Lld, SanitizerSet, SplitDebuginfo, StackProbeType, Target,
+    TargetOptions,
+};
+use std::borrow::Cow;
 
 pub fn target() -> Target {
     let mut base = super::freebsd_base::opts();
-    base.add_pre_link_args(LinkerFlavor::Gnu(Cc::Yes, Lld::No), &["-march=morello+c64", "-mabi=purecap"]);
+    base.add_pre_link_args(
+        LinkerFlavor::Gnu(Cc::Yes, Lld::No),
+        &[
+            "--target=aarch64-unknown-freebsd",
+            "-march=morello+c64",
+            "-mabi=purecap",
+            "-fuse-ld=lld",
+        ],
+    );
+    // Probes are emitted through CSP, so they stay within the stack capability's bounds.
+    base.stack_probes = StackProbeType::Inline;
+    // CheriBSD's rtld resolves the Morello TLS descriptor and TPREL128 relocations, and
+    // the thread pointer is a capability in CTPIDR_EL0, bounded to the thread's TLS block.
+    base.has_thread_local = true;
+    // Purecap debug info is large enough that it's worth keeping out of the binary. Packed
+    // debug info is linked into a `.dwp` by thorin, as on Linux, since CheriBSD doesn't ship a
+    // `dsymutil`-like tool of its own.
+    base.supported_split_debuginfo =
+        Cow::Borrowed(&[SplitDebuginfo::Packed, SplitDebuginfo::Unpacked, SplitDebuginfo::Off]);
 
     Target {
         data_layout: "e-m:e-pf200:128:128:128:64-i8:8:32-i16:16:32-i64:64-i128:128-n32:64-S128-A200-P200-G200".into(),
@@ -12,9 +34,15 @@ pub fn target() -> Target {
 
         options: TargetOptions {
             pointer_type_width: Some(128),
-            linker: Some("lld".into()),
+            // CheriBSD is linked through clang, which picks the purecap crt objects and
+            // libraries out of the sysroot. See `CHERIBSD_SYSROOT`.
+            linker: Some("clang".into()),
             llvm_abiname: "purecap".into(),
+            // Purecap code reaches globals through capabilities loaded PC-relative from the
+            // GOT, which only the small code model supports.
+            code_model: Some(CodeModel::Small),
             features: "+v8.2a,+morello,+c64".into(),
+            supported_sanitizers: SanitizerSet::CHERI,
             ..base
         },
     }
@@ -1,10 +1,14 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, Target, TargetOptions};
+use crate::spec::{
+    Cc, CodeModel, LinkerFlavor, Lld, SanitizerSet, StackProbeType, Target, TargetOptions,
+};
 
 pub fn target() -> Target {
     let mut base = super::linux_musl_base::opts();
     base.add_pre_link_args(LinkerFlavor::Gnu(Cc::Yes, Lld::No), &["-march=morello+c64", "-mabi=purecap"]);
     base.max_atomic_width = Some(128);
     base.has_thread_local = false;
+    // Probes are emitted through CSP, so they stay within the stack capability's bounds.
+    base.stack_probes = StackProbeType::Inline;
 
     Target {
         llvm_target: "aarch64-unknown-linux-musl_purecap".into(),
@@ -16,7 +20,11 @@ pub fn target() -> Target {
             pointer_type_width: Some(128),
             linker: Some("lld".into()),
             llvm_abiname: "purecap".into(),
+            // Purecap code reaches globals through capabilities loaded PC-relative from the
+            // GOT, which only the small code model supports.
+            code_model: Some(CodeModel::Small),
             features: "+v8.2a,+morello,+c64".into(),
+            supported_sanitizers: SanitizerSet::CHERI,
             mcount: "\u{1}_mcount".into(),
             ..base
         },
@@ -1,4 +1,6 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel, Target, TargetOptions};
+use crate::spec::{
+    Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel, SanitizerSet, Target, TargetOptions,
+};
 
 pub fn target() -> Target {
     Target {
@@ -15,6 +17,7 @@ pub fn target() -> Target {
             cpu: "generic".into(),
             atomic_cas: true,
             features: "+v8.2a,+morello,+c64".into(),
+            supported_sanitizers: SanitizerSet::CHERI,
             panic_strategy: PanicStrategy::Abort,
             relocation_model: RelocModel::Static,
             emit_debug_gdb_scripts: false,
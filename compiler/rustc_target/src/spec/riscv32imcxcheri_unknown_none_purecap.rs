@@ -1,4 +1,5 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel, Target, TargetOptions};
+use crate::spec::{Cc, CodeModel, LinkerFlavor, Lld, PanicStrategy};
+use crate::spec::{RelocModel, Target, TargetOptions};
 
 pub fn target() -> Target {
     Target {
@@ -11,8 +12,19 @@ pub fn target() -> Target {
             pointer_type_width: Some(64),
             linker_flavor: LinkerFlavor::Gnu(Cc::No, Lld::Yes),
             linker: Some("rust-lld".into()),
+            // Bare-metal startup code initialises capabilities from a legacy `__cap_relocs`
+            // table rather than from dynamic relocations.
+            pre_link_args: TargetOptions::link_args(
+                LinkerFlavor::Gnu(Cc::No, Lld::No),
+                &["--local-caprelocs=legacy"],
+            ),
             llvm_abiname: "il32pc64".into(),
+            // `auipcc`-relative addressing of the capability table, as for `medany`.
+            code_model: Some(CodeModel::Medium),
             cpu: "generic-rv32".into(),
+            // `lr.c` and `sc.c` are capability-width, so 64-bit integers can be accessed
+            // atomically as well as capabilities.
+            max_atomic_width: Some(64),
             atomic_cas: true,
             features: "+m,+a,+c,+xcheri,+cap-mode".into(),
             panic_strategy: PanicStrategy::Abort,
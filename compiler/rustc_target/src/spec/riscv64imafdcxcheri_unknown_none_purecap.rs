@@ -0,0 +1,38 @@
+use crate::spec::{Cc, CodeModel, LinkerFlavor, Lld, PanicStrategy};
+use crate::spec::{RelocModel, Target, TargetOptions};
+
+pub fn target() -> Target {
+    Target {
+        data_layout: "e-m:e-pf200:128:128:128:64-p:64:64-i64:64-i128:128-n64-S128-A200-P200-G200"
+            .into(),
+        llvm_target: "riscv64-unknown-none-purecap".into(),
+        pointer_width: 64,
+        arch: "riscv64".into(),
+
+        options: TargetOptions {
+            pointer_type_width: Some(128),
+            linker_flavor: LinkerFlavor::Gnu(Cc::No, Lld::Yes),
+            linker: Some("rust-lld".into()),
+            // Bare-metal startup code initialises capabilities from a legacy `__cap_relocs`
+            // table rather than from dynamic relocations.
+            pre_link_args: TargetOptions::link_args(
+                LinkerFlavor::Gnu(Cc::No, Lld::No),
+                &["--local-caprelocs=legacy"],
+            ),
+            llvm_abiname: "l64pc128d".into(),
+            // `auipcc`-relative addressing of the capability table, as for `medany`.
+            code_model: Some(CodeModel::Medium),
+            cpu: "generic-rv64".into(),
+            // `lr.c` and `sc.c` are capability-width, so 128-bit integers can be accessed
+            // atomically as well as capabilities.
+            max_atomic_width: Some(128),
+            atomic_cas: true,
+            features: "+m,+a,+f,+d,+c,+xcheri,+cap-mode".into(),
+            panic_strategy: PanicStrategy::Abort,
+            relocation_model: RelocModel::Static,
+            emit_debug_gdb_scripts: false,
+            eh_frame_header: false,
+            ..Default::default()
+        },
+    }
+}
@@ -1,3 +1,4 @@
+use crate::abi::{TargetDataLayout, TargetDataLayoutErrors};
 use crate::spec::Target;
 
 #[test]
@@ -55,3 +56,52 @@ fn no_warnings_for_valid_target() {
     let warnings = Target::from_json(json).unwrap().1;
     assert_eq!(warnings.warning_messages().len(), 0);
 }
+
+#[test]
+fn capability_data_layout() {
+    let target = |data_layout: &str, pointer_type_width: Option<u32>| {
+        let mut json = serde_json::json!({
+            "arch": "aarch64",
+            "data-layout": data_layout,
+            "llvm-target": "aarch64-unknown-none-elf",
+            "target-pointer-width": "64",
+        });
+        if let Some(width) = pointer_type_width {
+            json["target-pointer-type-width"] = width.into();
+        }
+        Target::from_json(json).unwrap().0
+    };
+
+    let purecap = "e-m:e-pf200:128:128:128:64-i64:64-i128:128-n32:64-S128-A200-P200-G200";
+    let dl = TargetDataLayout::parse(&target(purecap, Some(128))).ok().unwrap();
+    assert!(dl.ptr_layout(None).is_fat_ty);
+    assert_eq!(dl.ptr_layout(None).ty_size.bits(), 128);
+
+    assert!(matches!(
+        TargetDataLayout::parse(&target(purecap, Some(64))),
+        Err(TargetDataLayoutErrors::InconsistentTargetPointerTypeWidth {
+            pointer_size: 128,
+            target: 64
+        })
+    ));
+    assert!(matches!(
+        TargetDataLayout::parse(&target(purecap, None)),
+        Err(TargetDataLayoutErrors::MissingTargetPointerTypeWidth { pointer_size: 128 })
+    ));
+
+    let no_cap_layout = "e-m:e-i64:64-i128:128-n32:64-S128-A200-P200-G200";
+    assert!(matches!(
+        TargetDataLayout::parse(&target(no_cap_layout, Some(128))),
+        Err(TargetDataLayoutErrors::MissingPointerLayout { addr_space: 200, cause: "G" })
+    ));
+
+    let hybrid_stack = "e-m:e-pf200:128:128:128:64-i64:64-i128:128-n32:64-S128-P200-G200";
+    assert!(matches!(
+        TargetDataLayout::parse(&target(hybrid_stack, Some(128))),
+        Err(TargetDataLayoutErrors::InconsistentCapabilityAddressSpace {
+            addr_space: 0,
+            cause: "A",
+            default: 200
+        })
+    ));
+}
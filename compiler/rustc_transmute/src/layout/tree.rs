@@ -272,7 +272,7 @@ pub fn from_ty(ty: Ty<'tcx>, tcx: TyCtxt<'tcx>) -> Result<Self, Err> {
                 ty::Int(I64) | ty::Uint(U64) | ty::Float(F64) => Ok(Self::number(8)),
                 ty::Int(I128) | ty::Uint(U128) => Ok(Self::number(16)),
                 ty::Int(Isize) | ty::Uint(Usize) => {
-                    // TODO: More complexity needed here.
+                    // `isize` and `usize` are address-sized.
                     Ok(Self::number(target.ptr_layout(None).val_size.bytes_usize()))
                 }
 
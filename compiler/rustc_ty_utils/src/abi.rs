@@ -213,7 +213,7 @@ fn adjust_for_rust_scalar<'tcx>(
     }
 
     // Only pointer types handled below.
-    let Scalar::Initialized { value: Pointer, valid_range} = scalar else { return };
+    let Scalar::Initialized { value: Pointer(_), valid_range } = scalar else { return };
 
     if !valid_range.contains(0) {
         attrs.set(ArgAttribute::NonNull);
@@ -456,7 +456,14 @@ fn fn_abi_adjust_for_abi<'tcx>(
             }
 
             let ty_size = arg.layout.ty_size;
-            if arg.layout.is_unsized() || ty_size > Pointer.ty_size(cx) {
+            let dl = cx.data_layout();
+            if arg.layout.is_unsized()
+                || ty_size > Pointer(dl.default_address_space).ty_size(cx)
+                // On CHERI purecap targets, an aggregate aligned like a capability may hold one
+                // (e.g. a union or `MaybeUninit` of a pointer), and moving it through an integer
+                // register would clear the tag.
+                || (dl.is_cheri_purecap && arg.layout.align.abi >= dl.ptr_layout(None).align.abi)
+            {
                 arg.make_indirect();
             } else {
                 // We want to pass small aggregates as immediates, but using
@@ -67,3 +67,19 @@ pub enum GenericConstantTooComplexSub {
     #[label(ty_utils_operation_not_supported)]
     OperationNotSupported(#[primary_span] Span),
 }
+
+#[derive(Diagnostic)]
+#[diag(ty_utils_packed_capability)]
+#[note]
+#[help]
+#[help(address_help)]
+pub struct PackedCapability<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    #[label]
+    pub field_span: Span,
+    pub field_ty: Ty<'tcx>,
+    pub pack: u64,
+    pub capability_align: u64,
+}
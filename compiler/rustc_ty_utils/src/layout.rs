@@ -21,6 +21,7 @@
 use rand::{seq::SliceRandom, SeedableRng};
 use rand_xoshiro::Xoshiro128StarStar;
 
+use crate::errors::PackedCapability;
 use crate::layout_sanity_check::sanity_check_layout;
 
 pub fn provide(providers: &mut ty::query::Providers) {
@@ -382,7 +383,7 @@ fn layout_of_uncached<'tcx>(
             ty::FloatTy::F64 => F64,
         }),
         ty::FnPtr(_) => {
-            let mut ptr = scalar_unit(Pointer);
+            let mut ptr = scalar_unit(Pointer(dl.default_address_space));
             ptr.valid_range_mut().start = 1;
             tcx.intern_layout(LayoutS::scalar(cx, ptr))
         }
@@ -400,7 +401,7 @@ fn layout_of_uncached<'tcx>(
 
         // Potentially-wide pointers.
         ty::Ref(_, pointee, _) | ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) => {
-            let mut data_ptr = scalar_unit(Pointer);
+            let mut data_ptr = scalar_unit(Pointer(dl.default_address_space));
             if !ty.is_unsafe_ptr() {
                 data_ptr.valid_range_mut().start = 1;
             }
@@ -416,11 +417,14 @@ fn layout_of_uncached<'tcx>(
                     return Ok(tcx.intern_layout(LayoutS::scalar(cx, data_ptr)));
                 }
                 ty::Slice(_) | ty::Str => {
-                    // TODO: More complexity needed here.
+                    // The length is a `usize`, which is only as wide as an address, so on
+                    // capability targets it's followed by padding up to the alignment of the
+                    // data capability. `ptr::metadata_offset` and `PtrComponents` rely on this
+                    // `(data, metadata)` order.
                     scalar_unit(Int(dl.ptr_sized_integer(None), false))
                 }
                 ty::Dynamic(..) => {
-                    let mut vtable = scalar_unit(Pointer);
+                    let mut vtable = scalar_unit(Pointer(dl.default_address_space));
                     vtable.valid_range_mut().start = 1;
                     vtable
                 }
@@ -435,7 +439,7 @@ fn layout_of_uncached<'tcx>(
             // TODO: More complexity needed here.
             let mut data = scalar_unit(Int(dl.ptr_sized_integer(None), false));
             data.valid_range_mut().start = 0;
-            let mut vtable = scalar_unit(Pointer);
+            let mut vtable = scalar_unit(Pointer(dl.default_address_space));
             vtable.valid_range_mut().start = 1;
             tcx.intern_layout(scalar_pair(cx, data, vtable))
         }
@@ -664,6 +668,26 @@ fn layout_of_uncached<'tcx>(
                 })
                 .collect::<Result<IndexVec<VariantIdx, _>, _>>()?;
 
+            // Packing can put a capability at an offset that isn't a multiple of its size, where
+            // it can't be loaded or stored with its tag any more.
+            let capability_align = dl.ptr_layout(None).align.abi;
+            if dl.is_cheri_purecap
+                && let Some(pack) = def.repr().pack
+                && pack < capability_align
+                && let Some((field, field_layout)) =
+                    iter::zip(def.all_fields(), variants.iter().flatten())
+                        .find(|(_, layout)| layout.contains_capability(cx))
+            {
+                tcx.sess.emit_err(PackedCapability {
+                    span: tcx.def_span(def.did()),
+                    ty,
+                    field_span: tcx.def_span(field.did),
+                    field_ty: field_layout.ty,
+                    pack: pack.bytes(),
+                    capability_align: capability_align.bytes(),
+                });
+            }
+
             if def.is_union() {
                 if def.repr().pack.is_some() && def.repr().align.is_some() {
                     cx.tcx.sess.delay_span_bug(
@@ -782,6 +806,20 @@ fn layout_of_uncached<'tcx>(
                 let mut st = univariant_uninterned(cx, ty, &variants[v], &def.repr(), kind)?;
                 st.variants = Variants::Single { index: v };
 
+                if def.is_cheri_capability() {
+                    if let Some(address_space) = dl.capability_address_space() {
+                        // The fields of a `#[cheri_capability]` struct are all `PhantomData`, so
+                        // the struct itself is laid out as an opaque capability pointer.
+                        let value = scalar_unit(Pointer(address_space));
+                        st.abi = Abi::Scalar(value);
+                        st.ty_size = value.ty_size(dl);
+                        st.val_size = value.val_size(dl);
+                        st.align = value.align(dl);
+                        st.largest_niche = None;
+                        return Ok(tcx.intern_layout(st));
+                    }
+                }
+
                 if def.is_unsafe_cell() {
                     let hide_niches = |scalar: &mut _| match scalar {
                         Scalar::Initialized { value, valid_range } => {
@@ -1726,7 +1764,7 @@ fn record_layout_for_printing_outlined<'tcx>(
     }
 
     // (delay format until we actually need it)
-    let record = |kind, packed, opt_discr_size, variants| {
+    let record = |kind, packed, opt_discr_size, capability_niche, variants| {
         let type_desc = format!("{:?}", layout.ty);
         cx.tcx.sess.code_stats.record_type_size(
             kind,
@@ -1735,6 +1773,7 @@ fn record_layout_for_printing_outlined<'tcx>(
             layout.ty_size,
             packed,
             opt_discr_size,
+            capability_niche,
             variants,
         );
     };
@@ -1747,7 +1786,38 @@ fn record_layout_for_printing_outlined<'tcx>(
 
         ty::Closure(..) => {
             debug!("print-type-size t: `{:?}` record closure", layout.ty);
-            record(DataTypeKind::Closure, false, None, vec![]);
+            record(DataTypeKind::Closure, false, None, None, vec![]);
+            return;
+        }
+
+        // On purecap targets the metadata of a wide pointer is padded out to the alignment of the
+        // data capability, which code matching the C++ ABI needs to know about.
+        ty::Ref(..) | ty::RawPtr(..)
+            if cx.data_layout().is_cheri_purecap && matches!(layout.abi, Abi::ScalarPair(..)) =>
+        {
+            debug!("print-type-size t: `{:?}` record wide pointer", layout.ty);
+            let fields: Vec<_> = ["data_address", "metadata"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let field_layout = layout.field(cx, i);
+                    FieldInfo {
+                        name: Symbol::intern(name),
+                        offset: layout.fields.offset(i).bytes(),
+                        size: field_layout.ty_size.bytes(),
+                        align: field_layout.align.abi.bytes(),
+                    }
+                })
+                .collect();
+            let size = fields.iter().map(|field| field.offset + field.size).max().unwrap_or(0);
+            let variant = VariantInfo {
+                name: None,
+                kind: SizeKind::Exact,
+                align: layout.align.abi.bytes(),
+                size,
+                fields,
+            };
+            record(DataTypeKind::WidePointer, false, None, None, vec![variant]);
             return;
         }
 
@@ -1800,16 +1870,17 @@ fn record_layout_for_printing_outlined<'tcx>(
                     adt_kind.into(),
                     adt_packed,
                     None,
+                    None,
                     vec![build_variant_info(Some(variant_def.name), &fields, layout)],
                 );
             } else {
                 // (This case arises for *empty* enums; so give it
                 // zero variants.)
-                record(adt_kind.into(), adt_packed, None, vec![]);
+                record(adt_kind.into(), adt_packed, None, None, vec![]);
             }
         }
 
-        Variants::Multiple { tag, ref tag_encoding, .. } => {
+        Variants::Multiple { tag, ref tag_encoding, tag_field, .. } => {
             debug!(
                 "print-type-size `{:#?}` adt general variants def {}",
                 layout.ty,
@@ -1830,6 +1901,15 @@ fn record_layout_for_printing_outlined<'tcx>(
                     TagEncoding::Direct => Some(tag.ty_size(cx)),
                     _ => None,
                 },
+                // Only the null address of a capability is used as a niche, see `Niche`.
+                match (tag_encoding, tag.primitive()) {
+                    (TagEncoding::Niche { .. }, Pointer(address_space))
+                        if cx.data_layout().ptr_layout(Some(address_space)).is_fat_ty =>
+                    {
+                        Some(layout.fields.offset(tag_field))
+                    }
+                    _ => None,
+                },
                 variant_infos,
             );
         }
@@ -95,6 +95,14 @@ fn check_layout_abi<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: &TyAndLayou
                             // FIXME: I guess we could also check something here? Like, look at all fields?
                             return;
                         }
+                        FieldsShape::Arbitrary { .. }
+                            if inner
+                                .ty
+                                .ty_adt_def()
+                                .map_or(false, |def| def.is_cheri_capability()) =>
+                        {
+                            // Capabilities are opaque, their fields are all `PhantomData`.
+                        }
                         FieldsShape::Arbitrary { .. } => {
                             // Should be an enum, the only field is the discriminant.
                             assert!(
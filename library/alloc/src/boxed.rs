@@ -953,6 +953,7 @@ impl<T: ?Sized> Box<T> {
     #[stable(feature = "box_raw", since = "1.4.0")]
     #[inline]
     #[must_use = "call `drop(from_raw(ptr))` if you intend to drop the `Box`"]
+    #[cfg_attr(not(test), rustc_diagnostic_item = "box_from_raw")]
     pub unsafe fn from_raw(raw: *mut T) -> Self {
         unsafe { Self::from_raw_in(raw, Global) }
     }
@@ -1054,6 +1055,7 @@ impl<T: ?Sized, A: Allocator> Box<T, A> {
     /// [memory layout]: self#memory-layout
     #[stable(feature = "box_raw", since = "1.4.0")]
     #[inline]
+    #[cfg_attr(not(test), rustc_diagnostic_item = "box_into_raw")]
     pub fn into_raw(b: Self) -> *mut T {
         Self::into_raw_with_allocator(b).0
     }
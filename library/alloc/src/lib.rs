@@ -86,6 +86,9 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 #![allow(explicit_outlives_requirements)]
+// Casts between pointers and integers here implement the APIs that need them, such as
+// `ptr::from_exposed_addr`, whose callers are linted instead.
+#![cfg_attr(not(bootstrap), allow(cheri_int_to_ptr_cast, cheri_ptr_to_int_cast))]
 //
 // Library features:
 #![feature(alloc_layout_extra)]
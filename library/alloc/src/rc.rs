@@ -863,7 +863,11 @@ pub fn as_ptr(this: &Self) -> *const T {
         // SAFETY: This cannot go through Deref::deref or Rc::inner because
         // this is required to retain raw/mut provenance such that e.g. `get_mut` can
         // write through the pointer after the Rc is recovered through `from_raw`.
-        unsafe { ptr::addr_of_mut!((*ptr).value) }
+        //
+        // It is offset to the value rather than projected to the field, so that on CHERI
+        // targets `-Z cheri-subobject-bounds=aggressive` doesn't narrow its capability to the
+        // value: `from_raw` offsets back from it to reach the reference counts.
+        unsafe { ptr.byte_add(data_offset(ptr as *const T)) as *const T }
     }
 
     /// Constructs an `Rc<T>` from a raw pointer.
@@ -2235,8 +2239,10 @@ pub fn as_ptr(&self) -> *const T {
         } else {
             // SAFETY: if is_dangling returns false, then the pointer is dereferenceable.
             // The payload may be dropped at this point, and we have to maintain provenance,
-            // so use raw pointer manipulation.
-            unsafe { ptr::addr_of_mut!((*ptr).value) }
+            // so use raw pointer manipulation. As in `Rc::as_ptr`, offset the pointer
+            // rather than projecting it to the field, so that it keeps the bounds of the whole
+            // allocation for `from_raw`.
+            unsafe { ptr.byte_add(data_offset(ptr as *const T)) as *const T }
         }
     }
 
@@ -165,3 +165,15 @@ fn const_box() {
 
     assert!(VALUE == 42);
 }
+
+#[cfg(target_arch = "morello+c64")]
+#[test]
+fn purecap_raw_round_trip_keeps_capability() {
+    use core::arch::aarch64::cheri::{cheri_length_get, cheri_tag_get};
+
+    let raw = Box::into_raw(Box::new([7u8; 24]));
+    assert!(cheri_tag_get(raw));
+    assert!(cheri_length_get(raw) >= 24);
+    let b = unsafe { Box::from_raw(raw) };
+    assert_eq!(*b, [7; 24]);
+}
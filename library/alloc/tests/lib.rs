@@ -47,6 +47,7 @@
 #![feature(strict_provenance)]
 #![feature(once_cell)]
 #![feature(drain_keep_rest)]
+#![cfg_attr(target_arch = "morello+c64", feature(cheri_intrinsics))]
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
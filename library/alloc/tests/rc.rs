@@ -206,3 +206,32 @@ fn hmm<'a>(val: &'a mut Weak<&'a str>) -> Weak<&'a str> {
     // `val` dropped here while still borrowed
     // borrow might be used here, when `val` is dropped and runs the `Drop` code for type `std::rc::Weak`
 }
+
+// On CHERI purecap targets the pointer given out by `into_raw` must stay a valid capability
+// over the whole allocation, since `from_raw` offsets back from it to the reference counts.
+#[cfg(target_arch = "morello+c64")]
+#[test]
+fn purecap_raw_round_trip_keeps_capability() {
+    use core::arch::aarch64::cheri::{cheri_base_get, cheri_tag_get};
+
+    let rc = Rc::new([1u64; 4]);
+    for _ in 0..8 {
+        let raw = Rc::into_raw(Rc::clone(&rc));
+        assert!(cheri_tag_get(raw));
+        assert!(cheri_base_get(raw) <= raw.addr() - 2 * mem::size_of::<usize>());
+        let clone = unsafe { Rc::from_raw(raw) };
+        assert_eq!(Rc::strong_count(&clone), 2);
+        assert_eq!(*clone, [1; 4]);
+    }
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let raw = Rc::downgrade(&rc).into_raw();
+    assert!(cheri_tag_get(raw));
+    let weak = unsafe { Weak::from_raw(raw) };
+    assert_eq!(weak.upgrade().as_deref(), Some(&[1; 4]));
+
+    let any: Rc<dyn Any> = Rc::new(5u32);
+    let raw = Rc::into_raw(any);
+    assert!(cheri_tag_get(raw));
+    assert_eq!(unsafe { Rc::from_raw(raw) }.downcast::<u32>().ok().as_deref(), Some(&5));
+}
@@ -7,6 +7,8 @@
 use crate::cmp;
 use crate::error::Error;
 use crate::fmt;
+#[cfg(not(bootstrap))]
+use crate::intrinsics;
 use crate::mem::{self, ValidAlign};
 use crate::ptr::NonNull;
 
@@ -302,6 +304,39 @@ pub fn pad_to_align(&self) -> Layout {
         unsafe { Layout::from_size_align_unchecked(new_size, self.align()) }
     }
 
+    /// Creates a layout whose size and alignment are large enough for a
+    /// capability with bounds covering exactly the allocation to be
+    /// represented.
+    ///
+    /// CHERI capabilities compress their bounds, so large regions can only be
+    /// bounded exactly if their length is rounded up and their base is
+    /// aligned more strictly than the type requires. An allocator that hands
+    /// out memory for the returned layout can narrow the bounds of the result
+    /// to exactly that memory. On targets without capabilities this returns
+    /// `self` unchanged.
+    ///
+    /// On arithmetic overflow, returns `LayoutError`.
+    #[unstable(feature = "layout_to_representable", issue = "none")]
+    #[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+    #[must_use = "this returns a new `Layout`, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn to_representable(&self) -> Result<Self, LayoutError> {
+        cfg_if! {
+            if #[cfg(bootstrap)] {
+                Ok(*self)
+            } else {
+                let size = intrinsics::cheri_representable_length(self.size());
+                // The lowest set bit of the mask is the alignment the base needs.
+                let mask = intrinsics::cheri_representable_alignment_mask(self.size());
+                let align = (!mask).wrapping_add(1);
+                // `cmp::max` isn't const.
+                let align = if align > self.align() { align } else { self.align() };
+                Layout::from_size_align(size, align)
+            }
+        }
+    }
+
     /// Creates a layout describing the record for `n` instances of
     /// `self`, with a suitable amount of padding between each to
     /// ensure that each instance is given its requested size and
@@ -0,0 +1,362 @@
+//! Intrinsics for inspecting Morello capabilities.
+//!
+//! Every pointer on a purecap target is a capability: besides its address it carries bounds,
+//! permissions, an object type and a validity tag. The functions in this module read and narrow
+//! these fields without dereferencing the pointer, and so are safe to call on any pointer. They
+//! lower directly to the corresponding `llvm.cheri.cap.*` intrinsics.
+//!
+//! Capabilities are monotonic: no operation here can grant a pointer more rights than it
+//! started with. An operation that would do so instead clears the tag of its result, and any
+//! later access through that result faults.
+
+#![unstable(feature = "cheri_intrinsics", issue = "none")]
+
+use crate::arch::asm;
+use crate::intrinsics;
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.cheri.cap.base.get.i64"]
+    fn llvm_cheri_cap_base_get(cap: *const u8) -> usize;
+    #[link_name = "llvm.cheri.cap.offset.get.i64"]
+    fn llvm_cheri_cap_offset_get(cap: *const u8) -> usize;
+    #[link_name = "llvm.cheri.cap.perms.get.i64"]
+    fn llvm_cheri_cap_perms_get(cap: *const u8) -> usize;
+    #[link_name = "llvm.cheri.cap.perms.and.i64"]
+    fn llvm_cheri_cap_perms_and(cap: *const u8, perms: usize) -> *const u8;
+    #[link_name = "llvm.cheri.cap.type.get.i64"]
+    fn llvm_cheri_cap_type_get(cap: *const u8) -> isize;
+    #[link_name = "llvm.cheri.cap.tag.get"]
+    fn llvm_cheri_cap_tag_get(cap: *const u8) -> bool;
+    #[link_name = "llvm.cheri.cap.tag.clear"]
+    fn llvm_cheri_cap_tag_clear(cap: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.sealed.get"]
+    fn llvm_cheri_cap_sealed_get(cap: *const u8) -> bool;
+    #[link_name = "llvm.cheri.cap.bounds.set.i64"]
+    fn llvm_cheri_cap_bounds_set(cap: *const u8, len: usize) -> *const u8;
+    #[link_name = "llvm.cheri.cap.bounds.set.exact.i64"]
+    fn llvm_cheri_cap_bounds_set_exact(cap: *const u8, len: usize) -> *const u8;
+    #[link_name = "llvm.cheri.cap.seal"]
+    fn llvm_cheri_cap_seal(cap: *const u8, auth: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.unseal"]
+    fn llvm_cheri_cap_unseal(cap: *const u8, auth: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.seal.entry"]
+    fn llvm_cheri_cap_seal_entry(cap: *const u8) -> *const u8;
+}
+
+/// Returns the address of `ptr`, i.e. the location it currently points at.
+#[inline]
+#[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+pub const fn cheri_address_get<T: ?Sized>(ptr: *const T) -> usize {
+    intrinsics::ptr_addr(ptr as *const u8)
+}
+
+/// Returns the lowest address that `ptr` may be used to access.
+#[inline]
+pub fn cheri_base_get<T: ?Sized>(ptr: *const T) -> usize {
+    // SAFETY: reading a capability field has no preconditions.
+    unsafe { llvm_cheri_cap_base_get(ptr as *const u8) }
+}
+
+/// Returns the number of bytes, starting at its base, that `ptr` may be used to access.
+///
+/// In a const context this is the representable length of the allocation that `ptr` points
+/// into, which is what the bounds of the global the compiler emits for it will cover.
+#[inline]
+#[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+pub const fn cheri_length_get<T: ?Sized>(ptr: *const T) -> usize {
+    intrinsics::cheri_cap_length_get(ptr as *const u8)
+}
+
+/// Returns the distance of the address of `ptr` from its base.
+#[inline]
+pub fn cheri_offset_get<T: ?Sized>(ptr: *const T) -> usize {
+    // SAFETY: reading a capability field has no preconditions.
+    unsafe { llvm_cheri_cap_offset_get(ptr as *const u8) }
+}
+
+/// The permissions of a Morello capability.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Perms(usize);
+
+impl Perms {
+    /// The capability may be stored anywhere, rather than only through capabilities with
+    /// [`Perms::STORE_LOCAL_CAP`].
+    pub const GLOBAL: Self = Perms(1 << 0);
+    /// Code reached through the capability runs in executive rather than restricted mode.
+    pub const EXECUTIVE: Self = Perms(1 << 1);
+    /// A permission with a meaning defined by software.
+    pub const SW0: Self = Perms(1 << 2);
+    /// A permission with a meaning defined by software.
+    pub const SW1: Self = Perms(1 << 3);
+    /// A permission with a meaning defined by software.
+    pub const SW2: Self = Perms(1 << 4);
+    /// A permission with a meaning defined by software.
+    pub const SW3: Self = Perms(1 << 5);
+    /// Capabilities loaded through the capability keep their store permissions.
+    pub const MUTABLE_LOAD: Self = Perms(1 << 6);
+    /// The capability may be used to set the compartment ID.
+    pub const COMPARTMENT_ID: Self = Perms(1 << 7);
+    /// The capability may be used in a branch to a sealed pair of capabilities.
+    pub const BRANCH_SEALED_PAIR: Self = Perms(1 << 8);
+    /// Code reached through the capability may access system registers.
+    pub const SYSTEM_REGS: Self = Perms(1 << 9);
+    /// The capability may be used as the authority to unseal.
+    pub const UNSEAL: Self = Perms(1 << 10);
+    /// The capability may be used as the authority to seal.
+    pub const SEAL: Self = Perms(1 << 11);
+    /// Capabilities without [`Perms::GLOBAL`] may be stored through the capability.
+    pub const STORE_LOCAL_CAP: Self = Perms(1 << 12);
+    /// Capabilities may be stored through the capability.
+    pub const STORE_CAP: Self = Perms(1 << 13);
+    /// Capabilities may be loaded through the capability.
+    pub const LOAD_CAP: Self = Perms(1 << 14);
+    /// Code may be executed through the capability.
+    pub const EXECUTE: Self = Perms(1 << 15);
+    /// Data may be stored through the capability.
+    pub const STORE: Self = Perms(1 << 16);
+    /// Data may be loaded through the capability.
+    pub const LOAD: Self = Perms(1 << 17);
+
+    /// No permissions at all.
+    pub const fn empty() -> Self {
+        Perms(0)
+    }
+
+    /// Returns the permissions given by the bits of `bits`.
+    pub const fn from_bits(bits: usize) -> Self {
+        Perms(bits)
+    }
+
+    /// Returns the raw permission bits.
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Returns whether all of the permissions in `other` are also in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Perms {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Perms(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Perms {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Perms {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Perms(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for Perms {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Not for Perms {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Perms(!self.0)
+    }
+}
+
+/// Returns the permissions of `ptr`.
+#[inline]
+pub fn cheri_perms_get<T: ?Sized>(ptr: *const T) -> Perms {
+    // SAFETY: reading a capability field has no preconditions.
+    Perms(unsafe { llvm_cheri_cap_perms_get(ptr as *const u8) })
+}
+
+/// Drops every permission of `ptr` that isn't in `perms`.
+///
+/// For example, `cheri_perms_and(ptr, !(Perms::STORE | Perms::STORE_CAP))` gives a read-only
+/// view of the memory `ptr` points to.
+#[inline]
+pub fn cheri_perms_and<T>(ptr: *const T, perms: Perms) -> *const T {
+    // SAFETY: clearing permissions of a capability has no preconditions.
+    unsafe { llvm_cheri_cap_perms_and(ptr.cast(), perms.0).cast() }
+}
+
+/// Returns the object type of `ptr`, or `-1` if it is unsealed.
+#[inline]
+pub fn cheri_type_get<T: ?Sized>(ptr: *const T) -> isize {
+    // SAFETY: reading a capability field has no preconditions.
+    unsafe { llvm_cheri_cap_type_get(ptr as *const u8) }
+}
+
+/// Returns whether `ptr` is a valid capability, i.e. whether its tag is set.
+///
+/// Only tagged capabilities can be dereferenced; the tag is cleared by any operation that
+/// would otherwise forge a capability, such as building a pointer from an integer.
+#[inline]
+pub fn cheri_tag_get<T: ?Sized>(ptr: *const T) -> bool {
+    // SAFETY: reading a capability field has no preconditions.
+    unsafe { llvm_cheri_cap_tag_get(ptr as *const u8) }
+}
+
+/// Returns a copy of `ptr` with its tag cleared.
+///
+/// The result keeps the address, bounds and permissions of `ptr`, so it still compares and
+/// prints the same, but it can never be dereferenced again, nor turned back into a valid
+/// capability. This is useful when a pointer is only kept for its address and must not be
+/// usable as an authority, for example when it is handed to less trusted code as an identifier.
+#[inline]
+pub fn cheri_tag_clear<T>(ptr: *const T) -> *const T {
+    // SAFETY: clearing the tag of a capability has no preconditions.
+    unsafe { llvm_cheri_cap_tag_clear(ptr.cast()).cast() }
+}
+
+/// Returns whether `ptr` is sealed, and so can't be modified or dereferenced.
+#[inline]
+pub fn cheri_sealed_get<T: ?Sized>(ptr: *const T) -> bool {
+    // SAFETY: reading a capability field has no preconditions.
+    unsafe { llvm_cheri_cap_sealed_get(ptr as *const u8) }
+}
+
+/// Narrows the bounds of `ptr` to the `len` bytes starting at its address.
+///
+/// The bounds may be rounded outwards, to the nearest ones that can be represented; see
+/// [`cheri_bounds_set_exact`] for a variant that doesn't. If the requested bounds aren't
+/// within those of `ptr`, the result is untagged.
+#[inline]
+pub fn cheri_bounds_set<T>(ptr: *const T, len: usize) -> *const T {
+    // SAFETY: narrowing the bounds of a capability has no preconditions.
+    unsafe { llvm_cheri_cap_bounds_set(ptr.cast(), len).cast() }
+}
+
+/// Narrows the bounds of `ptr` to exactly the `len` bytes starting at its address.
+///
+/// If those bounds can't be represented, or aren't within those of `ptr`, the result is
+/// untagged, so that any access through it traps.
+#[inline]
+pub fn cheri_bounds_set_exact<T>(ptr: *const T, len: usize) -> *const T {
+    // SAFETY: narrowing the bounds of a capability has no preconditions.
+    unsafe { llvm_cheri_cap_bounds_set_exact(ptr.cast(), len).cast() }
+}
+
+/// Seals `ptr` with the object type given by the address of `auth`.
+///
+/// A sealed capability can't be dereferenced or modified until it is unsealed with the same
+/// object type, which makes it usable as an opaque handle. `auth` must be a tagged, unsealed
+/// capability with the seal permission whose bounds include its address; otherwise the result
+/// is untagged.
+#[inline]
+pub fn cheri_seal<T, A>(ptr: *const T, auth: *const A) -> *const T {
+    // SAFETY: sealing a capability has no preconditions.
+    unsafe { llvm_cheri_cap_seal(ptr.cast(), auth.cast()).cast() }
+}
+
+/// Unseals `ptr`, which must have been sealed with the object type given by the address of
+/// `auth`.
+///
+/// `auth` must be a tagged, unsealed capability with the unseal permission whose bounds include
+/// its address, and that address must match the object type of `ptr`; otherwise the result is
+/// untagged.
+#[inline]
+pub fn cheri_unseal<T, A>(ptr: *const T, auth: *const A) -> *const T {
+    // SAFETY: unsealing a capability has no preconditions.
+    unsafe { llvm_cheri_cap_unseal(ptr.cast(), auth.cast()).cast() }
+}
+
+/// Turns the code capability `f` into a sealed entry capability (sentry).
+///
+/// A sentry can only be used as the target of a branch, which unseals it, so it can be handed
+/// to other compartments as an entry point without giving them access to the code or data it
+/// covers.
+///
+/// # Safety
+///
+/// `f` must be a function pointer, or a pointer to code that is a valid entry point. Calling the
+/// result runs that code without any of the checks a Rust function call would normally imply.
+#[inline]
+pub unsafe fn cheri_sentry_create<F: Copy>(f: F) -> F {
+    assert!(crate::mem::size_of::<F>() == crate::mem::size_of::<*const u8>());
+    // SAFETY: `F` is pointer-sized, and the caller guarantees that it is a code pointer.
+    unsafe {
+        let f: *const u8 = crate::mem::transmute_copy(&f);
+        crate::mem::transmute_copy(&llvm_cheri_cap_seal_entry(f))
+    }
+}
+
+/// Rounds `len` up to the nearest length that the bounds of a capability can represent
+/// exactly (`RRLEN`).
+///
+/// Allocators can pad allocations to this length, so that bounds covering exactly the
+/// allocation can be set with [`cheri_bounds_set_exact`].
+#[inline]
+#[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+pub const fn cheri_representable_length(len: usize) -> usize {
+    intrinsics::cheri_representable_length(len)
+}
+
+/// Returns the mask that the base of a capability of length `len` must be aligned to for its
+/// bounds to be represented exactly (`RRMASK`).
+///
+/// An address `addr` is suitably aligned if `addr & !cheri_representable_alignment_mask(len)`
+/// is zero.
+#[inline]
+#[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+pub const fn cheri_representable_alignment_mask(len: usize) -> usize {
+    intrinsics::cheri_representable_alignment_mask(len)
+}
+
+/// Returns the thread pointer, the capability in `CTPIDR_EL0` that the purecap ABI uses to find
+/// the current thread's TLS block.
+///
+/// Its bounds cover the whole static TLS area of the thread, so it can be used to reach the
+/// variables of any module loaded at startup, but not those of modules loaded with `dlopen`.
+#[inline]
+pub fn cheri_thread_pointer() -> *mut u8 {
+    let tp: *mut u8;
+    // SAFETY: reading `CTPIDR_EL0` has no side effects.
+    unsafe {
+        asm!("mrs {}, ctpidr_el0", out(creg) tp, options(nomem, nostack, preserves_flags));
+    }
+    tp
+}
+
+/// A frame record, which the prologue of every function that keeps a frame pointer pushes onto
+/// the stack and points `C29` at.
+///
+/// Following `prev` from [`cheri_frame_record`] walks the call stack without unwind tables, as
+/// long as every function on it was built with `-C force-frame-pointers`. The walk ends at a
+/// null `prev`, or at a function that was built without frame pointers and so didn't push a
+/// record of its own.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FrameRecord {
+    /// The frame record of the caller.
+    pub prev: *const FrameRecord,
+    /// The capability the function returns through, which is usually a sentry.
+    pub return_address: *const u8,
+}
+
+/// Returns the frame record of the calling function, i.e. the value of `C29`.
+///
+/// This is only meaningful if the caller was built with `-C force-frame-pointers`; otherwise
+/// `C29` may hold anything.
+#[inline(always)]
+pub fn cheri_frame_record() -> *const FrameRecord {
+    let fp: *const FrameRecord;
+    // SAFETY: reading `C29` has no side effects.
+    unsafe {
+        asm!("mov {}, c29", out(creg) fp, options(nomem, nostack, preserves_flags));
+    }
+    fp
+}
@@ -0,0 +1,271 @@
+//! Experimental capability-aware APIs.
+//!
+//! # Reference introspection
+//!
+//! References on a purecap target are capabilities just like raw pointers, and [`perms_of`]
+//! and friends expose the permissions and bounds that the compiler gave them. They are meant for
+//! instrumentation and tests that check those capabilities, for example that a shared reference
+//! can't be used to store to its referent. Nothing here is needed, or intended, to write
+//! correct programs.
+//!
+//! The permissions of a reference are currently inherited unchanged from the pointer it was
+//! derived from: a `&T` reborrowed from a `&mut T` still has [`Perms::STORE`]. Code checking
+//! for narrower permissions should expect those checks to fail until the compiler narrows them.
+//!
+//! # Sealed handles
+//!
+//! [`Sealed`] wraps a pointer sealed with an object type. The hardware refuses to dereference
+//! or modify a sealed capability, so it can be handed to less trusted code as an opaque handle
+//! that can't be forged or tampered with, only passed back and unsealed by whoever holds the
+//! authority for its object type. `Sealed<T>` is `#[repr(transparent)]`, so it is passed to and
+//! returned from foreign functions in a capability register, exactly like the pointer it wraps.
+//!
+//! # Unions of capabilities and other data
+//!
+//! A union can overlap a pointer with data that isn't a capability, such as a `usize` or a byte
+//! array. Reading and writing its fields then behaves as follows:
+//!
+//! * Reading the other field after writing the pointer gives the in-memory bits of the
+//!   capability. The address is in its low-order bytes, and the rest holds the compressed
+//!   bounds, permissions and object type, whose encoding is specific to the architecture.
+//!   The tag is not part of these bits.
+//! * Writing the other field clears the tag of the capability, even if only some of its bytes
+//!   are written or the bits written are the same as before. Reading the pointer afterwards
+//!   gives a capability that still has those bits, but faults on any access.
+//!
+//! A pointer therefore can't be rebuilt from its bits through a union. Use the address APIs on
+//! pointers, such as `with_addr`, to derive a pointer with a new address from one that is still
+//! valid. The `cheri_capability_unions` lint warns about such unions in `extern` signatures,
+//! since C code often relies on using them both ways.
+
+use crate::arch::aarch64::cheri;
+use crate::fmt;
+use crate::marker::PhantomData;
+use crate::ops::Range;
+
+pub use crate::arch::aarch64::cheri::Perms;
+
+/// Returns the permissions of the capability behind `r`.
+///
+/// # Example
+///
+/// ```
+/// #![feature(cheri_intrinsics, cheri_reference_perms)]
+/// use core::arch::cheri::{perms_of, Perms};
+///
+/// let x = 7;
+/// assert!(perms_of(&x).contains(Perms::LOAD));
+/// ```
+#[inline]
+#[unstable(feature = "cheri_reference_perms", issue = "none")]
+pub fn perms_of<T: ?Sized>(r: &T) -> Perms {
+    cheri::cheri_perms_get(r)
+}
+
+/// Returns the permissions of the capability behind `r`.
+///
+/// This is the same as [`perms_of`], but doesn't need a shared reborrow of `r` to be created
+/// first, so it reports the permissions of the mutable reference itself.
+#[inline]
+#[unstable(feature = "cheri_reference_perms", issue = "none")]
+pub fn perms_of_mut<T: ?Sized>(r: &mut T) -> Perms {
+    cheri::cheri_perms_get(r)
+}
+
+/// Returns whether data may be loaded through `r`.
+#[inline]
+#[unstable(feature = "cheri_reference_perms", issue = "none")]
+pub fn can_load<T: ?Sized>(r: &T) -> bool {
+    perms_of(r).contains(Perms::LOAD)
+}
+
+/// Returns whether data may be stored through `r`.
+#[inline]
+#[unstable(feature = "cheri_reference_perms", issue = "none")]
+pub fn can_store<T: ?Sized>(r: &T) -> bool {
+    perms_of(r).contains(Perms::STORE)
+}
+
+/// Returns the range of addresses that the capability behind `r` may be used to access.
+///
+/// This always contains the referent, but may be larger: bounds are only narrowed when the
+/// compiler or the allocator chose to, and may be rounded outwards to be representable.
+#[inline]
+#[unstable(feature = "cheri_reference_perms", issue = "none")]
+pub fn bounds_of<T: ?Sized>(r: &T) -> Range<usize> {
+    let base = cheri::cheri_base_get(r);
+    // The length of an unbounded capability covers the whole address space, so the end may
+    // not be representable.
+    base..base.saturating_add(cheri::cheri_length_get(r))
+}
+
+/// Checks that `len` bytes at `ptr` can be accessed through it, for a store if `store` is set.
+///
+/// `-Z sanitizer=cheri` calls this before every access through a raw pointer. If the hardware
+/// would fault on the access, this reports the capability and the location of the access, and
+/// aborts instead of leaving the process to be killed by `SIGPROT`.
+#[lang = "cheri_check_access"]
+#[track_caller]
+#[rustc_nounwind]
+fn check_access(ptr: *const u8, len: usize, store: bool) {
+    let addr = cheri::cheri_address_get(ptr);
+    let base = cheri::cheri_base_get(ptr);
+    let length = cheri::cheri_length_get(ptr);
+    let perms = cheri::cheri_perms_get(ptr);
+    let offset = addr.wrapping_sub(base);
+    let fault = if !cheri::cheri_tag_get(ptr) {
+        "tag"
+    } else if cheri::cheri_sealed_get(ptr) {
+        "seal"
+    } else if !perms.contains(if store { Perms::STORE } else { Perms::LOAD }) {
+        "permission"
+    } else if addr < base || offset > length || length - offset < len {
+        "bounds"
+    } else {
+        return;
+    };
+    let access = if store { "store" } else { "load" };
+    crate::panicking::panic_nounwind_fmt(format_args!(
+        "capability {fault} violation: {access} of {len} bytes at {addr:#x} through a \
+         capability with base {base:#x}, length {length:#x} and permissions {perms:?}"
+    ))
+}
+
+/// Checks that `len` bytes at `ptr`, a pointer cast from an integer, can be accessed through it.
+///
+/// `-Z check-provenance-casts=runtime` calls this before every access through the result of an
+/// int-to-pointer cast. In the purecap ABI such a pointer only has a valid capability if the
+/// integer came from one, so this reports the integer and the location of the access, and aborts
+/// instead of leaving the process to be killed by `SIGPROT`.
+#[lang = "cheri_check_provenance_cast"]
+#[track_caller]
+#[rustc_nounwind]
+fn check_provenance_cast(ptr: *const u8, len: usize) {
+    let addr = cheri::cheri_address_get(ptr);
+    let base = cheri::cheri_base_get(ptr);
+    let length = cheri::cheri_length_get(ptr);
+    let offset = addr.wrapping_sub(base);
+    let fault = if !cheri::cheri_tag_get(ptr) {
+        "untagged"
+    } else if cheri::cheri_sealed_get(ptr) {
+        "sealed"
+    } else if addr < base || offset > length || length - offset < len {
+        "out of bounds"
+    } else {
+        return;
+    };
+    crate::panicking::panic_nounwind_fmt(format_args!(
+        "pointer cast from the integer {addr:#x} is {fault}: {len} bytes can't be accessed \
+         through its capability with base {base:#x} and length {length:#x}"
+    ))
+}
+
+/// A pointer to a `T` sealed with an object type.
+///
+/// The only way to get the pointer back is [`unseal`](Sealed::unseal) with a capability that
+/// has the unseal permission for the same object type. Until then the hardware faults on any
+/// attempt to dereference the pointer or change its address, bounds or permissions, so a
+/// `Sealed<T>` can be given to untrusted code as a handle that it can store and pass back but
+/// neither use nor forge.
+///
+/// The object type is taken from the address of the authorizing capability, the *key*, which
+/// must have [`Perms::SEAL`] (to seal) or [`Perms::UNSEAL`] (to unseal) and bounds that
+/// include that address. Keys are normally handed out by the operating system.
+///
+/// # Example
+///
+/// ```ignore (needs a sealing key from the operating system)
+/// #![feature(cheri_intrinsics, cheri_sealed)]
+/// use core::arch::cheri::Sealed;
+///
+/// let mut secret = 42;
+/// let handle = Sealed::seal(&mut secret, key).unwrap();
+/// // `handle` can be handed out; only holders of `key` can get at `secret`.
+/// let ptr = handle.unseal(key).unwrap();
+/// assert_eq!(unsafe { *ptr }, 42);
+/// ```
+#[unstable(feature = "cheri_sealed", issue = "none")]
+#[repr(transparent)]
+pub struct Sealed<T> {
+    ptr: *const T,
+    phantom: PhantomData<*mut T>,
+}
+
+#[unstable(feature = "cheri_sealed", issue = "none")]
+impl<T> Sealed<T> {
+    /// Seals `ptr` with the object type given by the address of `key`.
+    ///
+    /// Returns `None` if `key` doesn't authorize sealing with that object type or if `ptr` is
+    /// not a valid, unsealed capability.
+    #[inline]
+    pub fn seal<K>(ptr: *mut T, key: *const K) -> Option<Self> {
+        if !cheri::cheri_tag_get(ptr) || cheri::cheri_sealed_get(ptr) {
+            return None;
+        }
+        let sealed = cheri::cheri_seal(ptr as *const T, key);
+        // Sealing with an unsuitable key clears the tag of the result.
+        if !cheri::cheri_tag_get(sealed) {
+            return None;
+        }
+        Some(Sealed { ptr: sealed, phantom: PhantomData })
+    }
+
+    /// Wraps a capability that is already sealed, for example one received from foreign code.
+    ///
+    /// Returns `None` if `ptr` is not a valid, sealed capability.
+    #[inline]
+    pub fn from_raw(ptr: *const T) -> Option<Self> {
+        if !cheri::cheri_tag_get(ptr) || !cheri::cheri_sealed_get(ptr) {
+            return None;
+        }
+        Some(Sealed { ptr, phantom: PhantomData })
+    }
+
+    /// Returns the sealed capability, for example to pass it to foreign code.
+    ///
+    /// The result stays sealed and can't be dereferenced.
+    #[inline]
+    pub fn as_raw(&self) -> *const T {
+        self.ptr
+    }
+
+    /// Returns the object type that the pointer is sealed with.
+    #[inline]
+    pub fn otype(&self) -> isize {
+        cheri::cheri_type_get(self.ptr)
+    }
+
+    /// Unseals the pointer with the object type given by the address of `key`.
+    ///
+    /// Returns `None` if `key` doesn't authorize unsealing this object type. The returned
+    /// pointer has the address, bounds and permissions it had when it was sealed.
+    #[inline]
+    pub fn unseal<K>(self, key: *const K) -> Option<*mut T> {
+        let ptr = cheri::cheri_unseal(self.ptr, key);
+        // Unsealing with the wrong key clears the tag of the result, or leaves it sealed.
+        if !cheri::cheri_tag_get(ptr) || cheri::cheri_sealed_get(ptr) {
+            return None;
+        }
+        Some(ptr as *mut T)
+    }
+}
+
+// Manual impls needed to avoid `T: Clone` and `T: Copy` bounds.
+
+#[unstable(feature = "cheri_sealed", issue = "none")]
+impl<T> Clone for Sealed<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "cheri_sealed", issue = "none")]
+impl<T> Copy for Sealed<T> {}
+
+#[unstable(feature = "cheri_sealed", issue = "none")]
+impl<T> fmt::Debug for Sealed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sealed").field("otype", &self.otype()).finish_non_exhaustive()
+    }
+}
@@ -101,6 +101,29 @@ macro_rules! type_alias {
 #[unstable(feature = "c_size_t", issue = "88345")]
 pub type c_ssize_t = isize;
 
+/// Equivalent to C's `intptr_t` type, from `stdint.h` (or `cstdint` for C++).
+///
+/// This type is [`isize`] on most platforms. On CHERI purecap targets `intptr_t` is
+/// capability-sized and carries provenance, so it is a pointer there instead; use
+/// [`addr`][pointer::addr] and [`with_addr`][pointer::with_addr] to do arithmetic on it.
+#[unstable(feature = "c_intptr_t", issue = "none")]
+pub type c_intptr_t = c_intptr_definition::c_intptr_t;
+
+/// Equivalent to C's `uintptr_t` type, from `stdint.h` (or `cstdint` for C++).
+///
+/// This type is [`usize`] on most platforms. On CHERI purecap targets `uintptr_t` is
+/// capability-sized and carries provenance, so it is a pointer there instead; use
+/// [`addr`][pointer::addr] and [`with_addr`][pointer::with_addr] to do arithmetic on it.
+#[unstable(feature = "c_intptr_t", issue = "none")]
+pub type c_uintptr_t = c_intptr_definition::c_uintptr_t;
+
+/// Equivalent to C's `ptraddr_t` type, from `stddef.h` on CHERI targets.
+///
+/// This is the type of the address part of a pointer, and is always [`usize`]. On CHERI
+/// purecap targets it is narrower than [`c_uintptr_t`].
+#[unstable(feature = "c_intptr_t", issue = "none")]
+pub type c_ptraddr_t = usize;
+
 mod c_char_definition {
     cfg_if! {
         // These are the targets on which c_char is unsigned.
@@ -201,6 +224,23 @@ mod c_long_definition {
     }
 }
 
+mod c_intptr_definition {
+    cfg_if! {
+        // These are the CHERI purecap targets, where pointers are capabilities twice the width of
+        // their address.
+        if #[cfg(any(
+            all(target_pointer_width = "64", target_pointer_type_width = "128"),
+            all(target_pointer_width = "32", target_pointer_type_width = "64"),
+        ))] {
+            pub type c_intptr_t = *mut super::c_void;
+            pub type c_uintptr_t = *mut super::c_void;
+        } else {
+            pub type c_intptr_t = isize;
+            pub type c_uintptr_t = usize;
+        }
+    }
+}
+
 // N.B., for LLVM to recognize the void pointer type and by extension
 //     functions like malloc(), we need to have it represented as i8* in
 //     LLVM bitcode. The enum used here ensures this and prevents misuse
@@ -218,6 +218,10 @@ pub fn spin_loop() {
 /// extent to which it can block optimisations may vary depending upon the platform and code-gen
 /// backend used. Programs cannot rely on `black_box` for *correctness* in any way.
 ///
+/// The value itself always comes back unchanged, though. On CHERI targets that includes the
+/// validity tags of any capabilities it contains, so pointers passed through `black_box` remain
+/// dereferenceable.
+///
 /// [`std::convert::identity`]: crate::convert::identity
 #[inline]
 #[stable(feature = "bench_black_box", since = "CURRENT_RUSTC_VERSION")]
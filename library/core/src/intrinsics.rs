@@ -1312,6 +1312,111 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[cfg_attr(not(bootstrap), rustc_safe_intrinsic)]
     pub fn ptr_mask<T>(ptr: *const T, mask: usize) -> *const T;
 
+    /// Returns the address of a pointer, without exposing its provenance.
+    ///
+    /// On CHERI targets this reads the address field of the capability.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// Consider using [`pointer::addr`] instead.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+    #[rustc_safe_intrinsic]
+    pub fn ptr_addr<T>(ptr: *const T) -> usize;
+
+    /// Creates a pointer with the given address and the provenance of `ptr`.
+    ///
+    /// On CHERI targets this sets the address field of the capability, which
+    /// keeps its tag, bounds and permissions as long as the new address is
+    /// representable.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// Consider using [`pointer::with_addr`] instead.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn ptr_with_addr<T>(ptr: *const T, addr: usize) -> *const T;
+
+    /// Compares two pointers for exact equality.
+    ///
+    /// On CHERI targets, where pointers are capabilities, this compares the
+    /// whole capability, including its tag, bounds and permissions, rather
+    /// than just its address. Elsewhere it is the same as `==`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// Consider using [`crate::ptr::eq_exact`] instead.
+    #[cfg(not(bootstrap))]
+    #[rustc_safe_intrinsic]
+    pub fn ptr_eq_exact<T>(a: *const T, b: *const T) -> bool;
+
+    /// Returns the length of the bounds of the capability `ptr`.
+    ///
+    /// During const evaluation this is the representable length of the allocation `ptr`
+    /// points into, which is what the bounds of the global emitted for it cover. Pointers
+    /// without provenance have unbounded capabilities. On targets without capabilities this
+    /// always returns `usize::MAX`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_cap_length_get<T>(ptr: *const T) -> usize;
+
+    /// Rounds `len` up to the nearest length that the bounds of a capability can represent
+    /// exactly. On targets without capabilities this returns `len`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_representable_length(len: usize) -> usize;
+
+    /// Returns the mask that the base of a capability of length `len` must be aligned to for
+    /// its bounds to be represented exactly. On targets without capabilities this returns
+    /// `usize::MAX`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_cheri_intrinsics", issue = "none")]
+    #[rustc_safe_intrinsic]
+    pub fn cheri_representable_alignment_mask(len: usize) -> usize;
+
+    /// Derives a capability covering the `len` bytes at `addr` from the default data
+    /// capability (DDC).
+    ///
+    /// This is meant for hybrid-mode code that receives plain addresses from code that doesn't
+    /// use capabilities, and needs to access that memory through a capability with explicit
+    /// bounds. `C` must be a `#[cheri_capability]` type.
+    ///
+    /// The result is only tagged if DDC is tagged, and `addr..addr + len` lies within its bounds
+    /// and is representable. It has the permissions of DDC.
+    ///
+    /// # Safety
+    ///
+    /// The result gives access to whatever DDC gives access to, so it must only be used within
+    /// the memory the address was handed out for, as with a pointer built from an integer.
+    #[cfg(not(bootstrap))]
+    pub fn cheri_cap_build<C>(addr: usize, len: usize) -> C;
+
     /// Equivalent to the appropriate `llvm.memcpy.p0i8.0i8.*` intrinsic, with
     /// a size of `count` * `size_of::<T>()` and an alignment of
     /// `min_align_of::<T>()`
@@ -2050,6 +2155,10 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// Returns `1` if the pointers are guaranteed equal
     /// Returns `0` if the pointers are guaranteed inequal
     ///
+    /// Only the addresses are compared, also on CHERI targets where pointers are capabilities.
+    /// During const evaluation the result is known for pointers into the same data allocation,
+    /// and for pointers strictly inside two different statics.
+    ///
     /// Note that, unlike most intrinsics, this is safe to call;
     /// it does not require an `unsafe` block.
     /// Therefore, implementations must not require the user to uphold
@@ -2241,6 +2350,34 @@ pub(crate) fn is_valid_allocation_size<T>(len: usize) -> bool {
     len <= max_len
 }
 
+/// Checks whether `ptr` may be used to access `len` instances of `T`, as far
+/// as the hardware can tell: on CHERI targets the capability must be tagged
+/// and its bounds must cover the whole region. Empty regions are never
+/// accessed, so any pointer will do for them, and on other targets this is
+/// always true.
+pub(crate) fn is_within_capability_bounds<T>(ptr: *const T, len: usize) -> bool {
+    cfg_if! {
+        if #[cfg(target_arch = "morello+c64")] {
+            use crate::arch::aarch64::cheri;
+
+            let size = crate::mem::size_of::<T>().wrapping_mul(len);
+            if size == 0 {
+                return true;
+            }
+            let base = cheri::cheri_base_get(ptr);
+            let addr = ptr.addr();
+            cheri::cheri_tag_get(ptr)
+                && base <= addr
+                && (addr - base)
+                    .checked_add(size)
+                    .map_or(false, |end| end <= cheri::cheri_length_get(ptr))
+        } else {
+            let _ = (ptr, len);
+            true
+        }
+    }
+}
+
 /// Checks whether the regions of memory starting at `src` and `dst` of size
 /// `count * size_of::<T>()` do *not* overlap.
 pub(crate) fn is_nonoverlapping<T>(src: *const T, dst: *const T, count: usize) -> bool {
@@ -2264,6 +2401,11 @@ pub(crate) fn is_nonoverlapping<T>(src: *const T, dst: *const T, count: usize) -
 /// The copy is "untyped" in the sense that data may be uninitialized or otherwise violate the
 /// requirements of `T`. The initialization state is preserved exactly.
 ///
+/// On CHERI targets the copy also preserves capability tags, whatever `T` is: every
+/// capability-sized, capability-aligned chunk of `src` is copied to `dst` together with its
+/// tag, as long as `src` and `dst` have the same offset modulo the capability alignment. This
+/// makes `copy_nonoverlapping::<u8>` suitable for relocating memory that contains pointers.
+///
 /// [`memcpy`]: https://en.cppreference.com/w/c/string/byte/memcpy
 ///
 /// # Safety
@@ -2377,6 +2519,8 @@ pub(crate) fn is_nonoverlapping<T>(src: *const T, dst: *const T, count: usize) -
 /// The copy is "untyped" in the sense that data may be uninitialized or otherwise violate the
 /// requirements of `T`. The initialization state is preserved exactly.
 ///
+/// On CHERI targets capability tags are preserved as described for [`copy_nonoverlapping`].
+///
 /// [`memmove`]: https://en.cppreference.com/w/c/string/byte/memmove
 ///
 /// # Safety
@@ -93,6 +93,9 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 #![allow(explicit_outlives_requirements)]
+// Casts between pointers and integers here implement the APIs that need them, such as
+// `ptr::from_exposed_addr`, whose callers are linted instead.
+#![cfg_attr(not(bootstrap), allow(cheri_int_to_ptr_cast, cheri_ptr_to_int_cast))]
 #![allow(incomplete_features)]
 //
 // Library features:
@@ -105,6 +108,7 @@
 #![feature(const_caller_location)]
 #![feature(const_cell_into_inner)]
 #![feature(const_char_convert)]
+#![feature(const_cheri_intrinsics)]
 #![feature(const_clone)]
 #![feature(const_cmp)]
 #![feature(const_discriminant)]
@@ -392,6 +396,18 @@ pub mod arch {
     #[stable(feature = "simd_arch", since = "1.27.0")]
     pub use crate::core_arch::arch::*;
 
+    /// Platform-specific intrinsics for Morello.
+    #[cfg(target_arch = "morello+c64")]
+    #[unstable(feature = "cheri_intrinsics", issue = "none")]
+    pub mod aarch64 {
+        pub mod cheri;
+    }
+
+    /// Experimental capability-aware APIs for CHERI targets.
+    #[cfg(target_arch = "morello+c64")]
+    #[unstable(feature = "cheri_intrinsics", issue = "none")]
+    pub mod cheri;
+
     /// Inline assembly.
     ///
     /// Refer to [rust by example] for a usage guide and the [reference] for
@@ -241,6 +241,11 @@
 /// remain `#[repr(transparent)]`. That said, `MaybeUninit<T>` will *always* guarantee that it has
 /// the same size, alignment, and ABI as `T`; it's just that the way `MaybeUninit` implements that
 /// guarantee may evolve.
+///
+/// On CHERI targets, moving a `MaybeUninit<T>` moves the bytes of `T` as they are, capability
+/// tags included: a `MaybeUninit<*const U>` that has been written to and then passed around by
+/// value still holds a dereferenceable pointer when it is read back with
+/// [`assume_init`](MaybeUninit::assume_init).
 #[stable(feature = "maybe_uninit", since = "1.36.0")]
 // Lang item so we can wrap other types in it. This is useful for generators.
 #[lang = "maybe_uninit"]
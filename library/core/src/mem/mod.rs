@@ -753,6 +753,9 @@ pub const fn swap<T>(x: &mut T, y: &mut T) {
     // Most importantly, this covers primitives and simd types that tend to
     // have size=align where doing anything else can be a pessimization.
     // (This will also be used for ZSTs, though any solution works for them.)
+    //
+    // On CHERI targets both paths keep capability tags: values that may hold
+    // capabilities are moved as whole capabilities, never through integers.
     swap_simple(x, y);
 }
 
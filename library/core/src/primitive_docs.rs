@@ -1472,7 +1472,7 @@ mod prim_ref {}
 /// assert_eq!(mem::size_of_val(&not_bar_ptr), 0);
 ///
 /// let bar_ptr: fn(i32) = not_bar_ptr; // force coercion to function pointer
-/// assert_eq!(mem::size_of_val(&bar_ptr), mem::size_of::<usize>());
+/// assert_eq!(mem::size_of_val(&bar_ptr), mem::size_of::<*const ()>());
 ///
 /// let footgun = &bar; // this is a shared reference to the zero-sized type identifying `bar`
 /// ```
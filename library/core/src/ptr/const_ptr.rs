@@ -182,10 +182,12 @@ pub fn addr(self) -> usize
     where
         T: Sized,
     {
-        // FIXME(strict_provenance_magic): I am magic and should be a compiler intrinsic.
+        #[cfg(not(bootstrap))]
+        return intrinsics::ptr_addr(self);
         // SAFETY: Pointer-to-integer transmutes are valid (if you are okay with losing the
         // provenance).
-        unsafe { mem::transmute(self) }
+        #[cfg(bootstrap)]
+        return unsafe { mem::transmute(self) };
     }
 
     /// Gets the "address" portion of the pointer, and 'exposes' the "provenance" part for future
@@ -242,17 +244,21 @@ pub fn with_addr(self, addr: usize) -> Self
     where
         T: Sized,
     {
-        // FIXME(strict_provenance_magic): I am magic and should be a compiler intrinsic.
-        //
-        // In the mean-time, this operation is defined to be "as if" it was
-        // a wrapping_offset, so we can emulate it as such. This should properly
-        // restore pointer provenance even under today's compiler.
-        let self_addr = self.addr() as isize;
-        let dest_addr = addr as isize;
-        let offset = dest_addr.wrapping_sub(self_addr);
+        // This operation is defined to be "as if" it was a wrapping_offset. On CHERI targets
+        // it sets the address of the capability, which keeps its tag as long as the new
+        // address is representable.
+        #[cfg(not(bootstrap))]
+        return intrinsics::ptr_with_addr(self, addr);
+
+        #[cfg(bootstrap)]
+        {
+            let self_addr = self.addr() as isize;
+            let dest_addr = addr as isize;
+            let offset = dest_addr.wrapping_sub(self_addr);
 
-        // This is the canonical desugarring of this operation
-        self.wrapping_byte_offset(offset)
+            // This is the canonical desugarring of this operation
+            self.wrapping_byte_offset(offset)
+        }
     }
 
     /// Creates a new pointer by mapping `self`'s address to a new one.
@@ -773,6 +779,85 @@ pub fn mask(self, mask: usize) -> *const T {
         unsafe { intrinsics::ptr_offset_from_unsigned(self, origin) }
     }
 
+    /// Calculates the distance between the addresses of two pointers. The returned value is in
+    /// units of T: the distance in bytes is divided by `mem::size_of::<T>()`, rounding towards
+    /// zero.
+    ///
+    /// Unlike [`offset_from`](#method.offset_from), the pointers do not have to point into the
+    /// same allocated object, and only their addresses take part in the computation. This makes
+    /// it suitable for pointers with unrelated provenance, such as CHERI capabilities derived
+    /// from different allocations, where `offset_from` would be undefined behavior. The
+    /// difference wraps around if it does not fit in an `isize`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `T` is a Zero-Sized Type ("ZST").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_offset_from_addr, strict_provenance)]
+    ///
+    /// let a = [0; 5];
+    /// let b = [0; 5];
+    /// let ptr1: *const i32 = &a[1];
+    /// let ptr2: *const i32 = &b[3];
+    /// let diff = ptr2.offset_from_addr(ptr1);
+    /// assert_eq!(ptr1.wrapping_offset(diff).addr(), ptr2.addr());
+    /// assert_eq!(ptr2.offset_from_addr(ptr2), 0);
+    /// ```
+    #[unstable(feature = "ptr_offset_from_addr", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn offset_from_addr(self, origin: *const T) -> isize
+    where
+        T: Sized,
+    {
+        let pointee_size = mem::size_of::<T>();
+        assert!(0 < pointee_size && pointee_size <= isize::MAX as usize);
+        (self.addr().wrapping_sub(origin.addr()) as isize) / pointee_size as isize
+    }
+
+    /// Calculates the distance between the addresses of two pointers, where it's known that
+    /// `self` is at an address equal to or greater than `origin`. The returned value is in units
+    /// of T: the distance in bytes is divided by `mem::size_of::<T>()`, rounding down.
+    ///
+    /// This is the unsigned counterpart of [`offset_from_addr`](#method.offset_from_addr), and
+    /// like it only compares addresses, so the pointers may have unrelated provenance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `T` is a Zero-Sized Type ("ZST"), or if the address of `self` is
+    /// less than the address of `origin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_offset_from_addr)]
+    ///
+    /// let a = [0; 5];
+    /// let ptr1: *const i32 = &a[1];
+    /// let ptr2: *const i32 = &a[3];
+    /// assert_eq!(ptr2.sub_addr(ptr1), 2);
+    /// assert_eq!(ptr2.sub_addr(ptr2), 0);
+    ///
+    /// // This would panic, as the pointers are not correctly ordered:
+    /// // ptr1.sub_addr(ptr2)
+    /// ```
+    #[unstable(feature = "ptr_offset_from_addr", issue = "none")]
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub fn sub_addr(self, origin: *const T) -> usize
+    where
+        T: Sized,
+    {
+        let pointee_size = mem::size_of::<T>();
+        assert!(0 < pointee_size && pointee_size <= isize::MAX as usize);
+        let bytes = self.addr().checked_sub(origin.addr()).expect("`self` is below `origin`");
+        bytes / pointee_size
+    }
+
     /// Returns whether two pointers are guaranteed to be equal.
     ///
     /// At runtime this function behaves like `Some(self == other)`.
@@ -1279,6 +1364,10 @@ pub unsafe fn read_volatile(self) -> T
     /// beyond the allocation that the pointer points into. It is up to the caller to ensure that
     /// the returned offset is correct in all terms other than alignment.
     ///
+    /// On CHERI targets the offset is computed from the address of the capability alone, so the
+    /// pointer's provenance is never lost along the way. Applying the offset with `wrapping_add`
+    /// keeps the capability valid as long as the result stays within its bounds.
+    ///
     /// # Panics
     ///
     /// The function panics if `align` is not a power-of-two.
@@ -29,6 +29,27 @@
 /// [dst]: https://doc.rust-lang.org/nomicon/exotic-sizes.html#dynamically-sized-types-dsts
 ///
 ///
+/// # Layout of wide pointers
+///
+/// A wide pointer is laid out like a `#[repr(C)]` struct holding the data pointer
+/// followed by the metadata, and so has the alignment of the more strictly aligned of
+/// the two. The offset of the metadata is available as [`metadata_offset`].
+///
+/// On most targets both halves are pointer-sized, so a wide pointer is twice the size of
+/// a `usize`. On CHERI purecap targets the data pointer is a capability, which is twice
+/// as wide as an address and carries a hidden validity tag:
+///
+/// * `*const [T]` and `*const str` are a 16-byte capability followed by the 8-byte
+///   length and 8 bytes of padding, for a total of 32 bytes aligned to 16.
+/// * `*const dyn Trait` is two capabilities, the data pointer and the vtable pointer,
+///   also 32 bytes aligned to 16.
+///
+/// The padding is not part of the value and its contents are unspecified. Code that
+/// rebuilds a wide pointer from raw memory, for example across an FFI boundary, must
+/// copy the data pointer as a whole capability rather than as an integer, otherwise its
+/// tag is lost. Putting the pointer together with [`from_raw_parts`] preserves it.
+///
+///
 /// # The `Pointee` trait
 ///
 /// The point of this trait is its `Metadata` associated type,
@@ -69,7 +90,7 @@ pub trait Pointee {
 /// #![feature(ptr_metadata)]
 ///
 /// fn this_never_panics<T: std::ptr::Thin>() {
-///     assert_eq!(std::mem::size_of::<&T>(), std::mem::size_of::<usize>())
+///     assert_eq!(std::mem::size_of::<&T>(), std::mem::size_of::<*const ()>())
 /// }
 /// ```
 #[unstable(feature = "ptr_metadata", issue = "81513")]
@@ -97,12 +118,58 @@ pub const fn metadata<T: ?Sized>(ptr: *const T) -> <T as Pointee>::Metadata {
     unsafe { PtrRepr { const_ptr: ptr }.components.metadata }
 }
 
+/// Returns the offset in bytes of the metadata within a (possibly-wide) pointer to `T`.
+///
+/// The data pointer always comes first, at offset zero. For thin pointers the metadata
+/// is zero-sized and this is the size of the data pointer.
+///
+/// On CHERI purecap targets the data pointer is a capability, which is twice as large as a
+/// `usize`. The metadata then starts right after it, and is followed by padding up to the
+/// alignment of the capability:
+///
+/// | Pointer to     | Data capability | Metadata                          | Size |
+/// |----------------|-----------------|-----------------------------------|------|
+/// | `[T]` or `str` | bytes 0..16     | `usize` length in bytes 16..24    | 32   |
+/// | `dyn Trait`    | bytes 0..16     | vtable capability in bytes 16..32 | 32   |
+///
+/// This is the layout of a C++ struct of a pointer followed by a `size_t` or pointer member on
+/// CheriBSD, so such structs can be passed to and from C++ as `&[T]` and `&dyn Trait`. The vtable
+/// itself has no layout that C++ code can rely on. Build with `-Z print-type-sizes` to see the
+/// layout of the wide pointers used by a crate.
+///
+/// # Example
+///
+/// ```
+/// #![feature(ptr_metadata, ptr_metadata_offset)]
+///
+/// use std::mem::size_of;
+/// use std::ptr;
+///
+/// let offset = ptr::metadata_offset::<[u8]>();
+/// assert!(offset >= size_of::<*const u8>());
+/// assert!(offset + size_of::<usize>() <= size_of::<*const [u8]>());
+/// ```
+#[unstable(feature = "ptr_metadata_offset", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_metadata_offset", issue = "none")]
+#[inline]
+pub const fn metadata_offset<T: ?Sized>() -> usize {
+    // `PtrComponents` is `#[repr(C)]`, so the metadata follows the data pointer at the
+    // next multiple of its own alignment. The size of the data pointer is always a
+    // multiple of its alignment, so this is the only padding that can come before it.
+    let data_size = crate::mem::size_of::<*const ()>();
+    let align = crate::mem::align_of::<<T as Pointee>::Metadata>();
+    (data_size + align - 1) & !(align - 1)
+}
+
 /// Forms a (possibly-wide) raw pointer from a data address and metadata.
 ///
 /// This function is safe but the returned pointer is not necessarily safe to dereference.
 /// For slices, see the documentation of [`slice::from_raw_parts`] for safety requirements.
 /// For trait objects, the metadata must come from a pointer to the same underlying erased type.
 ///
+/// On CHERI targets `data_address` is a full capability and is kept as-is, so the bounds and
+/// permissions of the returned pointer are exactly those of `data_address`.
+///
 /// [`slice::from_raw_parts`]: crate::slice::from_raw_parts
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
@@ -396,6 +396,8 @@
 mod metadata;
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 pub use metadata::{from_raw_parts, from_raw_parts_mut, metadata, DynMetadata, Pointee, Thin};
+#[unstable(feature = "ptr_metadata_offset", issue = "none")]
+pub use metadata::metadata_offset;
 
 mod non_null;
 #[stable(feature = "nonnull", since = "1.25.0")]
@@ -516,6 +518,29 @@ pub const fn null<T: ?Sized + Thin>() -> *const T {
     from_raw_parts(invalid(0), ())
 }
 
+cfg_if! {
+    // These are the CHERI purecap targets, where pointers are capabilities twice the width of
+    // their address.
+    if #[cfg(any(
+        all(target_pointer_width = "64", target_pointer_type_width = "128"),
+        all(target_pointer_width = "32", target_pointer_type_width = "64"),
+    ))] {
+        /// Returns the in-memory representation of a NULL-derived capability with the given
+        /// address: the address in the low half and every metadata bit clear. A capability built
+        /// from plain data is never tagged, so this is well-formed but cannot be dereferenced,
+        /// unlike an integer-to-pointer cast of a value that no capability backs.
+        #[inline(always)]
+        const fn invalid_ptr_bits(addr: usize) -> [usize; 2] {
+            [addr, 0]
+        }
+    } else {
+        #[inline(always)]
+        const fn invalid_ptr_bits(addr: usize) -> usize {
+            addr
+        }
+    }
+}
+
 /// Creates an invalid pointer with the given address.
 ///
 /// This is different from `addr as *const T`, which creates a pointer that picks up a previously
@@ -544,7 +569,7 @@ pub const fn invalid<T>(addr: usize) -> *const T {
     // is *not* the same as from_exposed_addr.
     // SAFETY: every valid integer is also a valid pointer (as long as you don't dereference that
     // pointer).
-    unsafe { mem::transmute(addr) }
+    unsafe { mem::transmute(invalid_ptr_bits(addr)) }
 }
 
 /// Creates an invalid mutable pointer with the given address.
@@ -575,7 +600,81 @@ pub const fn invalid_mut<T>(addr: usize) -> *mut T {
     // is *not* the same as from_exposed_addr.
     // SAFETY: every valid integer is also a valid pointer (as long as you don't dereference that
     // pointer).
-    unsafe { mem::transmute(addr) }
+    unsafe { mem::transmute(invalid_ptr_bits(addr)) }
+}
+
+/// Creates a pointer with the given address and no provenance.
+///
+/// This is the same operation as [`invalid`], under a name that says what the result is: a
+/// pointer that remembers an address and nothing else. It is meant for sentinels, such as the
+/// placeholder a collection stores before it allocates, and for addresses that are only ever
+/// compared or printed.
+///
+/// The result must never be dereferenced or used to access memory. On CHERI targets it is an
+/// untagged, NULL-derived capability, so any access through it traps, and unlike `addr as *const
+/// T` it does not trip the lints that reject integer-to-pointer casts there.
+///
+/// This API and its claimed semantics are part of the Strict Provenance experiment,
+/// see the [module documentation][crate::ptr] for details.
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "ptr_without_provenance", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_without_provenance", issue = "none")]
+pub const fn without_provenance<T>(addr: usize) -> *const T {
+    invalid(addr)
+}
+
+/// Creates a mutable pointer with the given address and no provenance.
+///
+/// This is the same operation as [`invalid_mut`], under a name that says what the result is: a
+/// pointer that remembers an address and nothing else. It is meant for sentinels, such as the
+/// placeholder a collection stores before it allocates, and for addresses that are only ever
+/// compared or printed.
+///
+/// The result must never be dereferenced or used to access memory. On CHERI targets it is an
+/// untagged, NULL-derived capability, so any access through it traps, and unlike `addr as *mut
+/// T` it does not trip the lints that reject integer-to-pointer casts there.
+///
+/// This API and its claimed semantics are part of the Strict Provenance experiment,
+/// see the [module documentation][crate::ptr] for details.
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "ptr_without_provenance", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_without_provenance", issue = "none")]
+pub const fn without_provenance_mut<T>(addr: usize) -> *mut T {
+    invalid_mut(addr)
+}
+
+/// Returns the address of `ptr`, deliberately discarding its provenance.
+///
+/// This is the same as [`ptr.addr()`][pointer::addr], under a name that states the intent: the
+/// address is going to be kept as plain data, for example as a hash key or an identifier, and is
+/// never going to be turned back into a pointer. On CHERI targets the result carries no tag and
+/// no bounds, so even an integer-to-pointer cast of it could never be dereferenced; code that
+/// needs a pointer back later should keep the pointer instead.
+///
+/// Unlike `ptr as usize`, this does not expose the provenance of `ptr`, and as it is not a cast,
+/// the lints about lossy provenance casts don't fire on it.
+///
+/// # Example
+///
+/// ```
+/// #![feature(ptr_as_untagged_address)]
+/// use std::collections::HashSet;
+/// use std::ptr;
+///
+/// let values = [1, 2, 3];
+/// let mut seen = HashSet::new();
+/// for v in &values {
+///     seen.insert(ptr::as_untagged_address(v));
+/// }
+/// assert_eq!(seen.len(), 3);
+/// ```
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "ptr_as_untagged_address", issue = "none")]
+pub fn as_untagged_address<T: ?Sized>(ptr: *const T) -> usize {
+    ptr.cast::<()>().addr()
 }
 
 /// Convert an address back to a pointer, picking up a previously 'exposed' provenance.
@@ -610,12 +709,20 @@ pub const fn invalid_mut<T>(addr: usize) -> *mut T {
 /// since it is generally not possible to actually *compute* which provenance the returned
 /// pointer has to pick up.
 ///
+/// On CHERI targets the behavior depends on the capability ABI. With the hybrid ABI, pointers are
+/// plain addresses that the hardware checks against the default data capability (DDC), so the
+/// returned pointer derives its authority from DDC. With the purecap ABI there is no ambient
+/// capability to derive the pointer from: the result has its tag cleared and any access through
+/// it traps. Calls to this function are therefore rejected by the `purecap_exposed_provenance`
+/// lint on purecap targets.
+///
 /// This API and its claimed semantics are part of the Strict Provenance experiment, see the
 /// [module documentation][crate::ptr] for details.
 #[must_use]
 #[inline]
 #[unstable(feature = "strict_provenance", issue = "95228")]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+#[rustc_diagnostic_item = "ptr_from_exposed_addr"]
 pub fn from_exposed_addr<T>(addr: usize) -> *const T
 where
     T: Sized,
@@ -647,12 +754,20 @@ pub fn from_exposed_addr<T>(addr: usize) -> *const T
 /// since it is generally not possible to actually *compute* which provenance the returned
 /// pointer has to pick up.
 ///
+/// On CHERI targets the behavior depends on the capability ABI. With the hybrid ABI, pointers are
+/// plain addresses that the hardware checks against the default data capability (DDC), so the
+/// returned pointer derives its authority from DDC. With the purecap ABI there is no ambient
+/// capability to derive the pointer from: the result has its tag cleared and any access through
+/// it traps. Calls to this function are therefore rejected by the `purecap_exposed_provenance`
+/// lint on purecap targets.
+///
 /// This API and its claimed semantics are part of the Strict Provenance experiment, see the
 /// [module documentation][crate::ptr] for details.
 #[must_use]
 #[inline]
 #[unstable(feature = "strict_provenance", issue = "95228")]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+#[rustc_diagnostic_item = "ptr_from_exposed_addr_mut"]
 pub fn from_exposed_addr_mut<T>(addr: usize) -> *mut T
 where
     T: Sized,
@@ -913,6 +1028,17 @@ macro_rules! attempt_swap_as_chunks {
     // copying those over multiple reads is difficult to support.
     #[cfg(not(miri))]
     {
+        // On CHERI targets, where pointers are wider than `usize`, anything aligned like a
+        // pointer may contain capabilities. Swap those in pointer-sized pieces, so that every
+        // capability moves through a capability register with its tag; less aligned values
+        // can't hold capabilities and are split up below.
+        if mem::size_of::<*const ()>() > mem::size_of::<usize>()
+            && mem::align_of::<T>() >= mem::align_of::<*const ()>()
+            && mem::size_of::<T>() > mem::size_of::<*const ()>()
+        {
+            attempt_swap_as_chunks!(*const ());
+        }
+
         // Split up the slice into small power-of-two-sized chunks that LLVM is able
         // to vectorize (unless it's a special type with more-than-pointer alignment,
         // because we don't want to pessimize things like slices of SIMD vectors.)
@@ -1449,6 +1575,11 @@ macro_rules! attempt_swap_as_chunks {
 /// (e.g., if a zero-sized type is passed to `read_volatile`) are noops
 /// and may be ignored.
 ///
+/// On CHERI purecap targets every pointer contained in `T` is accessed with a
+/// capability-width instruction, so its tag is preserved. This holds as long as
+/// the pointer is capability-aligned, which is always the case for `*const U`,
+/// `*mut U` and references unless `T` is `#[repr(packed)]`.
+///
 /// [c11]: http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 ///
 /// # Safety
@@ -1530,6 +1661,11 @@ pub unsafe fn read_volatile<T>(src: *const T) -> T {
 /// (e.g., if a zero-sized type is passed to `write_volatile`) are noops
 /// and may be ignored.
 ///
+/// On CHERI purecap targets every pointer contained in `T` is accessed with a
+/// capability-width instruction, so its tag is preserved. This holds as long as
+/// the pointer is capability-aligned, which is always the case for `*const U`,
+/// `*mut U` and references unless `T` is `#[repr(packed)]`.
+///
 /// [c11]: http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 ///
 /// # Safety
@@ -1650,6 +1786,8 @@ unsafe fn mod_inv(x: usize, m: usize) -> usize {
         }
     }
 
+    // Everything below works on the address alone, and no pointer is ever rebuilt from it. On
+    // CHERI targets this reads the capability's address without touching its tag or bounds.
     let addr = p.addr();
     let stride = mem::size_of::<T>();
     // SAFETY: `a` is a power-of-two, therefore non-zero.
@@ -1798,6 +1936,83 @@ pub fn eq<T: ?Sized>(a: *const T, b: *const T) -> bool {
     a == b
 }
 
+/// Compares raw pointers for exact equality.
+///
+/// Unlike [`eq`], which only compares addresses (and metadata, for fat pointers), this also
+/// compares everything else a pointer carries on CHERI targets: its tag, bounds, permissions and
+/// object type. Two pointers to the same location that were narrowed differently, or one of
+/// which has lost its tag, are therefore not exactly equal. On other targets this is the same as
+/// [`eq`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_eq_exact)]
+/// use std::ptr;
+///
+/// let five = 5;
+/// let a = &five as *const i32;
+/// assert!(ptr::eq_exact(a, a));
+/// ```
+#[unstable(feature = "ptr_eq_exact", issue = "none")]
+#[inline]
+pub fn eq_exact<T: ?Sized>(a: *const T, b: *const T) -> bool {
+    #[cfg(bootstrap)]
+    return a == b;
+    #[cfg(not(bootstrap))]
+    return intrinsics::ptr_eq_exact(a.cast::<u8>(), b.cast::<u8>()) && metadata(a) == metadata(b);
+}
+
+/// Compares the addresses of two function pointers.
+///
+/// This is the same as comparing `f as usize` with `g as usize`, but also works for function
+/// pointers of different types. On CHERI purecap targets function pointers are sealed entry
+/// capabilities (sentries): they can be called and compared, but not offset or dereferenced.
+/// Sentries for the same function may differ in their bounds, so only their addresses are
+/// compared. Use [`eq_exact`] on the result of [`FnPtr::addr`] to also compare everything else
+/// a capability carries.
+///
+/// Note that the same function may end up at different addresses in different codegen units,
+/// and different functions may be merged into one, so the result is not always what one would
+/// expect from the source.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_fn_addr_eq)]
+/// use std::ptr;
+///
+/// fn a() {}
+/// fn b(x: u8) -> u8 {
+///     x
+/// }
+///
+/// let f: fn() = a;
+/// assert!(ptr::fn_addr_eq(f, a as fn()));
+/// assert!(!ptr::fn_addr_eq(f, b as fn(u8) -> u8));
+/// ```
+#[unstable(feature = "ptr_fn_addr_eq", issue = "none")]
+#[inline]
+pub fn fn_addr_eq<T: FnPtr, U: FnPtr>(f: T, g: U) -> bool {
+    f.addr().addr() == g.addr().addr()
+}
+
+/// A function pointer type, as accepted by [`fn_addr_eq`].
+///
+/// This trait is sealed, and implemented for all function pointers with up to twelve arguments.
+#[unstable(feature = "ptr_fn_addr_eq", issue = "none")]
+pub trait FnPtr: Copy + fn_ptr_sealed::Sealed {
+    /// Returns the function pointer as a raw pointer to its code.
+    ///
+    /// On CHERI purecap targets the result is still a sealed entry capability, so it can't be
+    /// used to read the code or to reach any other address.
+    fn addr(self) -> *const ();
+}
+
+mod fn_ptr_sealed {
+    pub trait Sealed {}
+}
+
 /// Hash a raw pointer.
 ///
 /// This can be used to hash a `&T` reference (which coerces to `*const T` implicitly)
@@ -1883,6 +2098,19 @@ fn eq(&self, other: &Self) -> bool {
             impl<Ret, $($Arg),*> Eq for $FnTy {}
         }
 
+        impl<Ret, $($Arg),*> fn_ptr_sealed::Sealed for $FnTy {}
+
+        maybe_fnptr_doc! {
+            $($Arg)* @
+            #[unstable(feature = "ptr_fn_addr_eq", issue = "none")]
+            impl<Ret, $($Arg),*> FnPtr for $FnTy {
+                #[inline]
+                fn addr(self) -> *const () {
+                    self as *const ()
+                }
+            }
+        }
+
         maybe_fnptr_doc! {
             $($Arg)* @
             #[$meta]
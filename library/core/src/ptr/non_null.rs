@@ -75,6 +75,11 @@ impl<T: Sized> NonNull<T> {
     /// sentinel value. Types that lazily allocate must track initialization by
     /// some other means.
     ///
+    /// On CHERI purecap targets the returned pointer is an untagged, NULL-derived
+    /// capability whose address is the alignment of `T`. It is well-formed and
+    /// can be compared, offset by zero and passed around like any other pointer,
+    /// but any access through it traps.
+    ///
     /// # Examples
     ///
     /// ```
@@ -98,6 +103,33 @@ pub const fn dangling() -> Self {
         }
     }
 
+    /// Creates a `NonNull` with the given address and no provenance.
+    ///
+    /// Like [`ptr::without_provenance_mut`], the result is only good as a
+    /// sentinel or for comparisons, and must never be used to access memory.
+    ///
+    /// [`ptr::without_provenance_mut`]: crate::ptr::without_provenance_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_without_provenance)]
+    /// use std::num::NonZeroUsize;
+    /// use std::ptr::NonNull;
+    ///
+    /// let addr = NonZeroUsize::new(usize::MAX).unwrap();
+    /// let sentinel = NonNull::<u32>::without_provenance(addr);
+    /// assert_eq!(sentinel.as_ptr(), std::ptr::without_provenance_mut(usize::MAX));
+    /// ```
+    #[unstable(feature = "ptr_without_provenance", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_without_provenance", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn without_provenance(addr: NonZeroUsize) -> Self {
+        // SAFETY: `addr` is non-zero, so the pointer is not null.
+        unsafe { NonNull::new_unchecked(crate::ptr::without_provenance_mut(addr.get())) }
+    }
+
     /// Returns a shared references to the value. In contrast to [`as_ref`], this does not require
     /// that the value has to be initialized.
     ///
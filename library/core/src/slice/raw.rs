@@ -3,6 +3,7 @@
 use crate::array;
 use crate::intrinsics::{
     assert_unsafe_precondition, is_aligned_and_not_null, is_valid_allocation_size,
+    is_within_capability_bounds,
 };
 use crate::ops::Range;
 use crate::ptr;
@@ -35,6 +36,10 @@
 /// * The total size `len * mem::size_of::<T>()` of the slice must be no larger than `isize::MAX`.
 ///   See the safety documentation of [`pointer::offset`].
 ///
+/// On CHERI targets `data` must additionally be a tagged capability whose bounds
+/// cover the entire slice. With debug assertions enabled this is checked up front,
+/// so a bad pointer panics here instead of faulting on some later access.
+///
 /// # Caveat
 ///
 /// The lifetime for the returned slice is inferred from its usage. To
@@ -97,6 +102,10 @@
             [T](data: *const T, len: usize) => is_aligned_and_not_null(data)
                 && is_valid_allocation_size::<T>(len)
         );
+        assert_unsafe_precondition!(
+            "slice::from_raw_parts requires a tagged capability whose bounds cover the slice",
+            [T](data: *const T, len: usize) => is_within_capability_bounds(data, len)
+        );
         &*ptr::slice_from_raw_parts(data, len)
     }
 }
@@ -128,6 +137,9 @@
 /// * The total size `len * mem::size_of::<T>()` of the slice must be no larger than `isize::MAX`.
 ///   See the safety documentation of [`pointer::offset`].
 ///
+/// On CHERI targets `data` must additionally be a tagged capability whose bounds
+/// cover the entire slice. This is checked when debug assertions are enabled.
+///
 /// [valid]: ptr#safety
 /// [`NonNull::dangling()`]: ptr::NonNull::dangling
 #[inline]
@@ -142,6 +154,10 @@
             [T](data: *mut T, len: usize) => is_aligned_and_not_null(data)
                 && is_valid_allocation_size::<T>(len)
         );
+        assert_unsafe_precondition!(
+            "slice::from_raw_parts_mut requires a tagged capability whose bounds cover the slice",
+            [T](data: *mut T, len: usize) => is_within_capability_bounds(data, len)
+        );
         &mut *ptr::slice_from_raw_parts_mut(data, len)
     }
 }
@@ -167,12 +167,26 @@ unsafe impl Sync for AtomicBool {}
 ///
 /// **Note**: This type is only available on platforms that support atomic
 /// loads and stores of pointers. Its size depends on the target pointer's size.
+///
+/// On CHERI purecap targets the pointer is a capability, and every operation
+/// on this type reads and writes the whole capability, tag included. The
+/// `fetch_*` operations only change the address, so the result keeps the
+/// provenance of the stored pointer.
 #[cfg(target_has_atomic_load_store = "ptr")]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[cfg_attr(not(test), rustc_diagnostic_item = "AtomicPtr")]
 #[cfg_attr(target_pointer_width = "16", repr(C, align(2)))]
-#[cfg_attr(target_pointer_width = "32", repr(C, align(4)))]
-#[cfg_attr(bootstrap, cfg_attr(target_pointer_width = "64", repr(C, align(8))))]
+#[cfg_attr(
+    all(target_pointer_width = "32", not(target_pointer_type_width = "64")),
+    repr(C, align(4))
+)]
+#[cfg_attr(
+    all(target_pointer_width = "64", not(target_pointer_type_width = "128")),
+    repr(C, align(8))
+)]
+// CHERI purecap targets, where pointers are capabilities twice the width of their address.
+#[cfg_attr(all(target_pointer_width = "32", target_pointer_type_width = "64"), repr(C, align(8)))]
+#[cfg_attr(all(target_pointer_width = "64", target_pointer_type_width = "128"), repr(C, align(16)))]
 pub struct AtomicPtr<T> {
     p: UnsafeCell<*mut T>,
 }
@@ -1324,6 +1338,11 @@ pub fn compare_and_swap(&self, current: *mut T, new: *mut T, order: Ordering) ->
     /// of this operation [`Relaxed`], and using [`Release`] makes the successful load
     /// [`Relaxed`]. The failure ordering can only be [`SeqCst`], [`Acquire`] or [`Relaxed`].
     ///
+    /// On CHERI purecap targets the comparison is exact: the stored capability must match
+    /// `current` in every bit, including its tag, bounds and permissions, as with
+    /// [`ptr::eq_exact`](crate::ptr::eq_exact). Use [`AtomicPtr::compare_exchange_addr`] to
+    /// compare addresses only.
+    ///
     /// **Note:** This method is only available on platforms that support atomic
     /// operations on pointers.
     ///
@@ -1370,6 +1389,11 @@ pub fn compare_exchange(
     /// of this operation [`Relaxed`], and using [`Release`] makes the successful load
     /// [`Relaxed`]. The failure ordering can only be [`SeqCst`], [`Acquire`] or [`Relaxed`].
     ///
+    /// On CHERI purecap targets the comparison is exact: the stored capability must match
+    /// `current` in every bit, including its tag, bounds and permissions, as with
+    /// [`ptr::eq_exact`](crate::ptr::eq_exact). Use [`AtomicPtr::compare_exchange_addr`] to
+    /// compare addresses only.
+    ///
     /// **Note:** This method is only available on platforms that support atomic
     /// operations on pointers.
     ///
@@ -1407,6 +1431,71 @@ pub fn compare_exchange_weak(
         unsafe { atomic_compare_exchange_weak(self.p.get(), current, new, success, failure) }
     }
 
+    /// Stores a value into the pointer if the address of the current value is the same as the
+    /// address of `current`.
+    ///
+    /// This is [`AtomicPtr::compare_exchange`], except that only addresses are compared, the
+    /// same way `==` compares pointers. On most platforms the two are identical. On CHERI
+    /// purecap targets `compare_exchange` compares whole capabilities, while this method
+    /// succeeds whenever the stored capability has the same address as `current`, regardless
+    /// of its tag, bounds and permissions. The return value always carries the capability that
+    /// was actually stored.
+    ///
+    /// The orderings have the same meaning as for [`AtomicPtr::compare_exchange`].
+    ///
+    /// **Note:** This method is only available on platforms that support atomic
+    /// operations on pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_ptr_compare_exchange_addr)]
+    /// use std::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let ptr = &mut 5;
+    /// let some_ptr = AtomicPtr::new(ptr);
+    ///
+    /// let other_ptr = &mut 10;
+    ///
+    /// let value = some_ptr.compare_exchange_addr(ptr, other_ptr,
+    ///                                            Ordering::SeqCst, Ordering::Relaxed);
+    /// assert_eq!(value, Ok(ptr as *mut _));
+    /// ```
+    #[inline]
+    #[unstable(feature = "atomic_ptr_compare_exchange_addr", issue = "none")]
+    #[cfg(target_has_atomic = "ptr")]
+    #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    pub fn compare_exchange_addr(
+        &self,
+        current: *mut T,
+        new: *mut T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<*mut T, *mut T> {
+        cfg_if! {
+            // These are the CHERI purecap targets, where pointers are capabilities twice the
+            // width of their address.
+            if #[cfg(any(
+                all(target_pointer_width = "64", target_pointer_type_width = "128"),
+                all(target_pointer_width = "32", target_pointer_type_width = "64"),
+            ))] {
+                // The hardware exchange compares whole capabilities, so retry with whatever is
+                // stored for as long as its address still matches.
+                loop {
+                    let stored = self.load(failure);
+                    if stored.addr() != current.addr() {
+                        return Err(stored);
+                    }
+                    if let Ok(prev) = self.compare_exchange_weak(stored, new, success, failure) {
+                        return Ok(prev);
+                    }
+                }
+            } else {
+                self.compare_exchange(current, new, success, failure)
+            }
+        }
+    }
+
     /// Fetches the value, and applies a function to it that returns an optional
     /// new value. Returns a `Result` of `Ok(previous_value)` if the function
     /// returned `Some(_)`, else `Err(previous_value)`.
@@ -1818,6 +1907,247 @@ fn from(p: *mut T) -> Self {
     }
 }
 
+/// An address that can be safely shared between threads.
+///
+/// This type has the same in-memory representation as [`AtomicUsize`], and is meant for code
+/// that keeps only the *address* of a pointer in an atomic, for example to pack a tag or a
+/// generation count next to it. The address of a pointer is stored with
+/// [`store_ptr`](AtomicAddr::store_ptr), and turned back into a pointer with
+/// [`load_ptr`](AtomicAddr::load_ptr), which takes the provenance of the result from another
+/// pointer rather than conjuring it from the integer.
+///
+/// On CHERI purecap targets a pointer is a capability, and only its address fits in a `usize`.
+/// Casting a pointer to an integer for an `AtomicUsize` loses its tag, bounds and
+/// permissions, so casting it back gives a pointer that faults on any access. `AtomicAddr`
+/// makes that loss explicit: the pointer returned by `load_ptr` has the capability of the
+/// pointer it was derived from, with the stored address. Use [`AtomicPtr`] instead to share the
+/// pointer itself.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(atomic_addr)]
+/// use std::sync::atomic::{AtomicAddr, Ordering};
+///
+/// let buf = [1u32, 2, 3, 4];
+/// let base = buf.as_ptr();
+///
+/// let cursor = AtomicAddr::from_ptr(base);
+/// cursor.fetch_add(2 * std::mem::size_of::<u32>(), Ordering::Relaxed);
+///
+/// // The address comes from `cursor`, the provenance from `base`.
+/// let p = cursor.load_ptr(base, Ordering::Relaxed);
+/// assert_eq!(unsafe { *p }, 3);
+/// ```
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "atomic_addr", issue = "none")]
+#[repr(transparent)]
+pub struct AtomicAddr {
+    addr: AtomicUsize,
+}
+
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "atomic_addr", issue = "none")]
+impl Default for AtomicAddr {
+    /// Creates an `AtomicAddr` holding the address `0`.
+    #[inline]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "atomic_addr", issue = "none")]
+impl From<usize> for AtomicAddr {
+    /// Converts an address into an `AtomicAddr`.
+    #[inline]
+    fn from(addr: usize) -> Self {
+        Self::new(addr)
+    }
+}
+
+#[cfg(target_has_atomic_load_store = "ptr")]
+impl AtomicAddr {
+    /// Creates a new `AtomicAddr` holding `addr`.
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub const fn new(addr: usize) -> Self {
+        Self { addr: AtomicUsize::new(addr) }
+    }
+
+    /// Creates a new `AtomicAddr` holding the address of `ptr`.
+    ///
+    /// Only the address is kept: the provenance of `ptr` is not exposed.
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn from_ptr<T>(ptr: *const T) -> Self {
+        Self::new(ptr.addr())
+    }
+
+    /// Returns a mutable reference to the address.
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut usize {
+        self.addr.get_mut()
+    }
+
+    /// Consumes the atomic and returns the address.
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn into_inner(self) -> usize {
+        self.addr.into_inner()
+    }
+
+    /// Loads the address.
+    ///
+    /// `load` takes an [`Ordering`] argument which describes the memory ordering of this
+    /// operation. Possible values are [`SeqCst`], [`Acquire`] and [`Relaxed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`] or [`AcqRel`].
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn load(&self, order: Ordering) -> usize {
+        self.addr.load(order)
+    }
+
+    /// Loads the address, and returns a pointer to it with the provenance of `base`.
+    ///
+    /// This is `base.with_addr(self.load(order))`. The result may only be used to access memory
+    /// that `base` could access, so on CHERI purecap targets it faults if the address is outside
+    /// the bounds of `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`] or [`AcqRel`].
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn load_ptr<T>(&self, base: *const T, order: Ordering) -> *const T {
+        base.with_addr(self.load(order))
+    }
+
+    /// Loads the address, and returns a mutable pointer to it with the provenance of `base`.
+    ///
+    /// See [`load_ptr`](AtomicAddr::load_ptr).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`] or [`AcqRel`].
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn load_ptr_mut<T>(&self, base: *mut T, order: Ordering) -> *mut T {
+        base.with_addr(self.load(order))
+    }
+
+    /// Stores an address.
+    ///
+    /// `store` takes an [`Ordering`] argument which describes the memory ordering of this
+    /// operation. Possible values are [`SeqCst`], [`Release`] and [`Relaxed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Acquire`] or [`AcqRel`].
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn store(&self, addr: usize, order: Ordering) {
+        self.addr.store(addr, order)
+    }
+
+    /// Stores the address of `ptr`, without exposing its provenance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Acquire`] or [`AcqRel`].
+    #[inline]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn store_ptr<T>(&self, ptr: *const T, order: Ordering) {
+        self.store(ptr.addr(), order)
+    }
+
+    /// Stores an address, returning the previous one.
+    ///
+    /// See [`AtomicUsize::swap`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn swap(&self, addr: usize, order: Ordering) -> usize {
+        self.addr.swap(addr, order)
+    }
+
+    /// Stores an address if the current one is the same as `current`.
+    ///
+    /// See [`AtomicUsize::compare_exchange`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn compare_exchange(
+        &self,
+        current: usize,
+        new: usize,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<usize, usize> {
+        self.addr.compare_exchange(current, new, success, failure)
+    }
+
+    /// Stores an address if the current one is the same as `current`, and may fail spuriously.
+    ///
+    /// See [`AtomicUsize::compare_exchange_weak`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn compare_exchange_weak(
+        &self,
+        current: usize,
+        new: usize,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<usize, usize> {
+        self.addr.compare_exchange_weak(current, new, success, failure)
+    }
+
+    /// Adds `val` to the address, returning the previous address.
+    ///
+    /// See [`AtomicUsize::fetch_add`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn fetch_add(&self, val: usize, order: Ordering) -> usize {
+        self.addr.fetch_add(val, order)
+    }
+
+    /// Subtracts `val` from the address, returning the previous address.
+    ///
+    /// See [`AtomicUsize::fetch_sub`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn fetch_sub(&self, val: usize, order: Ordering) -> usize {
+        self.addr.fetch_sub(val, order)
+    }
+
+    /// Bitwise "and" of the address with `val`, returning the previous address.
+    ///
+    /// See [`AtomicUsize::fetch_and`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn fetch_and(&self, val: usize, order: Ordering) -> usize {
+        self.addr.fetch_and(val, order)
+    }
+
+    /// Bitwise "or" of the address with `val`, returning the previous address.
+    ///
+    /// See [`AtomicUsize::fetch_or`].
+    #[inline]
+    #[cfg(target_has_atomic = "ptr")]
+    #[unstable(feature = "atomic_addr", issue = "none")]
+    pub fn fetch_or(&self, val: usize, order: Ordering) -> usize {
+        self.addr.fetch_or(val, order)
+    }
+}
+
 #[allow(unused_macros)] // This macro ends up being unused on some architectures.
 macro_rules! if_not_8_bit {
     (u8, $($tt:tt)*) => { "" };
@@ -3435,6 +3765,14 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "atomic_addr", issue = "none")]
+impl fmt::Debug for AtomicAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.load(Ordering::Relaxed))
+    }
+}
+
 #[cfg(target_has_atomic_load_store = "ptr")]
 #[stable(feature = "atomic_pointer", since = "1.24.0")]
 impl<T> fmt::Pointer for AtomicPtr<T> {
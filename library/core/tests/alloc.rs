@@ -73,3 +73,27 @@ fn layout_accepts_all_valid_alignments() {
         assert_eq!(layout.align(), 1_usize << align);
     }
 }
+
+#[test]
+fn layout_to_representable() {
+    for shift in 0..24 {
+        for size in [(1_usize << shift) - 1, 1 << shift, (1 << shift) + 1] {
+            let layout = Layout::from_size_align(size, 8).unwrap();
+            let rep = layout.to_representable().unwrap();
+            assert!(rep.size() >= layout.size());
+            assert!(rep.align() >= layout.align());
+            if cfg!(not(target_arch = "morello+c64")) {
+                assert_eq!(rep, layout);
+            }
+        }
+    }
+}
+
+#[test]
+fn layout_to_representable_const() {
+    const LAYOUT: Layout = match Layout::new::<[u8; 100_001]>().to_representable() {
+        Ok(layout) => layout,
+        Err(_) => panic!(),
+    };
+    assert_eq!(LAYOUT, Layout::new::<[u8; 100_001]>().to_representable().unwrap());
+}
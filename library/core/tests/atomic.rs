@@ -312,3 +312,19 @@ fn atomic_const_from() {
     const _ATOMIC_BOOL: AtomicBool = AtomicBool::from(true);
     const _ATOMIC_PTR: AtomicPtr<u32> = AtomicPtr::from(core::ptr::null_mut());
 }
+
+#[test]
+fn atomic_addr() {
+    let mut buf = [1u32, 2, 3, 4];
+    let base = buf.as_mut_ptr();
+    let cursor = AtomicAddr::from_ptr(base);
+
+    cursor.fetch_add(core::mem::size_of::<u32>(), SeqCst);
+    let p = cursor.load_ptr_mut(base, SeqCst);
+    unsafe { *p = 20 };
+    assert_eq!(buf, [1, 20, 3, 4]);
+
+    cursor.store_ptr(&buf[3], SeqCst);
+    assert_eq!(unsafe { *cursor.load_ptr(buf.as_ptr(), SeqCst) }, 4);
+    assert_eq!(cursor.into_inner(), (&buf[3] as *const u32).addr());
+}
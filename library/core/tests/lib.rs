@@ -9,6 +9,7 @@
 #![feature(const_black_box)]
 #![feature(const_bool_to_option)]
 #![feature(const_caller_location)]
+#![feature(const_cheri_intrinsics)]
 #![feature(const_cell_into_inner)]
 #![feature(const_convert)]
 #![feature(const_heap)]
@@ -44,6 +45,7 @@
 #![feature(inline_const)]
 #![feature(is_sorted)]
 #![feature(layout_for_ptr)]
+#![feature(layout_to_representable)]
 #![feature(pattern)]
 #![feature(pin_macro)]
 #![feature(sort_internals)]
@@ -83,6 +85,10 @@
 #![feature(pointer_byte_offsets)]
 #![feature(portable_simd)]
 #![feature(ptr_metadata)]
+#![feature(ptr_metadata_offset)]
+#![feature(ptr_as_untagged_address)]
+#![feature(ptr_fn_addr_eq)]
+#![feature(ptr_without_provenance)]
 #![feature(once_cell)]
 #![feature(option_result_contains)]
 #![feature(unsized_tuple_coercion)]
@@ -95,6 +101,7 @@
 #![feature(split_array)]
 #![feature(strict_provenance)]
 #![feature(strict_provenance_atomic_ptr)]
+#![feature(atomic_addr)]
 #![feature(trusted_random_access)]
 #![feature(unsize)]
 #![feature(const_array_from_ref)]
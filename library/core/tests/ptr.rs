@@ -106,6 +106,46 @@ fn test_is_null() {
     assert!(em.is_null());
 }
 
+#[test]
+fn test_without_provenance() {
+    const EMPTY: *mut u64 = ptr::without_provenance_mut(usize::MAX);
+    const NONNULL: NonNull<u64> =
+        NonNull::without_provenance(unsafe { NonZeroUsize::new_unchecked(mem::align_of::<u64>()) });
+
+    assert!(!EMPTY.is_null());
+    assert_eq!(EMPTY.addr(), usize::MAX);
+    assert_eq!(ptr::without_provenance::<u64>(0), ptr::null());
+    assert_eq!(ptr::without_provenance_mut::<u64>(0), ptr::null_mut());
+    assert_eq!(NONNULL, NonNull::dangling());
+}
+
+#[test]
+fn test_as_untagged_address() {
+    let array = [0u16; 4];
+    let slice: &[u16] = &array;
+    assert_eq!(ptr::as_untagged_address(&array[2]), array.as_ptr().addr() + 4);
+    assert_eq!(ptr::as_untagged_address(slice), array.as_ptr().addr());
+    assert_eq!(ptr::as_untagged_address(ptr::null::<u8>()), 0);
+}
+
+#[test]
+fn test_fn_addr_eq() {
+    fn a() {}
+    fn b(x: u8) -> u8 {
+        x
+    }
+
+    let f: fn() = a;
+    let g: unsafe extern "C" fn() = {
+        extern "C" fn c() {}
+        c
+    };
+    assert!(ptr::fn_addr_eq(f, a as fn()));
+    assert!(!ptr::fn_addr_eq(f, b as fn(u8) -> u8));
+    assert!(ptr::fn_addr_eq(g, g));
+    assert_eq!(ptr::FnPtr::addr(f).addr(), f as usize);
+}
+
 #[test]
 fn test_as_ref() {
     unsafe {
@@ -557,6 +597,26 @@ fn static_assert_expected_bounds_for_metadata<Meta>()
     }
 }
 
+#[test]
+fn ptr_metadata_offset() {
+    let ptr_size = mem::size_of::<*const ()>();
+    assert_eq!(metadata_offset::<u8>(), ptr_size);
+    assert_eq!(metadata_offset::<[u64]>(), ptr_size);
+    assert_eq!(metadata_offset::<str>(), ptr_size);
+    assert_eq!(metadata_offset::<dyn Display>(), ptr_size);
+
+    // The metadata read back from where `metadata_offset` says it lives.
+    let slice: *const [u16] = &[1, 2, 3][..];
+    let len = unsafe {
+        *(&slice as *const _ as *const u8).add(metadata_offset::<[u16]>()).cast::<usize>()
+    };
+    assert_eq!(len, 3);
+
+    assert_eq!(mem::size_of::<*const [u8]>() % mem::align_of::<*const u8>(), 0);
+    assert!(metadata_offset::<[u8]>() + mem::size_of::<usize>() <= mem::size_of::<*const [u8]>());
+    assert_eq!(mem::align_of::<*const dyn Display>(), mem::align_of::<*const u8>());
+}
+
 #[test]
 fn dyn_metadata() {
     #[derive(Debug)]
@@ -57,6 +57,17 @@ fn main() {
         }
     }
 
+    // The runtime stores pointers to the counters and functions in its data records, so on
+    // purecap targets it has to be built as purecap code for those to be capabilities with
+    // valid tags. Use the same flags that the target spec passes when linking.
+    if env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("morello+c64") {
+        if target.contains("freebsd") {
+            cfg.flag("--target=aarch64-unknown-freebsd");
+        }
+        cfg.flag("-march=morello+c64");
+        cfg.flag("-mabi=purecap");
+    }
+
     // Assume that the Unixes we are building this for have fnctl() available
     if env::var_os("CARGO_CFG_UNIX").is_some() {
         cfg.define("COMPILER_RT_HAS_FCNTL_LCK", Some("1"));
@@ -218,6 +218,9 @@
 #![warn(missing_docs)]
 #![warn(missing_debug_implementations)]
 #![allow(explicit_outlives_requirements)]
+// Casts between pointers and integers here implement the APIs that need them, such as
+// `ptr::from_exposed_addr`, whose callers are linted instead.
+#![cfg_attr(not(bootstrap), allow(cheri_int_to_ptr_cast, cheri_ptr_to_int_cast))]
 #![allow(unused_lifetimes)]
 #![deny(rustc::existing_doc_keyword)]
 // Ensure that std can be linked against panic_abort despite compiled with `-C panic=unwind`
@@ -37,7 +37,7 @@
 //! and the last personality routine transfers control to the catch block.
 
 use super::dwarf::eh::{self, EHAction, EHContext};
-use libc::{c_int, uintptr_t};
+use libc::c_int;
 use unwind as uw;
 
 // Register ids were lifted from LLVM's TargetLowering::getExceptionPointerRegister()
@@ -55,9 +55,11 @@
 #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
 const UNWIND_DATA_REG: (i32, i32) = (0, 1); // R0, R1 / X0, X1
 
+// Morello purecap hands the exception object to the landing pad as a capability, so the
+// capability registers have to be set rather than their 64-bit integer views.
 #[cfg(not(bootstrap))]
 #[cfg(target_arch = "morello+c64")]
-const UNWIND_DATA_REG: (i32, i32) = (0, 1); // R0, R1 / X0, X1
+const UNWIND_DATA_REG: (i32, i32) = (198, 199); // C0, C1
 
 #[cfg(target_arch = "m68k")]
 const UNWIND_DATA_REG: (i32, i32) = (0, 1); // D0, D1
@@ -78,8 +80,16 @@
 const UNWIND_DATA_REG: (i32, i32) = (0, 1); // R0, R1
 
 #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+#[cfg(not(target_pointer_type_width = "64"))]
+#[cfg(not(target_pointer_type_width = "128"))]
 const UNWIND_DATA_REG: (i32, i32) = (10, 11); // x10, x11
 
+// CHERI-RISC-V purecap hands the exception object over in the capability registers, which
+// share their DWARF numbers with the integer registers they extend.
+#[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+#[cfg(any(target_pointer_type_width = "64", target_pointer_type_width = "128"))]
+const UNWIND_DATA_REG: (i32, i32) = (10, 11); // ca0, ca1
+
 // The following code is based on GCC's C and C++ personality routines.  For reference, see:
 // https://github.com/gcc-mirror/gcc/blob/master/libstdc++-v3/libsupc++/eh_personality.cc
 // https://github.com/gcc-mirror/gcc/blob/trunk/libgcc/unwind-c.c
@@ -151,7 +161,7 @@
                         uw::_Unwind_SetGR(
                             context,
                             UNWIND_DATA_REG.0,
-                            exception_object as uintptr_t,
+                            exception_object as libc::uintptr_t,
                         );
                         uw::_Unwind_SetGR(context, UNWIND_DATA_REG.1, 0);
                         uw::_Unwind_SetIP(context, lpad);
@@ -208,12 +218,14 @@ fn __gnu_unwind_frame(
                 match eh_action {
                     EHAction::None => uw::_URC_CONTINUE_UNWIND,
                     EHAction::Cleanup(lpad) | EHAction::Catch(lpad) => {
+                        // On CHERI purecap targets these are capability registers, so the
+                        // exception object is passed as a pointer to keep its tag.
                         uw::_Unwind_SetGR(
                             context,
                             UNWIND_DATA_REG.0,
-                            exception_object as uintptr_t,
+                            exception_object as uw::_Unwind_Reg,
                         );
-                        uw::_Unwind_SetGR(context, UNWIND_DATA_REG.1, 0 as uintptr_t);
+                        uw::_Unwind_SetGR(context, UNWIND_DATA_REG.1, 0 as uw::_Unwind_Reg);
                         uw::_Unwind_SetIP(context, lpad);
                         uw::_URC_INSTALL_CONTEXT
                     }
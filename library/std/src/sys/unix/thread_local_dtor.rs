@@ -10,12 +10,14 @@
 //
 // Note, however, that we run on lots older linuxes, as well as cross
 // compiling from a newer linux to an older linux, so we also have a
-// fallback implementation to use as well.
+// fallback implementation to use as well. FreeBSD's libc has shipped the
+// same symbol since FreeBSD 11.
 #[cfg(any(
     target_os = "linux",
     target_os = "fuchsia",
     target_os = "redox",
-    target_os = "emscripten"
+    target_os = "emscripten",
+    target_os = "freebsd"
 ))]
 #[cfg_attr(target_family = "wasm", allow(unused))] // might remain unused depending on target details (e.g. wasm32-unknown-emscripten)
 pub unsafe fn register_dtor(t: *mut u8, dtor: unsafe extern "C" fn(*mut u8)) {
@@ -122,13 +122,18 @@ pub enum _Unwind_Action {
     }
     pub use _Unwind_Action::*;
 
+    /// The value of a register as `_Unwind_GetGR` and `_Unwind_SetGR` see it. These take C's
+    /// `uintptr_t`, which is a capability on CHERI purecap targets, so that the capability
+    /// registers can be read and set with their tags.
+    pub type _Unwind_Reg = core::ffi::c_uintptr_t;
+
     #[cfg_attr(
         all(feature = "llvm-libunwind", any(target_os = "fuchsia", target_os = "linux")),
         link(name = "unwind", kind = "static", modifiers = "-bundle")
     )]
     extern "C" {
-        pub fn _Unwind_GetGR(ctx: *mut _Unwind_Context, reg_index: c_int) -> _Unwind_Word;
-        pub fn _Unwind_SetGR(ctx: *mut _Unwind_Context, reg_index: c_int, value: _Unwind_Word);
+        pub fn _Unwind_GetGR(ctx: *mut _Unwind_Context, reg_index: c_int) -> _Unwind_Reg;
+        pub fn _Unwind_SetGR(ctx: *mut _Unwind_Context, reg_index: c_int, value: _Unwind_Reg);
         pub fn _Unwind_GetIP(ctx: *mut _Unwind_Context) -> _Unwind_Word;
         pub fn _Unwind_SetIP(ctx: *mut _Unwind_Context, value: _Unwind_Word);
         pub fn _Unwind_GetIPInfo(ctx: *mut _Unwind_Context, ip_before_insn: *mut c_int)
@@ -29,7 +29,6 @@
 use crate::{Build, CLang, DocTests, GitRepo, Mode};
 
 pub use crate::Compiler;
-use home::home_dir;
 // FIXME: replace with std::lazy after it gets stabilized and reaches beta
 use once_cell::sync::{Lazy, OnceCell};
 use xz2::bufread::XzDecoder;
@@ -1738,26 +1737,21 @@ pub fn cargo(
         if self.is_fuse_ld_lld(target) {
             rustflags.arg("-Clink-args=-fuse-ld=lld");
         }
-        if target == "morello-unknown-freebsd-purecap" {
-            rustflags.arg("-Clink-args=-march=morello+c64");
-            rustflags.arg("-Clink-args=-mabi=purecap");
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("cheri").join("output").join("rootfs-morello-purecap"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            rustflags.arg(&format!("-Clink-args=--sysroot={}", sysroot));
+        if target == "morello-unknown-freebsd-purecap" && env::var_os("CHERIBSD_SYSROOT").is_none()
+        {
+            // The target spec carries the `-march`/`-mabi` flags; rustc only needs to be told
+            // where the CheriBSD sysroot lives.
+            if let Some(sysroot) = self.cheri_sysroot(target) {
+                cargo.env("CHERIBSD_SYSROOT", sysroot);
+            }
         }
         if target == "morello-unknown-linux-purecap" {
             rustflags.arg("-Clink-args=-march=morello+c64");
             rustflags.arg("-Clink-args=-mabi=purecap");
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("morello").join("musl"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            rustflags.arg(&format!("-Clink-args=--sysroot={}", sysroot));
-            rustflags.arg(&format!("-Clink-self-contained=no"));
+            if let Some(sysroot) = self.cheri_sysroot(target) {
+                rustflags.arg(&format!("-Clink-args=--sysroot={}", sysroot.display()));
+            }
+            rustflags.arg("-Clink-self-contained=no");
         }
         self.lld_flags(target).for_each(|flag| {
             rustdocflags.arg(&flag);
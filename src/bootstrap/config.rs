@@ -431,6 +431,8 @@ pub struct Target {
     pub musl_libdir: Option<PathBuf>,
     pub wasi_root: Option<PathBuf>,
     pub qemu_rootfs: Option<PathBuf>,
+    pub qemu_firmware: Option<PathBuf>,
+    pub cheri_sysroot: Option<PathBuf>,
     pub no_std: bool,
 }
 
@@ -771,6 +773,8 @@ struct TomlTarget {
         musl_libdir: Option<String> = "musl-libdir",
         wasi_root: Option<String> = "wasi-root",
         qemu_rootfs: Option<String> = "qemu-rootfs",
+        qemu_firmware: Option<String> = "qemu-firmware",
+        cheri_sysroot: Option<String> = "cheri-sysroot",
         no_std: Option<bool> = "no-std",
     }
 }
@@ -1235,6 +1239,8 @@ pub fn parse(args: &[String]) -> Config {
                 target.musl_libdir = cfg.musl_libdir.map(PathBuf::from);
                 target.wasi_root = cfg.wasi_root.map(PathBuf::from);
                 target.qemu_rootfs = cfg.qemu_rootfs.map(PathBuf::from);
+                target.qemu_firmware = cfg.qemu_firmware.map(PathBuf::from);
+                target.cheri_sysroot = cfg.cheri_sysroot.map(PathBuf::from);
                 target.sanitizers = cfg.sanitizers;
                 target.profiler = cfg.profiler;
 
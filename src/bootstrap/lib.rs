@@ -1079,22 +1079,11 @@ fn cflags(&self, target: TargetSelection, which: GitRepo, c: CLang) -> Vec<Strin
         }
 
         println!("cflags requested for {:?}", &target.triple);
-        if &*target.triple == "morello-unknown-freebsd-purecap" {
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("cheri").join("output").join("rootfs-morello-purecap"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            base.push(format!("--sysroot={}", sysroot));
-        }
         if &*target.triple == "morello-unknown-linux-purecap" {
             base.push("--target=aarch64-unknown-linux-musl_purecap".into());
-            let sysroot = match home_dir() {
-                Some(path) => path.as_path().join("morello").join("musl"),
-                None => Path::new("").to_path_buf(),
-            };
-            let sysroot = sysroot.into_os_string().into_string().unwrap();
-            base.push(format!("--sysroot={}", sysroot));
+        }
+        if let Some(sysroot) = self.cheri_sysroot(target) {
+            base.push(format!("--sysroot={}", sysroot.display()));
         }
 
         // Work around an apparently bad MinGW / GCC optimization,
@@ -1212,6 +1201,26 @@ fn wasi_root(&self, target: TargetSelection) -> Option<&Path> {
         self.config.target_config.get(&target).and_then(|t| t.wasi_root.as_ref()).map(|p| &**p)
     }
 
+    /// Returns the C sysroot used to build and link hosted CHERI targets, if any.
+    ///
+    /// Defaults to the locations used by cheribuild and the Morello musl build
+    /// when `cheri-sysroot` is not set for the target.
+    fn cheri_sysroot(&self, target: TargetSelection) -> Option<PathBuf> {
+        if let Some(p) =
+            self.config.target_config.get(&target).and_then(|t| t.cheri_sysroot.as_ref())
+        {
+            return Some(p.clone());
+        }
+        let home = home_dir()?;
+        match &*target.triple {
+            "morello-unknown-freebsd-purecap" => {
+                Some(home.join("cheri").join("output").join("rootfs-morello-purecap"))
+            }
+            "morello-unknown-linux-purecap" => Some(home.join("morello").join("musl")),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if this is a no-std `target`, if defined
     fn no_std(&self, target: TargetSelection) -> Option<bool> {
         self.config.target_config.get(&target).map(|t| t.no_std)
@@ -1234,6 +1243,12 @@ fn qemu_rootfs(&self, target: TargetSelection) -> Option<&Path> {
         self.config.target_config.get(&target).and_then(|t| t.qemu_rootfs.as_ref()).map(|p| &**p)
     }
 
+    /// Returns the firmware that QEMU boots the rootfs of this target with, if one was
+    /// configured.
+    fn qemu_firmware(&self, target: TargetSelection) -> Option<&Path> {
+        self.config.target_config.get(&target).and_then(|t| t.qemu_firmware.as_ref()).map(|p| &**p)
+    }
+
     /// Path to the python interpreter to use
     fn python(&self) -> &Path {
         if self.config.build.ends_with("apple-darwin") {
@@ -16,8 +16,6 @@
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use home::home_dir;
-
 use crate::bolt::{instrument_with_bolt_inplace, optimize_library_with_bolt_inplace};
 use crate::builder::{Builder, RunConfig, ShouldRun, Step};
 use crate::channel;
@@ -1002,6 +1000,8 @@ fn run(self, builder: &Builder<'_>) {
             TargetSelection::from_user("x86_64-unknown-linux-gnu")
         } else if self.target == "riscv32imcxcheri-unknown-none-purecap" {
             TargetSelection::from_user("riscv32-unknown-elf")
+        } else if self.target == "riscv64imafdcxcheri-unknown-none-purecap" {
+            TargetSelection::from_user("riscv64-unknown-elf")
         } else if self.target == "morello-unknown-none-purecap" {
             TargetSelection::from_user("aarch64-unknown-elf")
         } else {
@@ -1029,23 +1029,9 @@ fn run(self, builder: &Builder<'_>) {
                 cfg.archiver(ar);
             }
             cfg.compiler(builder.cc(target));
-            if self.target == "morello-unknown-freebsd-purecap" {
-                let sysroot = match home_dir() {
-                    Some(path) => path.as_path().join("cheri").join("output").join("rootfs-morello-purecap"),
-                    None => Path::new("").to_path_buf(),
-                };
-                let sysroot = sysroot.into_os_string().into_string().unwrap();
-                cfg.flag(&format!("--sysroot={}", sysroot));
-            }
-            if self.target == "morello-unknown-linux-purecap" {
-                let sysroot = match home_dir() {
-                    Some(path) => path.as_path().join("morello").join("musl"),
-                    None => Path::new("").to_path_buf(),
-                };
-                let sysroot = sysroot.into_os_string().into_string().unwrap();
-                cfg.flag(&format!("--sysroot={}", sysroot));
+            if let Some(sysroot) = builder.cheri_sysroot(self.target) {
+                cfg.flag(&format!("--sysroot={}", sysroot.display()));
             }
-
         }
         cfg.cargo_metadata(false)
             .out_dir(&dst)
@@ -1604,6 +1604,14 @@ fn run(self, builder: &Builder<'_>) {
 
         if builder.remote_tested(target) {
             cmd.arg("--remote-test-client").arg(builder.tool_exe(Tool::RemoteTestClient));
+            // Purecap tests run under full-system emulation are slow, and a capability
+            // fault in the middle of a test can leave it hanging; don't wait forever.
+            if target.contains("purecap")
+                && builder.qemu_rootfs(target).is_some()
+                && env::var_os("TEST_DEVICE_TIMEOUT").is_none()
+            {
+                cmd.env("TEST_DEVICE_TIMEOUT", "600");
+            }
         }
 
         // Running a C compiler on MSVC requires a few env vars to be set, to be
@@ -2334,6 +2342,9 @@ fn run(self, builder: &Builder<'_>) {
         if let Some(rootfs) = builder.qemu_rootfs(target) {
             cmd.arg(rootfs);
         }
+        if let Some(firmware) = builder.qemu_firmware(target) {
+            cmd.env("TEST_DEVICE_FIRMWARE", firmware);
+        }
         builder.run(&mut cmd);
 
         // Push all our dylibs to the emulator
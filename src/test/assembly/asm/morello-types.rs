@@ -0,0 +1,100 @@
+// assembly-output: emit-asm
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![crate_type = "rlib"]
+#![no_core]
+#![allow(non_camel_case_types)]
+
+#[rustc_builtin_macro]
+macro_rules! asm {
+    () => {};
+}
+#[rustc_builtin_macro]
+macro_rules! concat {
+    () => {};
+}
+#[rustc_builtin_macro]
+macro_rules! stringify {
+    () => {};
+}
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+type ptr = *mut u8;
+type fnptr = fn();
+
+impl Copy for ptr {}
+impl Copy for fnptr {}
+
+macro_rules! check {
+    ($func:ident $ty:ident $class:ident $mov:literal $modifier:literal) => {
+        #[no_mangle]
+        pub unsafe fn $func(x: $ty) -> $ty {
+            // Hack to avoid function merging
+            extern "Rust" {
+                fn dont_merge(s: &str);
+            }
+            dont_merge(stringify!($func));
+
+            let y;
+            asm!(
+                concat!($mov, " {:", $modifier, "}, {:", $modifier, "}"),
+                out($class) y,
+                in($class) x
+            );
+            y
+        }
+    };
+}
+
+macro_rules! check_reg {
+    ($func:ident $ty:ident $reg:tt $mov:literal) => {
+        #[no_mangle]
+        pub unsafe fn $func(x: $ty) -> $ty {
+            // Hack to avoid function merging
+            extern "Rust" {
+                fn dont_merge(s: &str);
+            }
+            dont_merge(stringify!($func));
+
+            let y;
+            asm!(concat!($mov, " ", $reg, ", ", $reg), lateout($reg) y, in($reg) x);
+            y
+        }
+    };
+}
+
+// CHECK-LABEL: creg_ptr:
+// CHECK: //APP
+// CHECK: mov c{{[0-9]+}}, c{{[0-9]+}}
+// CHECK: //NO_APP
+check!(creg_ptr ptr creg "mov" "");
+
+// CHECK-LABEL: creg_fnptr:
+// CHECK: //APP
+// CHECK: mov c{{[0-9]+}}, c{{[0-9]+}}
+// CHECK: //NO_APP
+check!(creg_fnptr fnptr creg "mov" "");
+
+// CHECK-LABEL: creg_ptr_x:
+// CHECK: //APP
+// CHECK: mov x{{[0-9]+}}, x{{[0-9]+}}
+// CHECK: //NO_APP
+check!(creg_ptr_x ptr creg "mov" "x");
+
+// CHECK-LABEL: c0_ptr:
+// CHECK: //APP
+// CHECK: mov c0, c0
+// CHECK: //NO_APP
+check_reg!(c0_ptr ptr "c0" "mov");
+
+// CHECK-LABEL: c30_ptr:
+// CHECK: //APP
+// CHECK: mov c30, c30
+// CHECK: //NO_APP
+check_reg!(c30_ptr ptr "c30" "mov");
@@ -0,0 +1,90 @@
+// assembly-output: emit-asm
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![crate_type = "rlib"]
+#![no_core]
+#![allow(non_camel_case_types)]
+
+#[rustc_builtin_macro]
+macro_rules! asm {
+    () => {};
+}
+#[rustc_builtin_macro]
+macro_rules! concat {
+    () => {};
+}
+#[rustc_builtin_macro]
+macro_rules! stringify {
+    () => {};
+}
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+type ptr = *mut u8;
+type fnptr = fn();
+
+impl Copy for ptr {}
+impl Copy for fnptr {}
+
+macro_rules! check {
+    ($func:ident $ty:ident $class:ident $mov:literal) => {
+        #[no_mangle]
+        pub unsafe fn $func(x: $ty) -> $ty {
+            // Hack to avoid function merging
+            extern "Rust" {
+                fn dont_merge(s: &str);
+            }
+            dont_merge(stringify!($func));
+
+            let y;
+            asm!(concat!($mov, " {}, {}"), out($class) y, in($class) x);
+            y
+        }
+    };
+}
+
+macro_rules! check_reg {
+    ($func:ident $ty:ident $reg:tt $mov:literal) => {
+        #[no_mangle]
+        pub unsafe fn $func(x: $ty) -> $ty {
+            // Hack to avoid function merging
+            extern "Rust" {
+                fn dont_merge(s: &str);
+            }
+            dont_merge(stringify!($func));
+
+            let y;
+            asm!(concat!($mov, " ", $reg, ", ", $reg), lateout($reg) y, in($reg) x);
+            y
+        }
+    };
+}
+
+// CHECK-LABEL: creg_ptr:
+// CHECK: #APP
+// CHECK: cmove {{[a-z0-9]+}}, {{[a-z0-9]+}}
+// CHECK: #NO_APP
+check!(creg_ptr ptr creg "cmove");
+
+// CHECK-LABEL: creg_fnptr:
+// CHECK: #APP
+// CHECK: cmove {{[a-z0-9]+}}, {{[a-z0-9]+}}
+// CHECK: #NO_APP
+check!(creg_fnptr fnptr creg "cmove");
+
+// CHECK-LABEL: ca0_ptr:
+// CHECK: #APP
+// CHECK: cmove ca0, ca0
+// CHECK: #NO_APP
+check_reg!(ca0_ptr ptr "ca0" "cmove");
+
+// CHECK-LABEL: cs2_ptr:
+// CHECK: #APP
+// CHECK: cmove cs2, cs2
+// CHECK: #NO_APP
+check_reg!(cs2_ptr ptr "cs2" "cmove");
@@ -0,0 +1,36 @@
+// Check that `-Z cheri-stack-bounds` controls whether capabilities to stack allocations are
+// bounded on purecap targets.
+
+// revisions: all escapes off
+// assembly-output: emit-asm
+// compile-flags: -O --target morello-unknown-none-purecap
+//[all] compile-flags: -Z cheri-stack-bounds=all-or-nothing
+//[escapes] compile-flags: -Z cheri-stack-bounds=if-escapes
+//[off] compile-flags: -Z cheri-stack-bounds=off
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u32 {}
+
+extern "C" {
+    fn consume(x: *mut u32);
+}
+
+// CHECK-LABEL: escaping_local:
+#[no_mangle]
+pub unsafe fn escaping_local() {
+    // all: scbnds
+    // escapes: scbnds
+    // off-NOT: scbnds
+    // CHECK: bl consume
+    let mut x = 0u32;
+    consume(&mut x as *mut u32);
+}
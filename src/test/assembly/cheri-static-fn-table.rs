@@ -0,0 +1,45 @@
+// Test that an interrupt vector table of function pointers in a static gets a capability
+// relocation for every handler on purecap targets, naming the handler itself so that it is
+// initialised as a function capability, and that empty slots are left as null capabilities.
+
+// revisions: MORELLO RISCV
+// assembly-output: emit-asm
+//[MORELLO] compile-flags: --target morello-unknown-none-purecap
+//[MORELLO] needs-llvm-components: aarch64
+//[RISCV] compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+//[RISCV] needs-llvm-components: riscv
+// compile-flags: -Copt-level=2
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "sync"]
+trait Sync {}
+
+pub enum Option<T> {
+    None,
+    Some(T),
+}
+
+impl Sync for [Option<extern "C" fn()>; 4] {}
+
+#[no_mangle]
+pub extern "C" fn reset() {}
+
+#[no_mangle]
+pub extern "C" fn timer() {}
+
+// CHECK: .section .vectors
+// CHECK: VECTORS:
+// CHECK-NEXT: .chericap reset
+// MORELLO-NEXT: .zero 16
+// RISCV-NEXT: .zero 8
+// CHECK-NEXT: .chericap timer
+// CHECK-NEXT: .chericap reset
+#[no_mangle]
+#[link_section = ".vectors"]
+pub static VECTORS: [Option<extern "C" fn()>; 4] =
+    [Option::Some(reset), Option::None, Option::Some(timer), Option::Some(reset)];
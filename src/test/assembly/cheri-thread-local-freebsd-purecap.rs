@@ -0,0 +1,37 @@
+// Test that accesses to `#[thread_local]` statics on CheriBSD purecap go through the thread
+// pointer capability in CTPIDR_EL0, with the TLS descriptor relocations rtld resolves for
+// general-dynamic accesses and the TPREL relocations for initial-exec ones.
+
+// revisions: general_dynamic initial_exec
+// assembly-output: emit-asm
+// compile-flags: --target morello-unknown-freebsd-purecap -Copt-level=1 -Crelocation-model=pic
+// [initial_exec] compile-flags: -Ztls-model=initial-exec
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, thread_local)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "sync"]
+trait Sync {}
+
+impl Copy for u32 {}
+impl Sync for u32 {}
+
+#[thread_local]
+#[no_mangle]
+static mut COUNTER: u32 = 0;
+
+// CHECK-LABEL: set:
+// general_dynamic: adrp c{{[0-9]+}}, :tlsdesc:COUNTER
+// general_dynamic: .tlsdesccall COUNTER
+// initial_exec: adrp {{[xc][0-9]+}}, :gottprel:COUNTER
+// CHECK: mrs c{{[0-9]+}}, CTPIDR_EL0
+#[no_mangle]
+pub unsafe fn set(x: u32) {
+    COUNTER = x;
+}
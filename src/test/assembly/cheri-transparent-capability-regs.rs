@@ -0,0 +1,52 @@
+// Test that `#[repr(transparent)]` wrappers around pointers are passed and returned in capability
+// registers on Morello purecap, so that the tag survives the call.
+
+// assembly-output: emit-asm
+// compile-flags: --target morello-unknown-none-purecap -Copt-level=2
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+impl<T: ?Sized> Copy for PhantomData<T> {}
+impl<T: ?Sized> Copy for *const T {}
+
+#[repr(transparent)]
+#[rustc_layout_scalar_valid_range_start(1)]
+pub struct NonNull<T: ?Sized>(*const T);
+
+impl<T: ?Sized> Copy for NonNull<T> {}
+
+#[repr(transparent)]
+pub struct Typed<T>(NonNull<T>, PhantomData<T>);
+
+impl<T> Copy for Typed<T> {}
+
+extern "C" {
+    fn consume(h: Typed<u32>);
+}
+
+// CHECK-LABEL: load:
+// CHECK: ldr c0, [c0]
+// CHECK-NEXT: ret
+#[no_mangle]
+pub extern "C" fn load(p: &Typed<u32>) -> Typed<u32> {
+    *p
+}
+
+// CHECK-LABEL: forward:
+// CHECK: ldr c0, [c0]
+// CHECK: b{{l?}} consume
+#[no_mangle]
+pub unsafe extern "C" fn forward(p: &Typed<u32>) {
+    consume(*p);
+}
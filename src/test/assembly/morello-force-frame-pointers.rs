@@ -0,0 +1,40 @@
+// Test that `-C force-frame-pointers` on Morello purecap keeps a capability frame pointer in C29,
+// pushing a frame record of C29 and C30 that profilers can walk, and that the unwind tables
+// describe the frame through C29 so that debuggers find the same records.
+
+// revisions: force omit
+// assembly-output: emit-asm
+// compile-flags: --target morello-unknown-freebsd-purecap -Copt-level=2
+// [force] compile-flags: -Cforce-frame-pointers=yes
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+extern "C" {
+    fn callee();
+}
+
+// CHECK-LABEL: caller:
+// force: stp c29, c30, [csp, #-{{[0-9]+}}]!
+// force: mov c29, csp
+// force: .cfi_def_cfa c29
+// omit-NOT: mov c29, csp
+// CHECK: bl callee
+#[no_mangle]
+pub unsafe fn caller() {
+    callee();
+    callee();
+}
+
+// Leaf functions get a frame record too, so that a sample taken in one still has the whole stack.
+// CHECK-LABEL: leaf:
+// force: mov c29, csp
+// omit-NOT: c29
+// CHECK: ret
+#[no_mangle]
+pub fn leaf() {}
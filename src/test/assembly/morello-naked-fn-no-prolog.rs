@@ -0,0 +1,27 @@
+// compile-flags: --target morello-unknown-none-purecap
+// assembly-output: emit-asm
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, rustc_attrs, naked_functions)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[rustc_builtin_macro]
+macro_rules! asm {
+    () => {};
+}
+#[lang = "sized"]
+trait Sized {}
+
+// Naked functions are used for compartment-switch trampolines, which run before any capability
+// table or stack capability can be trusted, so nothing may be emitted ahead of the asm block.
+// CHECK-LABEL: trampoline:
+// CHECK-NOT: {{c[0-9]+|csp|clr}}
+// CHECK: //APP
+// CHECK-NEXT: mov c0, csp
+// CHECK-NEXT: br c1
+#[no_mangle]
+#[naked]
+pub unsafe extern "C" fn trampoline() {
+    asm!("mov c0, csp", "br c1", options(noreturn))
+}
@@ -0,0 +1,51 @@
+// Test that the unwind tables of Morello purecap code describe the capability registers that are
+// saved, so that the unwinder restores C29 and C30 with their tags, and that functions with
+// landing pads get a personality routine and an LSDA.
+
+// assembly-output: emit-asm
+// compile-flags: --target morello-unknown-freebsd-purecap -Copt-level=2 -Cpanic=unwind
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, c_unwind)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "drop"]
+trait Drop {
+    fn drop(&mut self);
+}
+#[lang = "drop_in_place"]
+#[allow(unconditional_recursion)]
+pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+    drop_in_place(to_drop)
+}
+#[lang = "eh_personality"]
+fn eh_personality() {}
+
+extern "C-unwind" {
+    fn may_unwind();
+    fn release();
+}
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        unsafe { release() }
+    }
+}
+
+// CHECK-LABEL: caller:
+// CHECK: .cfi_personality
+// CHECK: .cfi_lsda
+// CHECK: stp c29, c30, [csp
+// CHECK-DAG: .cfi_offset c30,
+// CHECK-DAG: .cfi_offset c29,
+// CHECK: bl may_unwind
+#[no_mangle]
+pub unsafe extern "C-unwind" fn caller() {
+    let _guard = Guard;
+    may_unwind();
+}
@@ -0,0 +1,37 @@
+// Check that `clobber_abi("C")` on Morello purecap clobbers whole capability registers.
+
+// compile-flags: -O --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[rustc_builtin_macro]
+macro_rules! asm {
+    () => {};
+}
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for *mut u8 {}
+
+// CHECK-LABEL: @clobber_c
+// CHECK: ={c0},={c1},={c2},={c3},={c4},={c5},={c6},={c7},={c8},={c9},={c10},={c11},={c12},={c13},={c14},={c15},={c16},={c17},={c18},={c30}
+// CHECK-NOT: {x{{[0-9]+}}}
+#[no_mangle]
+pub unsafe fn clobber_c() {
+    asm!("", clobber_abi("C"));
+}
+
+// CHECK-LABEL: @clobber_c_out
+// CHECK: ={c0},={c1},={c2}
+// CHECK-NOT: ={x0}
+#[no_mangle]
+pub unsafe fn clobber_c_out() -> *mut u8 {
+    let p;
+    asm!("", out("c0") p, clobber_abi("C"));
+    p
+}
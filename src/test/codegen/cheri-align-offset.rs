@@ -0,0 +1,27 @@
+// Check that `align_offset` and `slice::align_to` work on capability addresses on purecap
+// targets, and never rebuild a pointer from an integer.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+
+// CHECK-LABEL: @align_offset
+#[no_mangle]
+pub fn align_offset(p: *const u8) -> usize {
+    // CHECK-NOT: inttoptr
+    // CHECK: call i64 @llvm.cheri.cap.address.get
+    // CHECK-NOT: inttoptr
+    // CHECK: ret i64
+    p.align_offset(16)
+}
+
+// CHECK-LABEL: @align_to
+#[no_mangle]
+pub fn align_to(s: &[u8]) -> usize {
+    // CHECK-NOT: inttoptr
+    // CHECK-NOT: ptrtoint
+    // CHECK: ret i64
+    let (_, mid, _) = unsafe { s.align_to::<u64>() };
+    mid.len()
+}
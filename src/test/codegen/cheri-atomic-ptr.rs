@@ -0,0 +1,63 @@
+// Check that `AtomicPtr` operations act on whole capabilities on purecap targets, rather than
+// going through the address and dropping the tag.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(atomic_ptr_compare_exchange_addr, strict_provenance_atomic_ptr)]
+
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+// CHECK-LABEL: @load
+#[no_mangle]
+pub fn load(a: &AtomicPtr<u8>) -> *mut u8 {
+    // CHECK: load atomic ptr addrspace(200), ptr addrspace(200) %a acquire, align 16
+    a.load(Ordering::Acquire)
+}
+
+// CHECK-LABEL: @store
+#[no_mangle]
+pub fn store(a: &AtomicPtr<u8>, p: *mut u8) {
+    // CHECK: store atomic ptr addrspace(200) %p, ptr addrspace(200) %a release, align 16
+    a.store(p, Ordering::Release)
+}
+
+// CHECK-LABEL: @swap
+#[no_mangle]
+pub fn swap(a: &AtomicPtr<u8>, p: *mut u8) -> *mut u8 {
+    // CHECK: atomicrmw xchg ptr addrspace(200) %a, ptr addrspace(200) %p acq_rel
+    a.swap(p, Ordering::AcqRel)
+}
+
+// CHECK-LABEL: @compare_exchange
+#[no_mangle]
+pub fn compare_exchange(a: &AtomicPtr<u8>, old: *mut u8, new: *mut u8) -> bool {
+    // CHECK: cmpxchg exact ptr addrspace(200) %a, ptr addrspace(200) %old, ptr addrspace(200) %new
+    a.compare_exchange(old, new, Ordering::SeqCst, Ordering::Relaxed).is_ok()
+}
+
+// CHECK-LABEL: @compare_exchange_addr
+#[no_mangle]
+pub fn compare_exchange_addr(a: &AtomicPtr<u8>, old: *mut u8, new: *mut u8) -> bool {
+    // CHECK: load atomic ptr addrspace(200), ptr addrspace(200) %a
+    // CHECK: call i64 @llvm.cheri.cap.address.get
+    // CHECK: cmpxchg weak exact ptr addrspace(200) %a
+    a.compare_exchange_addr(old, new, Ordering::SeqCst, Ordering::Relaxed).is_ok()
+}
+
+// CHECK-LABEL: @fetch_byte_add
+#[no_mangle]
+pub fn fetch_byte_add(a: &AtomicPtr<u8>) -> *mut u8 {
+    // CHECK-NOT: ptrtoint
+    // CHECK: atomicrmw add ptr addrspace(200) %a, ptr addrspace(200)
+    a.fetch_byte_add(16, Ordering::Relaxed)
+}
+
+// CHECK-LABEL: @fetch_or
+#[no_mangle]
+pub fn fetch_or(a: &AtomicPtr<u8>) -> *mut u8 {
+    // CHECK-NOT: ptrtoint
+    // CHECK: atomicrmw or ptr addrspace(200) %a, ptr addrspace(200)
+    a.fetch_or(1, Ordering::Relaxed)
+}
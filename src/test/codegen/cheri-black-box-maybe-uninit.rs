@@ -0,0 +1,50 @@
+// Check that `black_box` and by-value `MaybeUninit` moves keep capability tags on purecap
+// targets, rather than moving capability-sized aggregates through integer registers.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+
+use std::hint::black_box;
+use std::mem::MaybeUninit;
+
+#[derive(Clone, Copy)]
+pub union PtrOrBits {
+    ptr: *const u8,
+    bits: u128,
+}
+
+// CHECK-LABEL: @pass_union
+// CHECK-NOT: i128
+// CHECK-SAME: ptr addrspace(200) {{.*}}%x
+#[no_mangle]
+pub fn pass_union(x: PtrOrBits) -> *const u8 {
+    unsafe { x.ptr }
+}
+
+// CHECK-LABEL: @pass_maybe_uninit
+// CHECK-NOT: i128
+#[no_mangle]
+pub fn pass_maybe_uninit(x: MaybeUninit<PtrOrBits>) -> MaybeUninit<PtrOrBits> {
+    x
+}
+
+// CHECK-LABEL: @black_box_ptr
+#[no_mangle]
+pub fn black_box_ptr(p: *const u8) -> *const u8 {
+    // CHECK-NOT: ptrtoint
+    // CHECK: store ptr addrspace(200) %p
+    // CHECK: call void asm sideeffect "", "C,~{memory}"
+    black_box(p)
+}
+
+// CHECK-LABEL: @black_box_union
+#[no_mangle]
+pub fn black_box_union(x: PtrOrBits) -> PtrOrBits {
+    // CHECK-NOT: load i128
+    // CHECK: call void @llvm.memcpy{{.*}} [[MUST:#[0-9]+]]
+    black_box(x)
+}
+
+// CHECK: attributes [[MUST]] = {{.*}}must_preserve_cheri_tags
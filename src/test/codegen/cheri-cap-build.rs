@@ -0,0 +1,36 @@
+// Check that `cheri_cap_build` derives a bounded capability from DDC.
+//
+// The intrinsic is meant for hybrid code, but `#[cheri_capability]` types are laid out the same
+// way on purecap targets, which unlike hybrid ones are built in.
+
+// compile-flags: -O --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, intrinsics, cheri_capability)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+#[cheri_capability]
+pub struct Capability<T>(PhantomData<T>);
+
+extern "rust-intrinsic" {
+    fn cheri_cap_build<C>(addr: usize, len: usize) -> C;
+}
+
+// CHECK-LABEL: @from_legacy
+// CHECK-SAME: (i64 %addr, i64 %len)
+#[no_mangle]
+pub unsafe fn from_legacy(addr: usize, len: usize) -> Capability<u8> {
+    // CHECK: [[DDC:%.*]] = {{.*}}call ptr addrspace(200) @llvm.cheri.ddc.get()
+    // CHECK: [[CAP:%.*]] = {{.*}}call ptr addrspace(200) @llvm.cheri.cap.address.set{{.*}}(ptr addrspace(200) [[DDC]], i64 %addr)
+    // CHECK: [[BOUNDED:%.*]] = {{.*}}call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) [[CAP]], i64 %len)
+    // CHECK: ret ptr addrspace(200) [[BOUNDED]]
+    cheri_cap_build(addr, len)
+}
@@ -0,0 +1,80 @@
+// Check that the capability-inspection functions lower to the CHERI intrinsics.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(cheri_intrinsics, ptr_eq_exact)]
+
+use std::arch::aarch64::cheri::*;
+
+// CHECK-LABEL: @base
+#[no_mangle]
+pub fn base(p: *const u8) -> usize {
+    // CHECK: call i64 @llvm.cheri.cap.base.get.i64
+    cheri_base_get(p)
+}
+
+// CHECK-LABEL: @length
+#[no_mangle]
+pub fn length(p: &[u32]) -> usize {
+    // CHECK: call i64 @llvm.cheri.cap.length.get.i64
+    cheri_length_get(p)
+}
+
+// CHECK-LABEL: @tag
+#[no_mangle]
+pub fn tag(p: *const u8) -> bool {
+    // CHECK: call i1 @llvm.cheri.cap.tag.get
+    cheri_tag_get(p)
+}
+
+// CHECK-LABEL: @tag_clear
+#[no_mangle]
+pub fn tag_clear(p: *const u8) -> *const u8 {
+    // CHECK: call {{.*}} @llvm.cheri.cap.tag.clear
+    cheri_tag_clear(p)
+}
+
+// CHECK-LABEL: @bounds
+#[no_mangle]
+pub fn bounds(p: *const u64) -> *const u64 {
+    // CHECK: call {{.*}} @llvm.cheri.cap.bounds.set.exact.i64
+    cheri_bounds_set_exact(p, 8)
+}
+
+// CHECK-LABEL: @seal
+#[no_mangle]
+pub fn seal(p: *const u8, auth: *const ()) -> *const u8 {
+    // CHECK: call {{.*}} @llvm.cheri.cap.seal
+    cheri_seal(p, auth)
+}
+
+// CHECK-LABEL: @sentry
+#[no_mangle]
+pub fn sentry(f: extern "C" fn()) -> extern "C" fn() {
+    // CHECK: call {{.*}} @llvm.cheri.cap.seal.entry
+    unsafe { cheri_sentry_create(f) }
+}
+
+// CHECK-LABEL: @read_only
+#[no_mangle]
+pub fn read_only(p: *const u8) -> *const u8 {
+    // CHECK: call {{.*}} @llvm.cheri.cap.perms.and.i64({{.*}}, i64 -73729)
+    cheri_perms_and(p, !(Perms::STORE | Perms::STORE_CAP))
+}
+
+// CHECK-LABEL: @representable
+#[no_mangle]
+pub fn representable(len: usize) -> (usize, usize) {
+    // CHECK: call i64 @llvm.cheri.round.representable.length.i64
+    // CHECK: call i64 @llvm.cheri.representable.alignment.mask.i64
+    (cheri_representable_length(len), cheri_representable_alignment_mask(len))
+}
+
+// CHECK-LABEL: @exact
+#[no_mangle]
+pub fn exact(a: *const u8, b: *const u8) -> bool {
+    // CHECK: call i1 @llvm.cheri.cap.equal.exact
+    std::ptr::eq_exact(a, b)
+}
@@ -0,0 +1,63 @@
+// Check that aggregates containing capabilities are passed in capability registers when they fit
+// in two of them and by reference otherwise, and never split through integer registers.
+
+// revisions: MORELLO RISCV
+//[MORELLO] compile-flags: --target morello-unknown-none-purecap
+//[MORELLO] needs-llvm-components: aarch64
+//[RISCV] compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+//[RISCV] needs-llvm-components: riscv
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[repr(C)]
+pub struct CapAndInt {
+    ptr: *const u8,
+    len: u64,
+}
+
+#[repr(C)]
+pub struct TwoCaps {
+    a: *const u8,
+    b: *const u8,
+}
+
+#[repr(C)]
+pub struct ThreeCaps {
+    a: *const u8,
+    b: *const u8,
+    c: *const u8,
+}
+
+#[repr(C)]
+pub struct Ints {
+    a: u64,
+    b: u64,
+}
+
+// CHECK-LABEL: @cap_and_int
+// CHECK-SAME: ([2 x ptr addrspace(200)]
+#[no_mangle]
+pub extern "C" fn cap_and_int(_x: CapAndInt) {}
+
+// CHECK-LABEL: @two_caps
+// CHECK-SAME: ([2 x ptr addrspace(200)]
+#[no_mangle]
+pub extern "C" fn two_caps(_x: TwoCaps) {}
+
+// CHECK-LABEL: @three_caps
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%_x)
+#[no_mangle]
+pub extern "C" fn three_caps(_x: ThreeCaps) {}
+
+// Aggregates without capabilities are unaffected.
+// CHECK-LABEL: @ints
+// MORELLO-SAME: ([2 x i64]
+// RISCV-SAME: (ptr addrspace(200) {{.*}}%_x)
+#[no_mangle]
+pub extern "C" fn ints(_x: Ints) {}
@@ -0,0 +1,47 @@
+// Check that capabilities and aggregates containing them are returned in capability registers when
+// they fit in two of them, and through a capability `sret` pointer otherwise.
+
+// revisions: MORELLO RISCV
+//[MORELLO] compile-flags: --target morello-unknown-none-purecap
+//[MORELLO] needs-llvm-components: aarch64
+//[RISCV] compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+//[RISCV] needs-llvm-components: riscv
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[repr(C)]
+pub struct CapAndInt {
+    ptr: *mut u8,
+    len: u64,
+}
+
+#[repr(C)]
+pub struct ThreeCaps {
+    a: *mut u8,
+    b: *mut u8,
+    c: *mut u8,
+}
+
+// CHECK: define{{.*}} ptr addrspace(200) @pointer(
+#[no_mangle]
+pub extern "C" fn pointer(p: *mut u8) -> *mut u8 {
+    p
+}
+
+// CHECK: define{{.*}} [2 x ptr addrspace(200)] @cap_and_int(
+#[no_mangle]
+pub extern "C" fn cap_and_int(ptr: *mut u8, len: u64) -> CapAndInt {
+    CapAndInt { ptr, len }
+}
+
+// CHECK: define{{.*}} void @three_caps(ptr addrspace(200) {{.*}}sret(%ThreeCaps)
+#[no_mangle]
+pub extern "C" fn three_caps(a: *mut u8, b: *mut u8, c: *mut u8) -> ThreeCaps {
+    ThreeCaps { a, b, c }
+}
@@ -0,0 +1,56 @@
+// ignore-tidy-linelength
+
+// Verifies that `-Zcheck-provenance-casts=runtime` checks pointers cast from integers before
+// terminators use them, here to drop the value that an assignment through one replaces.
+//
+// compile-flags: -Cno-prepopulate-passes -Zcheck-provenance-casts=runtime --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+#![allow(cheri_int_to_ptr_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u32 {}
+
+#[lang = "drop"]
+trait Drop {
+    fn drop(&mut self);
+}
+
+#[lang = "drop_in_place"]
+#[allow(unconditional_recursion)]
+unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+    drop_in_place(to_drop)
+}
+
+#[lang = "panic_location"]
+struct Location<'a> {
+    file: &'a str,
+    line: u32,
+    col: u32,
+}
+
+#[lang = "cheri_check_provenance_cast"]
+#[track_caller]
+fn check_provenance_cast(_ptr: *const u8, _len: usize) {}
+
+pub struct Inner(u32);
+
+impl Drop for Inner {
+    fn drop(&mut self) {}
+}
+
+// CHECK-LABEL: @replace
+#[no_mangle]
+pub unsafe fn replace(addr: usize) {
+    let p = addr as *mut Inner;
+    // CHECK: call void @{{.*}}check_provenance_cast{{.*}}(ptr addrspace(200) {{.*}}, i64 {{.*}}4, ptr addrspace(200)
+    // CHECK: call void @{{.*}}drop_in_place{{.*}}Inner
+    *p = Inner(0);
+}
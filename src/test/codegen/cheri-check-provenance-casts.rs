@@ -0,0 +1,65 @@
+// ignore-tidy-linelength
+
+// Verifies that `-Zcheck-provenance-casts=runtime` checks pointers cast from integers before they
+// are used, and leaves other pointers alone.
+//
+// compile-flags: -Cno-prepopulate-passes -Zcheck-provenance-casts=runtime --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+#![allow(cheri_int_to_ptr_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u32 {}
+
+#[lang = "panic_location"]
+struct Location<'a> {
+    file: &'a str,
+    line: u32,
+    col: u32,
+}
+
+#[lang = "cheri_check_provenance_cast"]
+#[track_caller]
+fn check_provenance_cast(_ptr: *const u8, _len: usize) {}
+
+// CHECK-LABEL: @load
+#[no_mangle]
+pub unsafe fn load(addr: usize) -> u32 {
+    // CHECK: call void @{{.*}}check_provenance_cast{{.*}}(ptr addrspace(200) {{.*}}[[PTR:%[a-z0-9_.]+]], i64 {{.*}}4, ptr addrspace(200)
+    // CHECK-NEXT: load i32, ptr addrspace(200) [[PTR]]
+    *(addr as *const u32)
+}
+
+// CHECK-LABEL: @store
+#[no_mangle]
+pub unsafe fn store(addr: usize, x: u32) {
+    let p = addr as *mut u32;
+    // CHECK: call void @{{.*}}check_provenance_cast{{.*}}(ptr addrspace(200) {{.*}}, i64 {{.*}}4, ptr addrspace(200)
+    // CHECK-NEXT: store i32 %x
+    *p = x;
+}
+
+// Pointers that don't come from integers are not checked.
+// CHECK-LABEL: @not_cast
+#[no_mangle]
+pub unsafe fn not_cast(p: *const u32) -> u32 {
+    // CHECK-NOT: check_provenance_cast
+    // CHECK: load i32, ptr addrspace(200) %p
+    *p
+}
+
+// Nor are pointers that are cast but never used here.
+// CHECK-LABEL: @passed_on
+#[no_mangle]
+pub fn passed_on(addr: usize) -> *const u32 {
+    // CHECK-NOT: check_provenance_cast
+    // CHECK: ret ptr addrspace(200)
+    addr as *const u32
+}
@@ -0,0 +1,42 @@
+// Check that the copy intrinsics keep capability tags on purecap targets even when the element
+// type is less aligned than a capability, while plain moves of such types do not need to.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+
+use std::ptr;
+
+#[repr(C)]
+pub struct Caps {
+    a: *const u8,
+    b: *const u8,
+}
+
+// CHECK-LABEL: @copy_bytes
+#[no_mangle]
+pub unsafe fn copy_bytes(src: *const u8, dst: *mut u8, len: usize) {
+    // CHECK: call void @llvm.memcpy
+    // CHECK-NOT: no_preserve_cheri_tags
+    // CHECK: ret void
+    ptr::copy_nonoverlapping(src, dst, len);
+}
+
+// CHECK-LABEL: @move_bytes
+#[no_mangle]
+pub unsafe fn move_bytes(src: *const u8, dst: *mut u8, len: usize) {
+    // CHECK: call void @llvm.memmove
+    // CHECK-NOT: no_preserve_cheri_tags
+    // CHECK: ret void
+    ptr::copy(src, dst, len);
+}
+
+// CHECK-LABEL: @copy_caps
+#[no_mangle]
+pub unsafe fn copy_caps(src: *const Caps, dst: *mut Caps, len: usize) {
+    // CHECK: call void @llvm.memcpy{{.*}} [[MUST:#[0-9]+]]
+    ptr::copy_nonoverlapping(src, dst, len);
+}
+
+// CHECK: attributes [[MUST]] = {{.*}}must_preserve_cheri_tags
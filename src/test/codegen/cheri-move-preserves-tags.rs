@@ -0,0 +1,43 @@
+// Check that moves and clones of values holding capabilities keep their tags on purecap targets,
+// and that values which cannot hold an aligned capability are copied without them.
+
+// only-morello+c64
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#[derive(Clone, Copy)]
+pub struct Pointers {
+    ptrs: [*const u8; 8],
+}
+
+#[derive(Clone, Copy)]
+#[repr(packed)]
+pub struct Packed {
+    tag: u8,
+    ptrs: [*const u8; 8],
+}
+
+// CHECK-LABEL: @clone_pointers
+#[no_mangle]
+pub fn clone_pointers(x: &Pointers) -> Pointers {
+    // CHECK: call void @llvm.memcpy{{.*}} [[MUST:#[0-9]+]]
+    x.clone()
+}
+
+// CHECK-LABEL: @move_pointers
+#[no_mangle]
+pub fn move_pointers(x: Pointers, out: &mut Pointers) {
+    // CHECK: call void @llvm.memcpy{{.*}} [[MUST]]
+    *out = x;
+}
+
+// CHECK-LABEL: @move_packed
+#[no_mangle]
+pub fn move_packed(x: Packed, out: &mut Packed) {
+    // CHECK: call void @llvm.memcpy{{.*}} [[NO:#[0-9]+]]
+    *out = x;
+}
+
+// CHECK: attributes [[MUST]] = {{.*}}must_preserve_cheri_tags
+// CHECK: attributes [[NO]] = {{.*}}no_preserve_cheri_tags
@@ -0,0 +1,26 @@
+// Check that `NonNull::dangling` and `ptr::invalid` build NULL-derived capabilities on purecap
+// targets instead of truncating a capability-sized transmute or casting an integer.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(strict_provenance)]
+
+use std::ptr::NonNull;
+
+// CHECK-LABEL: @dangling
+#[no_mangle]
+pub fn dangling() -> NonNull<u64> {
+    // CHECK-NOT: inttoptr
+    // CHECK: ret ptr addrspace(200)
+    NonNull::dangling()
+}
+
+// CHECK-LABEL: @invalid
+#[no_mangle]
+pub fn invalid(addr: usize) -> *mut u8 {
+    // CHECK-NOT: inttoptr
+    // CHECK: ret ptr addrspace(200)
+    std::ptr::invalid_mut(addr)
+}
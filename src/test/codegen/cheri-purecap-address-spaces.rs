@@ -0,0 +1,56 @@
+// Check that pointers, allocas, globals and function pointers all live in the capability address
+// space on purecap targets.
+
+// compile-flags: -C no-prepopulate-passes --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "freeze"]
+trait Freeze {}
+#[lang = "sync"]
+trait Sync {}
+
+impl Copy for u32 {}
+impl Sync for u32 {}
+
+// CHECK: @STATIC = {{.*}}addrspace(200) constant i32 0
+#[no_mangle]
+pub static STATIC: u32 = 0;
+
+// CHECK-LABEL: @reference
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%x)
+#[no_mangle]
+pub fn reference(x: &u32) -> &u32 {
+    // CHECK: ret ptr addrspace(200) %x
+    x
+}
+
+// CHECK-LABEL: @static_address
+#[no_mangle]
+pub fn static_address() -> &'static u32 {
+    // CHECK: ret ptr addrspace(200) @STATIC
+    &STATIC
+}
+
+// CHECK-LABEL: @local
+#[no_mangle]
+pub fn local(x: u32) -> *const u32 {
+    // CHECK: alloca i32, align 4, addrspace(200)
+    let y = x;
+    &y as *const u32
+}
+
+// CHECK-LABEL: @function_pointer
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%f)
+#[no_mangle]
+pub fn function_pointer(f: fn()) {
+    // CHECK: call {{.*}}void %f()
+    f()
+}
@@ -0,0 +1,42 @@
+// Check that purecap code reaches globals defined elsewhere through the capability table even
+// with the static relocation model, and that pointers in statics are emitted as capability
+// initializers for the linker to turn into capability relocations.
+
+// compile-flags: -C no-prepopulate-passes --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "freeze"]
+trait Freeze {}
+#[lang = "sync"]
+trait Sync {}
+
+impl Copy for u32 {}
+impl Sync for u32 {}
+impl Sync for &'static u32 {}
+
+// CHECK: @TARGET = {{(dso_local )?}}{{.*}}addrspace(200) constant i32 1
+#[no_mangle]
+pub static TARGET: u32 = 1;
+
+// CHECK: @POINTER = {{.*}}addrspace(200) constant {{.*}}ptr addrspace(200) @TARGET
+#[no_mangle]
+pub static POINTER: &u32 = &TARGET;
+
+extern "C" {
+    // CHECK: @EXTERNAL = external{{( local_unnamed_addr)?}} addrspace(200) global i32
+    static EXTERNAL: u32;
+}
+
+// CHECK-LABEL: @read_external
+#[no_mangle]
+pub fn read_external() -> u32 {
+    unsafe { EXTERNAL }
+}
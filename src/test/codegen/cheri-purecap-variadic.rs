@@ -0,0 +1,43 @@
+// Check that C-variadic functions on Morello purecap use the capability address space overloads
+// of the `va_*` intrinsics and read arguments directly from the single-capability `va_list`.
+
+// compile-flags: -C no-prepopulate-passes --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, c_variadic, intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+impl Copy for i32 {}
+impl Copy for i64 {}
+
+#[lang = "va_list"]
+pub struct VaListImpl<'f> {
+    ptr: *mut u8,
+    _marker: PhantomData<&'f mut &'f u8>,
+}
+
+extern "rust-intrinsic" {
+    fn va_arg<T>(ap: &mut VaListImpl<'_>) -> T;
+}
+
+// CHECK-LABEL: @sum
+#[no_mangle]
+pub unsafe extern "C" fn sum(_n: i32, mut args: ...) -> i64 {
+    // CHECK: alloca %VaListImpl, align 16, addrspace(200)
+    // CHECK: call void @llvm.va_start.p200i8(ptr addrspace(200)
+    // CHECK-NOT: va_arg ptr
+    // CHECK: load ptr addrspace(200), ptr addrspace(200) {{.*}}, align 16
+    let a: i32 = va_arg(&mut args);
+    let b: i64 = va_arg(&mut args);
+    // CHECK: call void @llvm.va_end.p200i8(ptr addrspace(200)
+    let _ = a;
+    b
+}
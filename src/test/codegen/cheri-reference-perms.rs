@@ -0,0 +1,34 @@
+// Check that the reference introspection functions read the capability behind the reference
+// directly, without going through an integer.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(cheri_intrinsics, cheri_reference_perms)]
+
+use std::arch::cheri::*;
+
+// CHECK-LABEL: @shared_perms
+#[no_mangle]
+pub fn shared_perms(r: &u32) -> Perms {
+    // CHECK-NOT: ptrtoint
+    // CHECK: call i64 @llvm.cheri.cap.perms.get.i64(ptr addrspace(200) {{.*}}%r)
+    perms_of(r)
+}
+
+// CHECK-LABEL: @unique_can_store
+#[no_mangle]
+pub fn unique_can_store(r: &mut [u8]) -> bool {
+    // CHECK-NOT: ptrtoint
+    // CHECK: call i64 @llvm.cheri.cap.perms.get.i64(ptr addrspace(200) {{.*}}%r.0)
+    can_store(r)
+}
+
+// CHECK-LABEL: @bounds
+#[no_mangle]
+pub fn bounds(r: &[u64; 4]) -> std::ops::Range<usize> {
+    // CHECK-DAG: call i64 @llvm.cheri.cap.base.get.i64
+    // CHECK-DAG: call i64 @llvm.cheri.cap.length.get.i64
+    bounds_of(r)
+}
@@ -0,0 +1,63 @@
+// Test that atomics on capability-width integers on CHERI-RISC-V are either left to the backend,
+// which lowers them to `lr.c`/`sc.c`, or expanded to a compare-exchange loop for the operations it
+// can't do on more than a register's worth of bits.
+
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap -Copt-level=1
+// needs-llvm-components: riscv
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u64 {}
+impl<T: ?Sized> Copy for *mut T {}
+
+extern "rust-intrinsic" {
+    fn atomic_xchg_seqcst<T: Copy>(dst: *mut T, src: T) -> T;
+    fn atomic_xadd_acquire<T: Copy>(dst: *mut T, src: T) -> T;
+    fn atomic_umax_relaxed<T: Copy>(dst: *mut T, src: T) -> T;
+}
+
+// CHECK-LABEL: @swap
+// CHECK: atomicrmw xchg ptr addrspace(200) %p, i64 %x seq_cst
+#[no_mangle]
+pub unsafe fn swap(p: *mut u64, x: u64) -> u64 {
+    atomic_xchg_seqcst(p, x)
+}
+
+// CHECK-LABEL: @fetch_add
+// CHECK-NOT: atomicrmw
+// CHECK: load atomic i64, ptr addrspace(200) %p monotonic, align 8
+// CHECK: atomicrmw.loop:
+// CHECK: [[OLD:%.*]] = phi i64
+// CHECK: [[NEW:%.*]] = add i64 [[OLD]], %x
+// CHECK: cmpxchg ptr addrspace(200) %p, i64 [[OLD]], i64 [[NEW]] acquire acquire
+// CHECK: atomicrmw.done:
+// CHECK: ret i64 [[OLD]]
+#[no_mangle]
+pub unsafe fn fetch_add(p: *mut u64, x: u64) -> u64 {
+    atomic_xadd_acquire(p, x)
+}
+
+// CHECK-LABEL: @fetch_max
+// CHECK: [[OLD:%.*]] = phi i64
+// CHECK: [[KEEP:%.*]] = icmp ugt i64 [[OLD]], %x
+// CHECK: select i1 [[KEEP]], i64 [[OLD]], i64 %x
+// CHECK: cmpxchg ptr addrspace(200) %p, {{.*}} monotonic monotonic
+#[no_mangle]
+pub unsafe fn fetch_max(p: *mut u64, x: u64) -> u64 {
+    atomic_umax_relaxed(p, x)
+}
+
+// Capabilities are exchanged whole.
+// CHECK-LABEL: @swap_ptr
+// CHECK: atomicrmw xchg ptr addrspace(200) %p, ptr addrspace(200) %x seq_cst
+#[no_mangle]
+pub unsafe fn swap_ptr(p: *mut *mut u8, x: *mut u8) -> *mut u8 {
+    atomic_xchg_seqcst(p, x)
+}
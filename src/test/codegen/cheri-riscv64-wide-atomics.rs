@@ -0,0 +1,65 @@
+// Test that 128-bit atomics, and so `AtomicU128`, are available on 64-bit CHERI-RISC-V, where
+// `lr.c`/`sc.c` can access capability-width integers, and that the operations the backend can't
+// do on more than a register's worth of bits are expanded to a compare-exchange loop.
+
+// compile-flags: --target riscv64imafdcxcheri-unknown-none-purecap -Copt-level=1
+// needs-llvm-components: riscv
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u128 {}
+
+extern "rust-intrinsic" {
+    fn atomic_load_acquire<T: Copy>(src: *const T) -> T;
+    fn atomic_xchg_seqcst<T: Copy>(dst: *mut T, src: T) -> T;
+    fn atomic_cxchg_seqcst_seqcst<T: Copy>(dst: *mut T, old: T, src: T) -> (T, bool);
+    fn atomic_xadd_release<T: Copy>(dst: *mut T, src: T) -> T;
+}
+
+// `AtomicU128` is gated on this.
+// CHECK-LABEL: @has_atomic_128
+#[cfg(target_has_atomic = "128")]
+#[no_mangle]
+pub fn has_atomic_128() {}
+
+// CHECK-LABEL: @load
+// CHECK: load atomic i128, ptr addrspace(200) %p acquire, align 16
+#[no_mangle]
+pub unsafe fn load(p: *mut u128) -> u128 {
+    atomic_load_acquire(p)
+}
+
+// CHECK-LABEL: @swap
+// CHECK: atomicrmw xchg ptr addrspace(200) %p, i128 %x seq_cst
+#[no_mangle]
+pub unsafe fn swap(p: *mut u128, x: u128) -> u128 {
+    atomic_xchg_seqcst(p, x)
+}
+
+// CHECK-LABEL: @compare_exchange
+// CHECK: cmpxchg ptr addrspace(200) %p, i128 %old, i128 %new seq_cst seq_cst
+#[no_mangle]
+pub unsafe fn compare_exchange(p: *mut u128, old: u128, new: u128) -> bool {
+    atomic_cxchg_seqcst_seqcst(p, old, new).1
+}
+
+// CHECK-LABEL: @fetch_add
+// CHECK-NOT: atomicrmw
+// CHECK: load atomic i128, ptr addrspace(200) %p monotonic, align 16
+// CHECK: atomicrmw.loop:
+// CHECK: [[OLD:%.*]] = phi i128
+// CHECK: [[NEW:%.*]] = add i128 [[OLD]], %x
+// CHECK: cmpxchg ptr addrspace(200) %p, i128 [[OLD]], i128 [[NEW]] release monotonic
+// CHECK: atomicrmw.done:
+// CHECK: ret i128 [[OLD]]
+#[no_mangle]
+pub unsafe fn fetch_add(p: *mut u128, x: u128) -> u128 {
+    atomic_xadd_release(p, x)
+}
@@ -0,0 +1,42 @@
+// Check that `-Z cheri-sealed-vtables` seals the vtable pointer of new trait objects and
+// unseals it before dynamic dispatch.
+
+// only-morello+c64
+// compile-flags: -O -Z cheri-sealed-vtables
+
+#![crate_type = "lib"]
+
+pub trait Speak {
+    fn speak(&self) -> u32;
+}
+
+impl Speak for u32 {
+    fn speak(&self) -> u32 {
+        *self
+    }
+}
+
+// CHECK-LABEL: @as_dyn
+#[no_mangle]
+pub fn as_dyn(x: &u32) -> &dyn Speak {
+    // CHECK: [[KEY:%.*]] = load ptr addrspace(200), ptr addrspace(200) @__rust_cheri_vtable_seal_key
+    // CHECK: call ptr addrspace(200) @llvm.cheri.cap.seal(ptr addrspace(200) @vtable.{{[0-9]+}}, ptr addrspace(200) [[KEY]])
+    x
+}
+
+// CHECK-LABEL: @call_dyn
+#[no_mangle]
+pub fn call_dyn(x: &dyn Speak) -> u32 {
+    // CHECK-NOT: @llvm.cheri.cap.sealed.get
+    // CHECK: call ptr addrspace(200) @llvm.cheri.cap.unseal(ptr addrspace(200) %x.1
+    // CHECK-NOT: select
+    x.speak()
+}
+
+// CHECK-LABEL: @const_dyn
+#[no_mangle]
+pub fn const_dyn() -> &'static dyn Speak {
+    // CHECK: call ptr addrspace(200) @llvm.cheri.cap.seal(
+    const SPEAKER: &dyn Speak = &7u32;
+    SPEAKER
+}
@@ -0,0 +1,24 @@
+// Check that `Sealed` lowers to the sealing intrinsics and is just a capability.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(cheri_intrinsics, cheri_sealed)]
+
+use std::arch::cheri::Sealed;
+
+// CHECK-LABEL: @seal
+#[no_mangle]
+pub fn seal(p: *mut u64, key: *const u8) -> Option<Sealed<u64>> {
+    // CHECK: call {{.*}} @llvm.cheri.cap.seal
+    Sealed::seal(p, key)
+}
+
+// CHECK-LABEL: @unseal
+// CHECK-SAME: ptr addrspace(200) {{.*}}%handle
+#[no_mangle]
+pub fn unseal(handle: Sealed<u64>, key: *const u8) -> Option<*mut u64> {
+    // CHECK: call {{.*}} @llvm.cheri.cap.unseal
+    handle.unseal(key)
+}
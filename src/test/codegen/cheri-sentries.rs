@@ -0,0 +1,46 @@
+// Check that function pointers in statics and vtables are emitted as references to the
+// functions themselves, so that the linker turns them into sealed entry capabilities, and that
+// `fn_addr_eq` only compares addresses.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(ptr_fn_addr_eq)]
+
+pub trait Speak {
+    fn speak(&self) -> u32;
+}
+
+impl Speak for u32 {
+    #[inline(never)]
+    fn speak(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[inline(never)]
+#[no_mangle]
+pub fn callback() -> u32 {
+    42
+}
+
+// CHECK-DAG: @CALLBACK = {{.*}}ptr addrspace(200) @callback
+#[no_mangle]
+pub static CALLBACK: fn() -> u32 = callback;
+
+// CHECK-DAG: @vtable.{{[0-9]+}} = {{.*}}ptr addrspace(200) @{{.*}}speak
+
+// CHECK-LABEL: @as_dyn
+#[no_mangle]
+pub fn as_dyn(x: &u32) -> &dyn Speak {
+    x
+}
+
+// CHECK-LABEL: @same_function
+#[no_mangle]
+pub fn same_function(f: fn() -> u32, g: fn() -> u32) -> bool {
+    // CHECK-NOT: llvm.cheri.cap.equal.exact
+    // CHECK: ret i1
+    std::ptr::fn_addr_eq(f, g)
+}
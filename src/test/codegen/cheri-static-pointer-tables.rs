@@ -0,0 +1,48 @@
+// ignore-tidy-linelength
+//
+// Check that statics holding tables of function pointers and trait objects are emitted with a
+// separate pointer constant for every element, so that each one gets its own capability relocation
+// on purecap targets, and that function pointers name their function directly.
+
+// only-morello+c64
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#[no_mangle]
+pub extern "C" fn reset() {}
+
+#[no_mangle]
+pub extern "C" fn irq() {}
+
+// CHECK: @VECTORS = {{.*}}constant <{ ptr addrspace(200), [16 x i8], ptr addrspace(200) }> <{ ptr addrspace(200) @reset, [16 x i8] zeroinitializer, ptr addrspace(200) @irq }>, align 16
+#[no_mangle]
+pub static VECTORS: [Option<extern "C" fn()>; 3] = [Some(reset), None, Some(irq)];
+
+pub trait Plugin {
+    fn run(&self) -> u32;
+}
+
+pub struct First;
+pub struct Second(u32);
+
+impl Plugin for First {
+    fn run(&self) -> u32 {
+        1
+    }
+}
+
+impl Plugin for Second {
+    fn run(&self) -> u32 {
+        self.0
+    }
+}
+
+// Both the data pointer and the vtable pointer of each trait object are capabilities.
+// CHECK: @PLUGINS = {{.*}}constant <{ ptr addrspace(200), ptr addrspace(200), ptr addrspace(200), ptr addrspace(200) }> <{ ptr addrspace(200) @{{[^ ,]+}}, ptr addrspace(200) [[FIRST_VTABLE:@[^ ,]+]], ptr addrspace(200) @{{[^ ,]+}}, ptr addrspace(200) [[SECOND_VTABLE:@[^ ,]+]] }>, align 16
+#[no_mangle]
+pub static PLUGINS: [&(dyn Plugin + Sync); 2] = [&First, &Second(2)];
+
+// The vtables are themselves tables of capabilities, with the methods named directly.
+// CHECK-DAG: [[FIRST_VTABLE]] = {{.*}}constant <{ ptr addrspace(200), [32 x i8], ptr addrspace(200) }> <{ ptr addrspace(200) @{{.*}}drop_in_place{{.*}}, [32 x i8] {{.*}}, ptr addrspace(200) @{{.*}}First{{.*}}run{{.*}} }>, align 16
+// CHECK-DAG: [[SECOND_VTABLE]] = {{.*}}constant <{ ptr addrspace(200), [32 x i8], ptr addrspace(200) }> <{ ptr addrspace(200) @{{.*}}drop_in_place{{.*}}, [32 x i8] {{.*}}, ptr addrspace(200) @{{.*}}Second{{.*}}run{{.*}} }>, align 16
@@ -0,0 +1,24 @@
+// Check that the strict-provenance API reads and sets capability addresses on purecap targets,
+// rather than going through integer-pointer casts that clear the tag.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(strict_provenance)]
+
+// CHECK-LABEL: @addr
+#[no_mangle]
+pub fn addr(p: *const u32) -> usize {
+    // CHECK: call i64 @llvm.cheri.cap.address.get
+    // CHECK-NOT: ptrtoint
+    p.addr()
+}
+
+// CHECK-LABEL: @align_down
+#[no_mangle]
+pub fn align_down(p: *mut u8) -> *mut u8 {
+    // CHECK: call {{.*}} @llvm.cheri.cap.address.set
+    // CHECK-NOT: inttoptr
+    p.map_addr(|a| a & !15)
+}
@@ -0,0 +1,73 @@
+// Check which borrows get narrowed bounds under `-Z cheri-subobject-bounds`.
+
+// revisions: safe aggressive off
+// compile-flags: -C no-prepopulate-passes --target morello-unknown-none-purecap
+//[safe] compile-flags: -Z cheri-subobject-bounds=safe
+//[aggressive] compile-flags: -Z cheri-subobject-bounds=aggressive
+//[off] compile-flags: -Z cheri-subobject-bounds=off
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, raw_ref_op)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+pub struct Pair {
+    a: u32,
+    b: u64,
+}
+
+#[repr(C)]
+pub struct Header {
+    tag: u32,
+    len: u32,
+}
+
+// CHECK-LABEL: @field_ref
+#[no_mangle]
+pub fn field_ref(p: &Pair) -> &u64 {
+    // safe: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 8)
+    // aggressive: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 8)
+    // off-NOT: @llvm.cheri.cap.bounds.set
+    &p.b
+}
+
+// CHECK-LABEL: @element_ref
+#[no_mangle]
+pub fn element_ref(a: &[u16; 4]) -> &u16 {
+    // safe: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 2)
+    // aggressive: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 2)
+    // off-NOT: @llvm.cheri.cap.bounds.set
+    &a[1]
+}
+
+// CHECK-LABEL: @field_raw
+#[no_mangle]
+pub fn field_raw(p: &Pair) -> *const u64 {
+    // safe-NOT: @llvm.cheri.cap.bounds.set
+    // aggressive: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 8)
+    // off-NOT: @llvm.cheri.cap.bounds.set
+    &raw const p.b
+}
+
+// CHECK-LABEL: @repr_c_header
+#[no_mangle]
+pub fn repr_c_header(h: &Header) -> &u32 {
+    // safe-NOT: @llvm.cheri.cap.bounds.set
+    // aggressive: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 4)
+    // off-NOT: @llvm.cheri.cap.bounds.set
+    &h.tag
+}
+
+// CHECK-LABEL: @repr_c_later_field
+#[no_mangle]
+pub fn repr_c_later_field(h: &Header) -> &u32 {
+    // safe: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 4)
+    // aggressive: call ptr addrspace(200) @llvm.cheri.cap.bounds.set.i64(ptr addrspace(200) %{{.*}}, i64 4)
+    // off-NOT: @llvm.cheri.cap.bounds.set
+    &h.len
+}
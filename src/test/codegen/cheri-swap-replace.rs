@@ -0,0 +1,58 @@
+// Check that `mem::swap` and `mem::replace` move pointer-containing values as capabilities on
+// purecap targets, whatever their size, so that swapping never clears tags.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+
+use std::mem;
+
+pub struct Pair {
+    buf: *const u8,
+    len: usize,
+}
+
+pub struct Big {
+    ptrs: [*const u8; 8],
+    lens: [usize; 8],
+}
+
+// CHECK-LABEL: @swap_ptr
+#[no_mangle]
+pub fn swap_ptr(x: &mut *const u8, y: &mut *const u8) {
+    // CHECK-NOT: load i64
+    // CHECK-NOT: load i128
+    // CHECK: load ptr addrspace(200)
+    // CHECK: store ptr addrspace(200)
+    mem::swap(x, y)
+}
+
+// CHECK-LABEL: @swap_pair
+#[no_mangle]
+pub fn swap_pair(x: &mut Pair, y: &mut Pair) {
+    // CHECK-NOT: load i128
+    // CHECK-NOT: load <{{[0-9]+}} x i64>
+    // CHECK: load ptr addrspace(200)
+    mem::swap(x, y)
+}
+
+// CHECK-LABEL: @swap_big
+#[no_mangle]
+pub fn swap_big(x: &mut Big, y: &mut Big) {
+    // CHECK-NOT: load i64
+    // CHECK-NOT: load i128
+    // CHECK-NOT: load <{{[0-9]+}} x i64>
+    // CHECK: load {{.*}}ptr addrspace(200)
+    // CHECK: ret void
+    mem::swap(x, y)
+}
+
+// CHECK-LABEL: @replace_big
+#[no_mangle]
+pub fn replace_big(x: &mut Big, v: Big) -> Big {
+    // CHECK-NOT: load i128
+    // CHECK-NOT: load <{{[0-9]+}} x i64>
+    // CHECK-NOT: call void @llvm.memcpy{{.*}}no_preserve_cheri_tags
+    mem::replace(x, v)
+}
@@ -0,0 +1,18 @@
+// Check that purecap modules record their ABI in the `target-abi` module flag, like CHERI clang, so
+// that LTO refuses to link them with bitcode built for another ABI.
+
+// revisions: MORELLO RISCV
+//[MORELLO] compile-flags: --target morello-unknown-none-purecap
+//[MORELLO] needs-llvm-components: aarch64
+//[RISCV] compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+//[RISCV] needs-llvm-components: riscv
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// MORELLO: !{{[0-9]+}} = !{i32 1, !"target-abi", !"purecap"}
+// RISCV: !{{[0-9]+}} = !{i32 1, !"target-abi", !"il32pc64"}
@@ -0,0 +1,36 @@
+// Test that `#[thread_local]` statics on CheriBSD purecap are TLS globals in the capability
+// address space, and that `-Z tls-model=local-dynamic` falls back to general-dynamic, since the
+// Morello ABI has no local-dynamic relocations.
+
+// revisions: default local_dynamic initial_exec
+// compile-flags: --target morello-unknown-freebsd-purecap -Copt-level=1
+// [local_dynamic] compile-flags: -Ztls-model=local-dynamic
+// [initial_exec] compile-flags: -Ztls-model=initial-exec
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, thread_local)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "sync"]
+trait Sync {}
+
+impl Copy for u32 {}
+impl Sync for u32 {}
+
+// default: @[[COUNTER:.*COUNTER.*]] = internal thread_local addrspace(200) global
+// local_dynamic: @[[COUNTER:.*COUNTER.*]] = internal thread_local addrspace(200) global
+// initial_exec: @[[COUNTER:.*COUNTER.*]] = internal thread_local(initialexec) addrspace(200) global
+#[thread_local]
+static mut COUNTER: u32 = 0;
+
+// CHECK-LABEL: @set
+// CHECK: store i32 %x, ptr addrspace(200) @[[COUNTER]]
+#[no_mangle]
+pub unsafe fn set(x: u32) {
+    COUNTER = x;
+}
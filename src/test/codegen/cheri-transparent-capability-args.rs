@@ -0,0 +1,113 @@
+// Check that `#[repr(transparent)]` wrappers around pointers are passed and returned exactly like
+// the capability they wrap on purecap targets, with both the C and the Rust ABI, so that typed
+// handles can be used in FFI signatures without changing the calling convention.
+
+// revisions: MORELLO RISCV
+//[MORELLO] compile-flags: --target morello-unknown-none-purecap
+//[MORELLO] needs-llvm-components: aarch64
+//[RISCV] compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+//[RISCV] needs-llvm-components: riscv
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+// Like `core::ptr::NonNull`.
+#[repr(transparent)]
+#[rustc_layout_scalar_valid_range_start(1)]
+#[rustc_nonnull_optimization_guaranteed]
+pub struct NonNull<T: ?Sized>(*const T);
+
+pub enum Option<T> {
+    None,
+    Some(T),
+}
+
+#[repr(transparent)]
+pub struct Handle(*mut u8);
+
+#[repr(transparent)]
+pub struct Typed<T>(NonNull<T>, PhantomData<T>);
+
+#[repr(transparent)]
+pub struct Nested(Typed<u32>);
+
+#[repr(C)]
+pub struct Inner {
+    ptr: *const u8,
+}
+
+#[repr(transparent)]
+pub struct OverC(Inner, PhantomData<u8>);
+
+// CHECK-LABEL: @c_handle
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%h)
+#[no_mangle]
+pub extern "C" fn c_handle(h: Handle) -> Handle {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// CHECK-LABEL: @c_typed
+// CHECK-SAME: (ptr addrspace(200) {{.*}}nonnull{{.*}} %h)
+#[no_mangle]
+pub extern "C" fn c_typed(h: Typed<u32>) -> Typed<u32> {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// CHECK-LABEL: @c_nested
+// CHECK-SAME: (ptr addrspace(200) {{.*}}nonnull{{.*}} %h)
+#[no_mangle]
+pub extern "C" fn c_nested(h: Nested) -> Nested {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// The niche of the wrapped `NonNull` makes the option a nullable capability.
+// CHECK-LABEL: @c_option
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%h)
+#[no_mangle]
+pub extern "C" fn c_option(h: Option<Typed<u32>>) -> Option<Typed<u32>> {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// CHECK-LABEL: @c_over_c
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%h)
+#[no_mangle]
+pub extern "C" fn c_over_c(h: OverC) -> OverC {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// CHECK-LABEL: @rust_handle
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%h)
+#[no_mangle]
+pub fn rust_handle(h: Handle) -> Handle {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// CHECK-LABEL: @rust_nested
+// CHECK-SAME: (ptr addrspace(200) {{.*}}nonnull{{.*}} %h)
+#[no_mangle]
+pub fn rust_nested(h: Nested) -> Nested {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
+
+// CHECK-LABEL: @rust_over_c
+// CHECK-SAME: (ptr addrspace(200) {{.*}}%h)
+#[no_mangle]
+pub fn rust_over_c(h: OverC) -> OverC {
+    // CHECK: ret ptr addrspace(200) %h
+    h
+}
@@ -0,0 +1,42 @@
+// Check that writing the integer field of a union that overlaps a capability is a plain integer
+// store, which clears the tag of the capability in memory, and that copies of such unions keep
+// the tag of a capability written through the pointer field.
+
+// only-morello+c64
+// compile-flags: -Cno-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union PtrOrAddr {
+    ptr: *const u8,
+    addr: usize,
+}
+
+// CHECK-LABEL: @write_addr_read_ptr
+#[no_mangle]
+pub unsafe fn write_addr_read_ptr(u: &mut PtrOrAddr, addr: usize) -> *const u8 {
+    // CHECK: store i64 %addr, ptr addrspace(200) %u
+    // CHECK: load ptr addrspace(200), ptr addrspace(200) %u
+    u.addr = addr;
+    u.ptr
+}
+
+// CHECK-LABEL: @write_ptr_read_addr
+#[no_mangle]
+pub unsafe fn write_ptr_read_addr(u: &mut PtrOrAddr, ptr: *const u8) -> usize {
+    // CHECK: store ptr addrspace(200) %ptr, ptr addrspace(200) %u
+    // CHECK: load i64, ptr addrspace(200) %u
+    u.ptr = ptr;
+    u.addr
+}
+
+// CHECK-LABEL: @copy_union
+#[no_mangle]
+pub fn copy_union(src: &PtrOrAddr, dst: &mut PtrOrAddr) {
+    // CHECK: call void @llvm.memcpy{{.*}} [[MUST:#[0-9]+]]
+    *dst = *src;
+}
+
+// CHECK: attributes [[MUST]] = {{.*}}must_preserve_cheri_tags
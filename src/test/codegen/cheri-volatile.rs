@@ -0,0 +1,47 @@
+// Check that volatile accesses to values containing pointers keep capability tags on purecap
+// targets, rather than being split into integer loads and stores.
+
+// only-morello+c64
+// compile-flags: -O
+
+#![crate_type = "lib"]
+
+use std::ptr;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Descriptor {
+    buf: *mut u8,
+    len: usize,
+}
+
+// CHECK-LABEL: @read_ptr
+#[no_mangle]
+pub unsafe fn read_ptr(p: *const *mut u8) -> *mut u8 {
+    // CHECK: load volatile ptr addrspace(200), ptr addrspace(200) %p, align 16
+    ptr::read_volatile(p)
+}
+
+// CHECK-LABEL: @write_ptr
+#[no_mangle]
+pub unsafe fn write_ptr(p: *mut *mut u8, v: *mut u8) {
+    // CHECK: store volatile ptr addrspace(200) %v, ptr addrspace(200) %p, align 16
+    ptr::write_volatile(p, v)
+}
+
+// CHECK-LABEL: @read_descriptor
+#[no_mangle]
+pub unsafe fn read_descriptor(p: *const Descriptor) -> Descriptor {
+    // CHECK-NOT: load volatile i128
+    // CHECK-NOT: load volatile [2 x i64]
+    // CHECK: load volatile {{.*}}ptr addrspace(200){{.*}}, ptr addrspace(200) %p, align 16
+    ptr::read_volatile(p)
+}
+
+// CHECK-LABEL: @write_descriptor
+#[no_mangle]
+pub unsafe fn write_descriptor(p: *mut Descriptor, v: Descriptor) {
+    // CHECK-NOT: store volatile i128
+    // CHECK: store volatile ptr addrspace(200)
+    ptr::write_volatile(p, v)
+}
@@ -0,0 +1,20 @@
+// Check the default code models of the purecap targets, and that `-C code-model` overrides them.
+
+// revisions: MORELLO MORELLO-TINY RISCV
+//[MORELLO] compile-flags: --target morello-unknown-freebsd-purecap
+//[MORELLO] needs-llvm-components: aarch64
+//[MORELLO-TINY] compile-flags: --target morello-unknown-freebsd-purecap -C code-model=tiny
+//[MORELLO-TINY] needs-llvm-components: aarch64
+//[RISCV] compile-flags: --target riscv32imcxcheri-unknown-none-purecap
+//[RISCV] needs-llvm-components: riscv
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// MORELLO: !{{[0-9]+}} = !{i32 1, !"Code Model", i32 1}
+// MORELLO-TINY: !{{[0-9]+}} = !{i32 1, !"Code Model", i32 0}
+// RISCV: !{{[0-9]+}} = !{i32 1, !"Code Model", i32 3}
@@ -0,0 +1,27 @@
+// ignore-tidy-linelength
+//
+// Check that `-Zcheri-debuginfo-members` describes thin pointers as structs exposing the
+// uncompressed fields of the capability metadata, and leaves fat pointers alone.
+
+// compile-flags: -g -Cno-prepopulate-passes -Zcheri-debuginfo-members
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// CHECK-DAG: [[PTR:![0-9]+]] = !DICompositeType(tag: DW_TAG_structure_type, name: "*const u8", {{.*}}size: 128, align: 128, {{.*}}elements: [[MEMBERS:![0-9]+]]
+// CHECK-DAG: [[MEMBERS]] = !{[[POINTER:![0-9]+]], [[FLAGS:![0-9]+]], [[OTYPE:![0-9]+]], [[PERMS:![0-9]+]]}
+// CHECK-DAG: [[POINTER]] = !DIDerivedType(tag: DW_TAG_member, name: "pointer", scope: [[PTR]], {{.*}}baseType: [[INNER:![0-9]+]], size: 128, align: 128)
+// CHECK-DAG: [[INNER]] = !DIDerivedType(tag: DW_TAG_pointer_type, baseType: {{.*}}, size: 128, align: 128
+// CHECK-DAG: [[FLAGS]] = !DIDerivedType(tag: DW_TAG_member, name: "flags", scope: [[PTR]], {{.*}}size: 8, offset: 56, flags: DIFlagArtificial | DIFlagBitField, extraData: i64 0)
+// CHECK-DAG: [[OTYPE]] = !DIDerivedType(tag: DW_TAG_member, name: "otype", scope: [[PTR]], {{.*}}size: 15, offset: 95, flags: DIFlagArtificial | DIFlagBitField, extraData: i64 64)
+// CHECK-DAG: [[PERMS]] = !DIDerivedType(tag: DW_TAG_member, name: "perms", scope: [[PTR]], {{.*}}size: 18, offset: 110, flags: DIFlagArtificial | DIFlagBitField, extraData: i64 64)
+// CHECK-DAG: !DICompositeType(tag: DW_TAG_structure_type, name: "&u32", {{.*}}size: 128, align: 128
+// CHECK-DAG: !DICompositeType(tag: DW_TAG_structure_type, name: "&[u8]", {{.*}}size: 256, align: 128
+#[no_mangle]
+pub fn pointers(_p: *const u8, _r: &u32, _s: &[u8]) {}
@@ -0,0 +1,25 @@
+// ignore-tidy-linelength
+//
+// Check that pointers are described as capabilities in the debuginfo for purecap targets, so that
+// debuggers read all of their bits instead of just the address. They are given the capability
+// address space, which the backend turns into capability-sized location descriptions.
+
+// compile-flags: -g -Cno-prepopulate-passes --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// CHECK-DAG: !DIDerivedType(tag: DW_TAG_pointer_type, name: "*const u8", baseType: {{.*}}, size: 128, align: 128{{.*}}dwarfAddressSpace: 200
+// CHECK-DAG: !DIDerivedType(tag: DW_TAG_pointer_type, name: "&u32", baseType: {{.*}}, size: 128, align: 128{{.*}}dwarfAddressSpace: 200
+// CHECK-DAG: !DIDerivedType(tag: DW_TAG_pointer_type, name: "fn()", baseType: {{.*}}, size: 128, align: 128{{.*}}dwarfAddressSpace: 200
+// CHECK-DAG: !DICompositeType(tag: DW_TAG_structure_type, name: "&[u8]", {{.*}}size: 256, align: 128
+// CHECK-DAG: !DIDerivedType(tag: DW_TAG_member, name: "data_ptr", {{.*}}size: 128, align: 128)
+// CHECK-DAG: !DIDerivedType(tag: DW_TAG_pointer_type, baseType: {{.*}}, size: 128, align: 128, dwarfAddressSpace: 200)
+// CHECK-DAG: !DIDerivedType(tag: DW_TAG_member, name: "length", {{.*}}size: 64, align: 64, offset: 128)
+#[no_mangle]
+pub fn pointers(_p: *const u8, _r: &u32, _s: &[u8], _f: fn()) {}
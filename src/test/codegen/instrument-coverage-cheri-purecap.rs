@@ -0,0 +1,22 @@
+// Test that `-Cinstrument-coverage` on CheriBSD purecap increments the counters through the
+// capability address space overload of `llvm.instrprof.increment`, and that the coverage mapping
+// is emitted alongside the other globals.
+
+// compile-flags: --target morello-unknown-freebsd-purecap -Cinstrument-coverage
+// compile-flags: -Cno-prepopulate-passes -Zno-profiler-runtime
+// needs-llvm-components: aarch64
+
+// CHECK: @__llvm_coverage_mapping = private {{.*}}addrspace(200) constant
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// CHECK-LABEL: @some_function
+// CHECK: call void @llvm.instrprof.increment.p200i8(ptr addrspace(200) @__profn_{{.*}}, i64
+#[no_mangle]
+#[inline(never)]
+pub fn some_function() {}
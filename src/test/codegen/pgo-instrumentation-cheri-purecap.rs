@@ -0,0 +1,30 @@
+// ignore-tidy-linelength
+
+// Test that `-Cprofile-generate` on CheriBSD purecap emits the counters and data records in the
+// capability address space, and that the data records refer to the instrumented functions through
+// capabilities, which the linker then emits capability relocations for.
+
+// compile-flags: --target morello-unknown-freebsd-purecap -Cprofile-generate -Ccodegen-units=1
+// compile-flags: -Zno-profiler-runtime
+// needs-llvm-components: aarch64
+
+// CHECK: @__llvm_profile_raw_version = {{.*}}addrspace(200)
+// CHECK-DAG: @__profc_{{.*}}some_function{{.*}} = {{.*}}addrspace(200) global
+// CHECK-DAG: @__profd_{{.*}}some_function{{.*}} = {{.*}}addrspace(200) global {{.*}}ptr addrspace(200) @some_function
+// CHECK: @__llvm_profile_filename = {{.*}}"default_%m.profraw\00"{{.*}}
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[no_mangle]
+#[inline(never)]
+pub fn some_function() {}
+
+#[no_mangle]
+pub fn some_other_function() {
+    some_function();
+}
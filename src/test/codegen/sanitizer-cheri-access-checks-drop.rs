@@ -0,0 +1,59 @@
+// ignore-tidy-linelength
+
+// Verifies that `-Zsanitizer=cheri` checks the places that terminators access through raw
+// pointers, here the field that the drop glue of `Outer` drops through `*mut Outer`.
+//
+// compile-flags: -Cno-prepopulate-passes -Zsanitizer=cheri --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u32 {}
+
+#[lang = "drop"]
+trait Drop {
+    fn drop(&mut self);
+}
+
+#[lang = "drop_in_place"]
+#[allow(unconditional_recursion)]
+unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+    drop_in_place(to_drop)
+}
+
+#[lang = "panic_location"]
+struct Location<'a> {
+    file: &'a str,
+    line: u32,
+    col: u32,
+}
+
+#[lang = "cheri_check_access"]
+#[track_caller]
+fn check_access(_ptr: *const u8, _len: usize, _store: bool) {}
+
+pub struct Inner(u32);
+
+impl Drop for Inner {
+    fn drop(&mut self) {}
+}
+
+pub struct Outer {
+    inner: Inner,
+}
+
+#[no_mangle]
+pub unsafe fn drop_outer(p: *mut Outer) {
+    drop_in_place(p)
+}
+
+// CHECK-LABEL: define {{.*}}drop_in_place{{.*}}Outer
+// CHECK: call void @{{.*}}check_access{{.*}}(ptr addrspace(200) {{.*}}, i64 {{.*}}4, i1 {{.*}}true, ptr addrspace(200)
+// CHECK-NEXT: call void @{{.*}}drop_in_place{{.*}}Inner
@@ -0,0 +1,78 @@
+// ignore-tidy-linelength
+
+// Verifies that `-Zsanitizer=cheri` checks accesses through raw pointers against their
+// capabilities, and leaves references and `#[no_sanitize(cheri)]` functions alone.
+//
+// compile-flags: -Cno-prepopulate-passes -Zsanitizer=cheri --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, no_sanitize)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u32 {}
+impl Copy for u64 {}
+
+#[lang = "panic_location"]
+struct Location<'a> {
+    file: &'a str,
+    line: u32,
+    col: u32,
+}
+
+#[lang = "cheri_check_access"]
+#[track_caller]
+fn check_access(_ptr: *const u8, _len: usize, _store: bool) {}
+
+#[repr(C)]
+pub struct Pair {
+    a: u32,
+    b: u64,
+}
+
+// CHECK-LABEL: @load
+#[no_mangle]
+pub unsafe fn load(p: *const u32) -> u32 {
+    // CHECK: call void @{{.*}}check_access{{.*}}(ptr addrspace(200) {{.*}}%p, i64 {{.*}}4, i1 {{.*}}false, ptr addrspace(200)
+    // CHECK-NEXT: load i32, ptr addrspace(200) %p
+    *p
+}
+
+// CHECK-LABEL: @store
+#[no_mangle]
+pub unsafe fn store(p: *mut u32, x: u32) {
+    // CHECK: call void @{{.*}}check_access{{.*}}(ptr addrspace(200) {{.*}}%p, i64 {{.*}}4, i1 {{.*}}true, ptr addrspace(200)
+    // CHECK-NEXT: store i32 %x, ptr addrspace(200) %p
+    *p = x;
+}
+
+// Only the accessed field is checked, through the capability derived for it.
+// CHECK-LABEL: @field
+#[no_mangle]
+pub unsafe fn field(p: *const Pair) -> u64 {
+    // CHECK: [[FIELD:%.*]] = getelementptr inbounds %Pair, ptr addrspace(200) %p, i32 0, i32 1
+    // CHECK: call void @{{.*}}check_access{{.*}}(ptr addrspace(200) {{.*}}[[FIELD]], i64 {{.*}}8, i1 {{.*}}false
+    (*p).b
+}
+
+// CHECK-LABEL: @reference
+#[no_mangle]
+pub fn reference(r: &u32) -> u32 {
+    // CHECK-NOT: check_access
+    // CHECK: ret i32
+    *r
+}
+
+// CHECK-LABEL: @unsanitized
+#[no_mangle]
+#[no_sanitize(cheri)]
+pub unsafe fn unsanitized(p: *const u32) -> u32 {
+    // CHECK-NOT: check_access
+    // CHECK: ret i32
+    *p
+}
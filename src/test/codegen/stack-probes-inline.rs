@@ -2,7 +2,7 @@
 // or `StackProbeType::InlineOrCall` when running on newer LLVM.
 
 // compile-flags: -C no-prepopulate-passes
-// revisions: powerpc powerpc64 powerpc64le s390x i686 x86_64
+// revisions: powerpc powerpc64 powerpc64le s390x i686 x86_64 morello
 //[powerpc] compile-flags: --target powerpc-unknown-linux-gnu
 //[powerpc] needs-llvm-components: powerpc
 //[powerpc64] compile-flags: --target powerpc64-unknown-linux-gnu
@@ -17,6 +17,8 @@
 //[x86_64] compile-flags: --target x86_64-unknown-linux-gnu
 //[x86_64] needs-llvm-components: x86
 //[x86_64] min-llvm-version: 16
+//[morello] compile-flags: --target morello-unknown-freebsd-purecap
+//[morello] needs-llvm-components: aarch64
 
 #![crate_type = "rlib"]
 #![feature(no_core, lang_items)]
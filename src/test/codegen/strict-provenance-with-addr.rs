@@ -0,0 +1,16 @@
+// Check that `with_addr` offsets the original pointer rather than casting the new address back
+// to a pointer, so that LLVM keeps its provenance.
+
+// compile-flags: -O
+// ignore-cheri
+
+#![crate_type = "lib"]
+#![feature(strict_provenance)]
+
+// CHECK-LABEL: @align_down
+#[no_mangle]
+pub fn align_down(p: *mut u8) -> *mut u8 {
+    // CHECK: getelementptr i8
+    // CHECK-NOT: inttoptr
+    p.map_addr(|a| a & !15)
+}
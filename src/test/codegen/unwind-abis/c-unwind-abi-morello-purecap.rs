@@ -0,0 +1,49 @@
+// compile-flags: -C no-prepopulate-passes --target morello-unknown-freebsd-purecap -C panic=unwind
+// needs-llvm-components: aarch64
+
+// Test that calls to `extern "C-unwind"` functions on Morello purecap are emitted as `invoke`s,
+// and that the landing pads receive the exception object as a capability.
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, c_unwind)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "drop"]
+trait Drop {
+    fn drop(&mut self);
+}
+#[lang = "drop_in_place"]
+#[allow(unconditional_recursion)]
+pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+    drop_in_place(to_drop)
+}
+#[lang = "eh_personality"]
+fn eh_personality() {}
+
+extern "C-unwind" {
+    fn may_unwind();
+    fn release();
+}
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        unsafe { release() }
+    }
+}
+
+// CHECK-LABEL: @rust_item_that_can_unwind
+#[no_mangle]
+pub unsafe extern "C-unwind" fn rust_item_that_can_unwind() {
+    let _guard = Guard;
+    // CHECK: invoke void @may_unwind()
+    // CHECK-NEXT: to label %{{.*}} unwind label %[[CLEANUP:.*]]
+    may_unwind();
+    // CHECK: [[CLEANUP]]:
+    // CHECK-NEXT: landingpad { ptr addrspace(200), i32 }
+    // CHECK-NEXT: cleanup
+    // CHECK: resume { ptr addrspace(200), i32 }
+}
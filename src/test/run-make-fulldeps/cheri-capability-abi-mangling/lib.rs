@@ -0,0 +1,14 @@
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[inline(never)]
+pub fn mangled() {}
+
+#[no_mangle]
+pub fn unmangled() {
+    mangled();
+}
@@ -0,0 +1,30 @@
+#![feature(no_core, lang_items, cheri_capability)]
+#![no_core]
+#![allow(clashing_extern_declarations)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+#[cheri_capability]
+pub struct Capability<T>(PhantomData<T>);
+
+mod plain {
+    extern "C" {
+        pub fn consume(p: *mut u8);
+    }
+}
+
+mod capability {
+    extern "C" {
+        pub fn consume(p: super::Capability<u8>);
+    }
+}
+
+pub unsafe fn both(p: *mut u8, c: Capability<u8>) {
+    plain::consume(p);
+    capability::consume(c);
+}
@@ -0,0 +1,31 @@
+#![feature(no_core, lang_items, cheri_capability)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+#[cheri_capability]
+pub struct Capability<T>(PhantomData<T>);
+
+impl<T> Copy for Capability<T> {}
+
+#[repr(C)]
+pub struct Pair {
+    tag: u8,
+    cap: Capability<u8>,
+    ptr: *const u8,
+}
+
+#[no_mangle]
+pub extern "C" fn id(c: Capability<u8>) -> Capability<u8> {
+    c
+}
+
+#[no_mangle]
+pub extern "C" fn cap_of(p: &Pair) -> Capability<u8> {
+    p.cap
+}
@@ -0,0 +1,36 @@
+#![feature(no_core, lang_items, rustc_attrs, cheri_capability)]
+#![no_core]
+#![deny(cheri_unqualified_fn_pointers)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+#[rustc_diagnostic_item = "Option"]
+pub enum Option<T> {
+    #[lang = "None"]
+    None,
+    #[lang = "Some"]
+    Some(T),
+}
+
+#[cheri_capability]
+pub struct Capability<T>(PhantomData<T>);
+
+extern "C" {
+    pub fn plain_callback(cb: extern "C" fn(i32));
+    pub fn optional_callback(cb: Option<extern "C" fn(i32)>);
+    pub fn qualified_callback(cb: Capability<extern "C" fn(i32)>);
+}
+
+pub extern "C" fn on_event(_: i32) {}
+
+pub unsafe fn register(cb: extern "C" fn(i32), registered: Capability<extern "C" fn(i32)>) {
+    plain_callback(on_event);
+    plain_callback(cb);
+    optional_callback(Option::Some(on_event));
+    // Nothing is passed, and capabilities are fine.
+    optional_callback(Option::None);
+    qualified_callback(registered);
+}
@@ -0,0 +1,20 @@
+#![feature(no_core, lang_items, cheri_capability)]
+#![no_core]
+#![deny(cheri_ffi_capability_mismatch)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "phantom_data"]
+pub struct PhantomData<T: ?Sized>;
+
+#[cheri_capability]
+pub struct Capability<T>(PhantomData<T>);
+
+extern "C" {
+    // Only plain pointers, as if none of them were qualified in C.
+    pub fn plain(dst: *mut u8, src: *const u8);
+    // Only capabilities.
+    pub fn qualified(dst: Capability<u8>, src: Capability<u8>);
+    // Likely `void * __capability dst, const void *src` in C, or a missing qualifier.
+    pub fn mixed(dst: Capability<u8>, src: *const u8);
+}
@@ -0,0 +1,36 @@
+#include <stdint.h>
+
+struct cap_and_int {
+    void *ptr;
+    uint64_t len;
+};
+
+struct two_caps {
+    void *a;
+    void *b;
+};
+
+struct three_caps {
+    void *a;
+    void *b;
+    void *c;
+};
+
+void *pointer(void *p) {
+    return p;
+}
+
+struct cap_and_int cap_and_int(void *ptr, uint64_t len) {
+    struct cap_and_int r = { ptr, len };
+    return r;
+}
+
+struct two_caps two_caps(void *a, void *b) {
+    struct two_caps r = { a, b };
+    return r;
+}
+
+struct three_caps three_caps(void *a, void *b, void *c) {
+    struct three_caps r = { a, b, c };
+    return r;
+}
@@ -0,0 +1,44 @@
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[repr(C)]
+pub struct CapAndInt {
+    ptr: *mut u8,
+    len: u64,
+}
+
+#[repr(C)]
+pub struct TwoCaps {
+    a: *mut u8,
+    b: *mut u8,
+}
+
+#[repr(C)]
+pub struct ThreeCaps {
+    a: *mut u8,
+    b: *mut u8,
+    c: *mut u8,
+}
+
+#[no_mangle]
+pub extern "C" fn pointer(p: *mut u8) -> *mut u8 {
+    p
+}
+
+#[no_mangle]
+pub extern "C" fn cap_and_int(ptr: *mut u8, len: u64) -> CapAndInt {
+    CapAndInt { ptr, len }
+}
+
+#[no_mangle]
+pub extern "C" fn two_caps(a: *mut u8, b: *mut u8) -> TwoCaps {
+    TwoCaps { a, b }
+}
+
+#[no_mangle]
+pub extern "C" fn three_caps(a: *mut u8, b: *mut u8, c: *mut u8) -> ThreeCaps {
+    ThreeCaps { a, b, c }
+}
@@ -0,0 +1,10 @@
+#![feature(no_core)]
+#![crate_type = "staticlib"]
+#![no_core]
+
+extern crate upstream;
+
+#[no_mangle]
+pub unsafe extern "C" fn relocate_pointers(src: *const *mut u8, dst: *mut *mut u8, count: usize) {
+    upstream::relocate(src, dst, count)
+}
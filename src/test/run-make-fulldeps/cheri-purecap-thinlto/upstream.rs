@@ -0,0 +1,15 @@
+#![feature(no_core, lang_items, intrinsics)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+pub trait Sized {}
+
+extern "rust-intrinsic" {
+    fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize);
+}
+
+// The copy has to keep the tags of the pointers it moves, wherever it ends up being inlined.
+pub unsafe fn relocate(src: *const *mut u8, dst: *mut *mut u8, count: usize) {
+    copy_nonoverlapping(src, dst, count)
+}
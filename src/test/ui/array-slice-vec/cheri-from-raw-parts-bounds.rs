@@ -0,0 +1,22 @@
+// Check that `slice::from_raw_parts` panics when debug assertions are enabled if the bounds of the
+// capability it is given don't cover the whole slice.
+
+// run-fail
+// only-morello+c64
+// only-debug
+// error-pattern: slice::from_raw_parts requires a tagged capability whose bounds cover the slice
+
+#![feature(cheri_intrinsics)]
+
+use std::arch::aarch64::cheri::cheri_bounds_set;
+use std::hint::black_box;
+
+fn main() {
+    let array = [0u32; 8];
+    let short = cheri_bounds_set(array.as_ptr(), 4 * 4);
+    // The first four elements are within bounds...
+    let slice = unsafe { std::slice::from_raw_parts(short, black_box(4)) };
+    assert_eq!(slice.len(), 4);
+    // ... but eight are not.
+    let _ = unsafe { std::slice::from_raw_parts(short, black_box(8)) };
+}
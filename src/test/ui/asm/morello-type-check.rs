@@ -0,0 +1,30 @@
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+// needs-asm-support
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+#![crate_type = "rlib"]
+
+#[rustc_builtin_macro]
+macro_rules! asm {
+    () => {};
+}
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u64 {}
+impl Copy for *mut u8 {}
+
+pub unsafe fn foo(p: *mut u8) {
+    // Capabilities need a capability register.
+    asm!("{}", in(creg) p);
+    asm!("{}", in(reg) p);
+    //~^ ERROR type `*mut u8` cannot be used with this register class
+
+    // Integers cannot be passed in capability registers.
+    asm!("{}", in(creg) 0u64);
+    //~^ ERROR type `u64` cannot be used with this register class
+}
@@ -0,0 +1,31 @@
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+// needs-asm-support
+
+#![feature(no_core, lang_items, rustc_attrs, naked_functions)]
+#![no_core]
+#![crate_type = "rlib"]
+
+#[rustc_builtin_macro]
+macro_rules! asm {
+    () => {};
+}
+#[lang = "sized"]
+trait Sized {}
+
+#[naked]
+pub unsafe extern "C" fn good() {
+    asm!("ldr c0, [csp]", "blr c1", "ret", options(noreturn))
+}
+
+#[naked]
+pub unsafe extern "C" fn integer_stack_pointer() {
+    asm!("ldr x0, [sp]", "ret", options(noreturn))
+    //~^ ERROR naked functions on purecap targets must use `csp` rather than `sp`
+}
+
+#[naked]
+pub unsafe extern "C" fn integer_return() {
+    asm!("1: ret x30", options(noreturn))
+    //~^ ERROR naked functions on purecap targets must branch through capability registers
+}
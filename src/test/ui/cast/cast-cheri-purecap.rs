@@ -0,0 +1,26 @@
+// Check that cast errors between pointers and integers explain the capability representation
+// on purecap targets.
+
+// compile-flags: --target morello-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub fn reference_to_int(x: &u8) -> usize {
+    x as usize
+    //~^ ERROR casting `&u8` as `usize` is invalid
+}
+
+pub fn int_to_reference(n: usize) -> &'static u8 {
+    n as &'static u8
+    //~^ ERROR non-primitive cast: `usize` as `&'static u8`
+}
+
+pub fn int_to_fn(n: usize) -> fn() {
+    n as fn()
+    //~^ ERROR non-primitive cast: `usize` as `fn()`
+}
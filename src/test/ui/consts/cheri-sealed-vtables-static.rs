@@ -0,0 +1,33 @@
+// build-fail
+// compile-flags: --target morello-unknown-none-purecap --crate-type=rlib -Z cheri-sealed-vtables
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "sync"]
+trait Sync {}
+impl<T: ?Sized> Sync for T {}
+#[lang = "drop_in_place"]
+#[allow(unconditional_recursion)]
+pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
+    drop_in_place(to_drop)
+}
+
+pub trait Speak {
+    fn speak(&self) -> u32;
+}
+
+impl Speak for u32 {
+    fn speak(&self) -> u32 {
+        *self
+    }
+}
+
+// Trait objects in constants are sealed when the constant is used.
+pub const SPEAKER: &dyn Speak = &7u32;
+
+pub static STATIC_SPEAKER: &dyn Speak = &7u32;
+//~^ ERROR statics can't hold trait objects with `-Z cheri-sealed-vtables`
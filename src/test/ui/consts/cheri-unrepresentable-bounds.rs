@@ -0,0 +1,26 @@
+// check-pass
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "sync"]
+trait Sync {}
+impl<T: ?Sized> Sync for T {}
+
+// Capabilities are 64 bits wide, and can only have exact bounds on objects of up to 64 bytes.
+static TABLE: [u8; 100] = [0; 100];
+static EXACT: [u8; 60] = [0; 60];
+static ALIGNED: [u64; 13] = [0; 13];
+
+pub static TABLE_REF: &[u8; 100] = &TABLE;
+//~^ WARNING the capability of a pointer in this static can't have the bounds of the allocation
+
+pub const TABLE_CONST: &[u8; 100] = &[0; 100];
+//~^ WARNING the capability of a pointer in this constant can't have the bounds of the allocation
+
+pub static EXACT_REF: &[u8; 60] = &EXACT;
+pub static ALIGNED_REF: &[u64; 13] = &ALIGNED;
@@ -0,0 +1,39 @@
+// Check that the capability-query intrinsics give the same results in const contexts as they do
+// at runtime.
+
+// run-pass
+// only-morello+c64
+
+#![feature(cheri_intrinsics, const_cheri_intrinsics, layout_to_representable)]
+
+use std::alloc::Layout;
+use std::arch::aarch64::cheri::*;
+use std::hint::black_box;
+
+static DATA: [u8; 100_001] = [0; 100_001];
+static DATA_LEN: usize = cheri_length_get(&DATA);
+
+macro_rules! check_representable {
+    ($($len:expr),*) => {$({
+        const LENGTH: usize = cheri_representable_length($len);
+        const MASK: usize = cheri_representable_alignment_mask($len);
+        assert_eq!(LENGTH, cheri_representable_length(black_box($len)), "length of {}", $len);
+        assert_eq!(MASK, cheri_representable_alignment_mask(black_box($len)), "mask of {}", $len);
+    })*};
+}
+
+fn main() {
+    check_representable!(0, 1, 4095, 4096, 16383, 16384, 16385, 100_001, (1 << 20) - 1, 1 << 40);
+
+    assert_eq!(DATA_LEN, cheri_length_get(black_box(&DATA)));
+    assert_eq!(DATA_LEN, cheri_representable_length(DATA.len()));
+
+    const NULL_LEN: usize = cheri_length_get(std::ptr::null::<u8>());
+    assert_eq!(NULL_LEN, usize::MAX);
+
+    const LAYOUT: Layout = match Layout::new::<[u8; 100_001]>().to_representable() {
+        Ok(layout) => layout,
+        Err(_) => panic!(),
+    };
+    assert_eq!(LAYOUT, black_box(Layout::new::<[u8; 100_001]>()).to_representable().unwrap());
+}
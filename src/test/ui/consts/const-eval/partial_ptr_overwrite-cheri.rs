@@ -0,0 +1,42 @@
+// Check that overwriting part of a capability, even a part that doesn't hold the address, clears
+// its tag the way the hardware does. The rest of the capability can't be used afterwards.
+
+// only-morello+c64
+
+#![feature(const_mut_refs)]
+
+const PARTIAL_OVERWRITE: () = {
+    let mut p = &42;
+    unsafe {
+        let ptr: *mut _ = &mut p;
+        *(ptr as *mut u8).add(12) = 123;
+    }
+    let x = *p; //~ ERROR constant
+    //~| using uninitialized data
+};
+
+const OVERWRITE_AGAIN: () = {
+    let mut p = &42;
+    unsafe {
+        let ptr: *mut _ = &mut p;
+        *(ptr as *mut u8).add(12) = 123;
+        // Storing a whole pointer gives a valid capability again.
+        *ptr = &43;
+    }
+    let x = *p;
+};
+
+const PARTIAL_COPY: () = {
+    let p = &42;
+    let mut q = &0;
+    unsafe {
+        let src = &p as *const _ as *const u8;
+        let dest = &mut q as *mut _ as *mut u8;
+        core::ptr::copy_nonoverlapping(src, dest, 8);
+        core::ptr::copy_nonoverlapping(src.add(8), dest.add(8), 8);
+    }
+    let x = *q; //~ ERROR constant
+    //~| using uninitialized data
+};
+
+fn main() {}
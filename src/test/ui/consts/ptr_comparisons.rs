@@ -32,11 +32,11 @@ macro_rules! check {
 check!(ne, unsafe { (FOO as *const usize).offset(1) }, 0);
 check!(ne, unsafe { (FOO as *const usize as *const u8).offset(3) }, 0);
 
-// We want pointers to be equal to themselves, but aren't checking this yet because
-// there are some open questions (e.g. whether function pointers to the same function
-// compare equal, they don't necessarily at runtime).
-// The case tested here should work eventually, but does not work yet.
-check!(!, FOO as *const _, FOO as *const _);
+// Pointers into the same data allocation are compared by their offsets, so a pointer
+// is known to be equal to itself. This does not extend to function pointers to the same
+// function, as they don't necessarily compare equal at runtime.
+// See `ptr_guaranteed_cmp.rs` for more cases.
+check!(eq, FOO as *const _, FOO as *const _);
 
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,67 @@
+// Check which pointer comparisons have a known result at compile time. Every known result must
+// agree with the runtime comparison, which on CHERI targets compares addresses only.
+
+// check-pass
+
+#![feature(core_intrinsics, const_raw_ptr_comparison)]
+
+use std::intrinsics::ptr_guaranteed_cmp;
+
+const ARRAY: &[u8; 4] = &[1, 2, 3, 4];
+
+static A: u8 = 0;
+static B: u8 = 0;
+
+fn f() {}
+
+macro_rules! check {
+    ($expected:literal, $a:expr, $b:expr) => {
+        const _: () = assert!(ptr_guaranteed_cmp($a as *const u8, $b as *const u8) == $expected);
+    };
+    (static $expected:literal, $a:expr, $b:expr) => {
+        static _S: () = assert!(ptr_guaranteed_cmp($a as *const u8, $b as *const u8) == $expected);
+    };
+}
+
+// Within one allocation: known, including out-of-bounds and one-past-the-end pointers.
+check!(1, ARRAY.as_ptr(), ARRAY.as_ptr());
+check!(1, ARRAY.as_ptr().wrapping_add(2), ARRAY.as_ptr().wrapping_add(1).wrapping_add(1));
+check!(0, ARRAY.as_ptr(), ARRAY.as_ptr().wrapping_add(1));
+check!(0, ARRAY.as_ptr(), ARRAY.as_ptr().wrapping_add(4));
+check!(0, ARRAY.as_ptr(), ARRAY.as_ptr().wrapping_add(100));
+
+// Different non-static allocations may be deduplicated: unknown.
+check!(2, ARRAY.as_ptr(), (&[1u8, 2, 3, 4]).as_ptr());
+
+// Functions can be duplicated: unknown even against themselves.
+check!(2, f as fn(), f as fn());
+
+mod statics {
+    use super::*;
+
+    // Distinct statics never overlap...
+    check!(static 0, &A, &B);
+}
+
+mod statics_end {
+    use super::*;
+
+    // ... but one past the end of one may be the start of the other.
+    check!(static 2, (&A as *const u8).wrapping_add(1), &B);
+}
+
+extern "C" {
+    #[link_name = "ptr_guaranteed_cmp_shared"]
+    static X: u8;
+    #[link_name = "ptr_guaranteed_cmp_shared"]
+    static Y: u8;
+}
+
+mod extern_statics {
+    use super::*;
+
+    // `extern` statics may be the same symbol under different names.
+    check!(static 2, unsafe { &X }, unsafe { &Y });
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// check-pass
+// compile-flags: --target morello-unknown-freebsd-purecap -g
+// needs-llvm-components: aarch64
+// revisions: packed unpacked
+//[packed] compile-flags: -Csplit-debuginfo=packed
+//[unpacked] compile-flags: -Csplit-debuginfo=unpacked
+//
+// Split debuginfo is stable on CheriBSD, so neither mode needs `-Zunstable-options`.
+
+#![feature(no_core)]
+#![no_core]
+#![crate_type = "rlib"]
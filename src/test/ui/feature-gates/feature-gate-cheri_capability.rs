@@ -0,0 +1,10 @@
+// compile-flags: --crate-type=rlib --target=morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[cheri_capability] //~ ERROR the `#[cheri_capability]` attribute is an experimental feature
+pub struct Capability;
@@ -0,0 +1,16 @@
+// Check that `-Zbranch-protection` is rejected on Morello purecap targets, where sentries already
+// protect return addresses and function pointers.
+
+// revisions: bti pac_ret
+// [bti] compile-flags: -Zbranch-protection=bti
+// [pac_ret] compile-flags: -Zbranch-protection=pac-ret,leaf
+// compile-flags: --target=morello-unknown-linux-purecap
+// check-fail
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang="sized"]
+trait Sized { }
@@ -0,0 +1,45 @@
+// Check that enums only use the null address of a capability as a niche on purecap targets, so
+// that no discriminant is ever encoded in the address, metadata or tag of a pointer.
+
+// check-pass
+// only-morello+c64
+
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+
+use std::mem::size_of;
+use std::ptr::NonNull;
+
+// Would have two niche values, 0 and 1, on other targets.
+#[rustc_layout_scalar_valid_range_start(2)]
+struct NotOne(*const u8);
+
+enum Three {
+    A(&'static u8),
+    B,
+    C,
+}
+
+struct Size<const S: usize>;
+
+macro_rules! check_size {
+    ($ty:ty, $size:expr) => {
+        const _: Size<{ $size }> = Size::<{ size_of::<$ty>() }>;
+    };
+}
+
+check_size!(&u8, 16);
+check_size!(Option<&u8>, 16);
+check_size!(Option<NonNull<u8>>, 16);
+check_size!(Option<Box<u8>>, 16);
+check_size!(Option<fn()>, 16);
+check_size!(Option<&[u8]>, 32);
+check_size!(Option<NotOne>, 16);
+
+// Only one niche value is available, so these need a tag.
+check_size!(Option<Option<&u8>>, 32);
+check_size!(Option<Option<NotOne>>, 32);
+check_size!(Three, 32);
+check_size!(Option<*const u8>, 32);
+
+fn main() {}
@@ -0,0 +1,64 @@
+// check-pass
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items)]
+#![no_core]
+#![allow(cheri_int_to_ptr_cast, cheri_ptr_to_int_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for usize {}
+impl<T: ?Sized> Copy for *const T {}
+
+#[lang = "add"]
+trait Add<Rhs = Self> {
+    type Output;
+    fn add(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Add for usize {
+    type Output = usize;
+    fn add(self, rhs: usize) -> usize {
+        self + rhs
+    }
+}
+
+#[lang = "sub"]
+trait Sub<Rhs = Self> {
+    type Output;
+    fn sub(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Sub for usize {
+    type Output = usize;
+    fn sub(self, rhs: usize) -> usize {
+        self - rhs
+    }
+}
+
+pub fn split(p: *const u32) -> *const u32 {
+    let addr = p as usize;
+    let next = addr + 4;
+    next as *const u32
+    //~^ WARNING pointer arithmetic on the address of a pointer cannot produce a valid capability
+}
+
+pub fn inline(p: *const u8, n: usize) -> *const u8 {
+    (p as usize + n) as *const u8
+    //~^ WARNING pointer arithmetic on the address of a pointer cannot produce a valid capability
+}
+
+pub fn header(p: *const u8) -> *const usize {
+    (p as usize - 8) as *const usize
+    //~^ WARNING pointer arithmetic on the address of a pointer cannot produce a valid capability
+}
+
+// No arithmetic, or no pointer that the address is taken from.
+pub fn not_flagged(p: *const u8, addr: usize) -> (*const u8, *const u8) {
+    let same = p as usize;
+    (same as *const u8, (addr + 1) as *const u8)
+}
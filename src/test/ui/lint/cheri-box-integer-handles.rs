@@ -0,0 +1,59 @@
+// check-pass
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+#![allow(cheri_int_to_ptr_cast, cheri_ptr_to_int_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub struct Box<T>(*mut T);
+
+impl<T> Box<T> {
+    pub fn new(_: T) -> Box<T> {
+        loop {}
+    }
+    #[rustc_diagnostic_item = "box_from_raw"]
+    pub unsafe fn from_raw(raw: *mut T) -> Box<T> {
+        Box(raw)
+    }
+    #[rustc_diagnostic_item = "box_into_raw"]
+    pub fn into_raw(b: Box<T>) -> *mut T {
+        b.0
+    }
+}
+
+pub struct Session;
+
+pub extern "C" fn session_new() -> usize {
+    Box::into_raw(Box::new(Session)) as usize
+    //~^ WARNING turning a `Box` into an integer handle drops its capability
+}
+
+pub unsafe extern "C" fn session_free(handle: usize) {
+    let _session = Box::from_raw(handle as *mut Session);
+    //~^ WARNING rebuilding a `Box` from an integer handle cannot produce a valid capability
+}
+
+pub extern "C" fn session_new_split() -> u64 {
+    let raw = Box::into_raw(Box::new(Session));
+    raw as u64
+    //~^ WARNING turning a `Box` into an integer handle drops its capability
+}
+
+pub unsafe extern "C" fn session_free_split(handle: u64) {
+    let raw = handle as *mut Session;
+    let _session = Box::from_raw(raw);
+    //~^ WARNING rebuilding a `Box` from an integer handle cannot produce a valid capability
+}
+
+// Handing out the pointer itself keeps the capability.
+pub extern "C" fn session_new_opaque() -> *mut Session {
+    Box::into_raw(Box::new(Session))
+}
+
+pub unsafe extern "C" fn session_free_opaque(handle: *mut Session) {
+    let _session = Box::from_raw(handle);
+}
@@ -0,0 +1,9 @@
+// Check that `cheri_int_to_ptr_cast` only fires on purecap targets.
+
+// check-pass
+// ignore-morello+c64
+// compile-flags: --crate-type=rlib
+
+pub fn from_int(addr: usize) -> *const u8 {
+    addr as *const u8
+}
@@ -0,0 +1,46 @@
+// revisions: lint error
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// [error]compile-flags: -Z strict-capability=error
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items)]
+#![no_core]
+#![allow(cheri_ptr_to_int_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "add"]
+trait Add<Rhs = Self> {
+    type Output;
+    fn add(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Copy for usize {}
+impl Add for usize {
+    type Output = usize;
+    fn add(self, rhs: usize) -> usize {
+        self + rhs
+    }
+}
+
+pub fn from_int(addr: usize) -> *const u8 {
+    addr as *const u8
+    //~^ ERROR casting an integer to a pointer cannot produce a valid capability
+}
+
+pub fn offset(p: *const u8) -> *const u8 {
+    (p as usize + 16) as *const u8
+    //~^ ERROR casting an integer to a pointer cannot produce a valid capability
+}
+
+pub fn null() -> *const u8 {
+    0 as *const u8
+}
+
+#[allow(cheri_int_to_ptr_cast)]
+pub fn allowed(addr: usize) -> *mut u8 {
+    addr as *mut u8
+    //[error]~^ ERROR casting an integer to a pointer cannot produce a valid capability
+}
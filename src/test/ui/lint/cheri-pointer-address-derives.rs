@@ -0,0 +1,54 @@
+// check-pass
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[rustc_diagnostic_item = "Hash"]
+pub trait Hash {}
+#[rustc_diagnostic_item = "PartialOrd"]
+pub trait PartialOrd {}
+#[rustc_diagnostic_item = "Ord"]
+pub trait Ord {}
+
+pub trait Serialize {}
+
+pub struct Entry {
+    key: u32,
+    node: *const u8,
+    //~^ WARNING `Entry` derives `Hash`, `PartialOrd` and `Ord` from the address of this pointer
+}
+
+#[automatically_derived]
+impl Hash for Entry {}
+#[automatically_derived]
+impl PartialOrd for Entry {}
+#[automatically_derived]
+impl Ord for Entry {}
+impl Serialize for Entry {}
+
+pub enum Handle {
+    Null,
+    Node(*mut u8),
+    //~^ WARNING `Handle` derives `Hash` from the address of this pointer
+}
+
+#[automatically_derived]
+impl Hash for Handle {}
+impl Serialize for Handle {}
+
+// Types that are not serialized can hash their pointers.
+pub struct InMemory(*const u8);
+
+#[automatically_derived]
+impl Hash for InMemory {}
+
+// Implementations written by hand are assumed to know what they use.
+pub struct Manual(*const u8);
+
+impl Hash for Manual {}
+impl Serialize for Manual {}
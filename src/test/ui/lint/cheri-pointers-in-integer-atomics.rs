@@ -0,0 +1,69 @@
+// check-pass
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+#![allow(cheri_int_to_ptr_cast, cheri_ptr_to_int_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[rustc_diagnostic_item = "atomic_mod"]
+pub mod atomic {
+    pub struct AtomicUsize(usize);
+    pub struct AtomicU32(u32);
+
+    impl AtomicUsize {
+        pub const fn new(v: usize) -> AtomicUsize {
+            AtomicUsize(v)
+        }
+        pub fn load(&self) -> usize {
+            loop {}
+        }
+        pub fn store(&self, _: usize) {}
+        pub fn compare_exchange(&self, _: usize, _: usize) -> usize {
+            loop {}
+        }
+    }
+
+    impl AtomicU32 {
+        pub fn store(&self, _: u32) {}
+    }
+}
+
+use atomic::{AtomicU32, AtomicUsize};
+
+pub static HEAD: AtomicUsize = AtomicUsize::new(0);
+
+pub fn push(node: *mut u8) {
+    HEAD.store(node as usize);
+    //~^ WARNING storing a pointer in an `AtomicUsize` drops its capability
+}
+
+pub fn pop() -> *mut u8 {
+    HEAD.load() as *mut u8
+    //~^ WARNING casting the value of an `AtomicUsize` to a pointer cannot produce a valid capability
+}
+
+pub fn replace(old: *mut u8, new: *mut u8) {
+    HEAD.compare_exchange(old as usize, new as usize);
+    //~^ WARNING storing a pointer in an `AtomicUsize` drops its capability
+    //~| WARNING storing a pointer in an `AtomicUsize` drops its capability
+}
+
+pub fn init(node: *mut u8) -> AtomicUsize {
+    AtomicUsize::new(node as usize)
+    //~^ WARNING storing a pointer in an `AtomicUsize` drops its capability
+}
+
+// Counters and atomics that are narrower than an address aren't flagged.
+pub fn not_pointers(counter: &AtomicUsize, flags: &AtomicU32, p: *const u8) -> usize {
+    counter.store(1);
+    flags.store(p as u32);
+    counter.load()
+}
+
+#[lang = "sync"]
+trait Sync {}
+impl Sync for AtomicUsize {}
@@ -0,0 +1,31 @@
+// check-pass
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items)]
+#![no_core]
+#![allow(cheri_int_to_ptr_cast)]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub fn addr(p: *const u64) -> usize {
+    p as usize
+    //~^ WARNING casting a pointer to an integer keeps only its address
+}
+
+pub fn narrow(p: *const u64) -> u32 {
+    p as u32
+    //~^ WARNING casting a pointer to an integer keeps only its address
+}
+
+pub fn round_trip(p: *const u8) -> *const u8 {
+    p as usize as *const u8
+    //~^ WARNING casting a pointer to an integer keeps only its address
+}
+
+pub fn through_local(p: *const u8) -> *const u8 {
+    let addr = p as usize;
+    //~^ WARNING casting a pointer to an integer keeps only its address
+    addr as *const u8
+}
@@ -0,0 +1,45 @@
+// Check that unions overlapping a capability with other data are flagged in `extern` signatures
+// on purecap targets.
+
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+#![deny(cheri_capability_unions)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for usize {}
+impl<T: ?Sized> Copy for *const T {}
+
+#[repr(C)]
+pub union PtrOrAddr {
+    ptr: *const u8,
+    addr: usize,
+}
+
+#[repr(C)]
+pub struct Wrapper {
+    tag: usize,
+    value: PtrOrAddr,
+}
+
+#[repr(C)]
+pub union Ptrs {
+    a: *const u8,
+    b: *const usize,
+}
+
+extern "C" {
+    pub fn take(x: PtrOrAddr); //~ ERROR `extern` block uses union `PtrOrAddr`
+    pub fn give() -> Wrapper; //~ ERROR `extern` block uses union `PtrOrAddr`
+    pub fn by_pointer(x: *const PtrOrAddr);
+    pub fn only_pointers(x: Ptrs);
+}
+
+pub extern "C" fn define(_: PtrOrAddr) {} //~ ERROR `extern` fn uses union `PtrOrAddr`
@@ -0,0 +1,27 @@
+// Check that `intptr_t` and `uintptr_t` declared as integers are flagged in `extern` signatures
+// on purecap targets, where C's `intptr_t` is a capability.
+
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+#![deny(cheri_ffi_capability_mismatch)]
+#![allow(non_camel_case_types)]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub type intptr_t = isize;
+pub type uintptr_t = usize;
+pub type size_t = usize;
+
+extern "C" {
+    pub fn stash(value: intptr_t); //~ ERROR `extern` block uses `intptr_t` as `isize`
+    pub fn fetch() -> *const uintptr_t; //~ ERROR `extern` block uses `uintptr_t` as `usize`
+    pub fn len(value: size_t) -> usize;
+    pub fn stash_pointer(value: *mut u8);
+}
+
+pub extern "C" fn define(_: uintptr_t) {} //~ ERROR `extern` fn uses `uintptr_t` as `usize`
@@ -0,0 +1,24 @@
+// revisions: lint error
+// compile-flags: --target riscv32imcxcheri-unknown-none-purecap --crate-type=rlib
+// [error]compile-flags: -Z strict-capability=error
+// needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[rustc_diagnostic_item = "ptr_from_exposed_addr"]
+fn from_exposed_addr(_addr: usize) {}
+
+pub fn f() {
+    from_exposed_addr(0x1000);
+    //~^ ERROR `from_exposed_addr` cannot recover a valid capability on a purecap target
+}
+
+#[allow(purecap_exposed_provenance)]
+pub fn g() {
+    from_exposed_addr(0x2000);
+    //[error]~^ ERROR `from_exposed_addr` cannot recover a valid capability on a purecap target
+}
@@ -0,0 +1,22 @@
+// check-pass
+// ignore-morello+c64
+
+use std::mem::size_of;
+
+const _: () = assert!(size_of::<usize>() == size_of::<*const u8>());
+//~^ WARNING this assumes that `usize` and `*const u8` have the same size
+//~| WARNING pointers are larger than `usize` on some targets
+
+const _: [(); 0] = [(); size_of::<&u32>() - size_of::<isize>()];
+//~^ WARNING this assumes that `isize` and `&u32` have the same size
+//~| WARNING pointers are larger than `usize` on some targets
+
+fn main() {
+    assert_eq!(size_of::<fn()>(), size_of::<usize>());
+    //~^ WARNING this assumes that `usize` and `fn()` have the same size
+    //~| WARNING pointers are larger than `usize` on some targets
+
+    // The size of a pointer to an unsized type, or of another integer, isn't an assumption.
+    assert!(size_of::<*const [u8]>() != size_of::<usize>());
+    assert!(size_of::<*const u8>() >= size_of::<u32>());
+}
@@ -0,0 +1,23 @@
+// build-pass
+// compile-flags: --crate-type=lib -Cdebuginfo=1 -Copt-level=2 -Cremark=cheri
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+//
+// Check that `-Cremark=cheri` only enables the remarks of the CHERI passes, so that the inliner
+// doesn't remark on `f` not being inlined into `g` here.
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[no_mangle]
+#[inline(never)]
+pub fn f() {
+}
+
+#[no_mangle]
+pub fn g() {
+    f();
+}
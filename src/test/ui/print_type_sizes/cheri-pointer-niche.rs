@@ -0,0 +1,28 @@
+// compile-flags: -Z print-type-sizes
+// build-pass
+// ignore-pass
+// ^-- needed because `--pass check` does not emit the output needed.
+//     FIXME: consider using an attribute instead of side-effects.
+// only-morello+c64
+
+// This file illustrates how niche-filling enums are handled on purecap targets, where the only
+// niche of a pointer is its null address.
+
+#![feature(start)]
+#![allow(dead_code)]
+
+pub enum MyOption<T> { None, Some(T) }
+
+pub struct Wrapper {
+    pre: u8,
+    r: &'static u32,
+}
+
+#[start]
+fn start(_: isize, _: *const *const u8) -> isize {
+    let _x: MyOption<&'static u32> = MyOption::None;
+    let _y: MyOption<Wrapper> = MyOption::None;
+    // The null address is taken, so the outer enum needs a tag.
+    let _z: MyOption<MyOption<&'static u32>> = MyOption::None;
+    0
+}
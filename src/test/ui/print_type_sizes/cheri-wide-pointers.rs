@@ -0,0 +1,28 @@
+// compile-flags: -Z print-type-sizes
+// build-pass
+// ignore-pass
+// ^-- needed because `--pass check` does not emit the output needed.
+//     FIXME: consider using an attribute instead of side-effects.
+// only-morello+c64
+
+// This file illustrates how wide pointers are broken down on purecap targets, where their
+// metadata is padded out to the alignment of the data capability.
+
+#![feature(start)]
+#![allow(dead_code)]
+
+pub trait Trait {}
+
+impl Trait for u8 {}
+
+pub struct Views {
+    bytes: &'static [u8],
+    text: &'static str,
+    object: &'static dyn Trait,
+}
+
+#[start]
+fn start(_: isize, _: *const *const u8) -> isize {
+    let _v = Views { bytes: &[], text: "", object: &0u8 };
+    0
+}
@@ -0,0 +1,59 @@
+// Check that packing a type below the alignment of a capability it holds is an error on purecap
+// targets, since the capability could then no longer be loaded or stored with its tag.
+
+// build-fail
+// compile-flags: --target morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+impl Copy for u64 {}
+impl<T: ?Sized> Copy for &T {}
+
+#[repr(packed)]
+pub struct Packed { //~ ERROR `Packed` cannot be packed because it holds a capability
+    a: u8,
+    p: *const u8,
+}
+
+pub struct Wrapper {
+    r: &'static u32,
+}
+
+#[repr(packed(2))]
+pub struct Nested { //~ ERROR `Nested` cannot be packed because it holds a capability
+    a: u8,
+    inner: Wrapper,
+}
+
+#[repr(packed)]
+pub union Union { //~ ERROR `Union` cannot be packed because it holds a capability
+    x: u64,
+    r: &'static u8,
+}
+
+// Packing to the alignment of a capability, or packing types without capabilities, is fine.
+#[repr(packed(16))]
+pub struct CapabilityAligned {
+    a: u8,
+    p: *const u8,
+}
+
+#[repr(packed)]
+pub struct Integers {
+    a: u8,
+    b: u64,
+}
+
+pub fn packed(_: Packed) {}
+pub fn nested(_: Nested) {}
+pub fn union(_: Union) {}
+pub fn capability_aligned(_: CapabilityAligned) {}
+pub fn integers(_: Integers) {}
@@ -0,0 +1,14 @@
+// compile-flags: --crate-type=rlib --target=morello-unknown-none-purecap
+// needs-llvm-components: aarch64
+// check-pass
+#![feature(no_core, lang_items, cheri_target_feature)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[target_feature(enable = "morello")]
+pub unsafe fn uses_capability_instructions() {}
+
+#[target_feature(enable = "c64")]
+pub unsafe fn uses_capability_mode() {}
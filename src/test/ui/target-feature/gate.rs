@@ -27,6 +27,7 @@
 // gate-test-riscv_target_feature
 // gate-test-ermsb_target_feature
 // gate-test-bpf_target_feature
+// gate-test-cheri_target_feature
 // gate-test-aarch64_ver_target_feature
 
 #[target_feature(enable = "avx512bw")]
@@ -0,0 +1,40 @@
+// Check that transmutes between pointers and integers are diagnosed on purecap targets, where
+// pointers are capabilities that integers can't hold.
+
+// compile-flags: --target morello-unknown-none-purecap --crate-type=rlib
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items, intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+extern "rust-intrinsic" {
+    fn transmute<T, U>(x: T) -> U;
+}
+
+pub unsafe fn to_usize(p: *const u8) -> usize {
+    transmute(p)
+    //~^ ERROR cannot transmute between pointers and integers of different sizes on CHERI
+}
+
+pub unsafe fn from_u64(x: u64) -> *mut u8 {
+    transmute(x)
+    //~^ ERROR cannot transmute between pointers and integers of different sizes on CHERI
+}
+
+pub unsafe fn to_u128(p: *const u8) -> u128 {
+    transmute(p)
+    //~^ WARNING transmuting a capability to `u128` loses its tag
+}
+
+pub unsafe fn from_u128(x: u128) -> &'static u8 {
+    transmute(x)
+    //~^ WARNING transmuting `u128` to a pointer cannot produce a valid capability
+}
+
+#[allow(cheri_capability_transmutes)]
+pub unsafe fn allowed(p: fn()) -> u128 {
+    transmute(p)
+}
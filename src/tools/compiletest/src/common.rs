@@ -423,6 +423,18 @@ pub fn get_pointer_width(&self) -> u32 {
         *&self.target_cfg().pointer_width
     }
 
+    /// Whether pointers on the target are CHERI capabilities, i.e. wider than
+    /// the addresses they carry.
+    pub fn is_cheri_purecap(&self) -> bool {
+        self.target_cfg().pointer_type_width != self.target_cfg().pointer_width
+    }
+
+    /// Whether compiled tests can actually be executed on the target, either
+    /// natively or through `remote-test-client`.
+    pub fn can_run_on_target(&self) -> bool {
+        self.run_enabled() && (self.target == self.host || self.remote_test_client.is_some())
+    }
+
     pub fn can_unwind(&self) -> bool {
         self.target_cfg().panic == PanicStrategy::Unwind
     }
@@ -446,6 +458,7 @@ pub struct TargetCfg {
     abi: String,
     families: Vec<String>,
     pointer_width: u32,
+    pointer_type_width: u32,
     endian: Endian,
     panic: PanicStrategy,
 }
@@ -485,6 +498,7 @@ fn new(config: &Config) -> TargetCfg {
         let mut abi = None;
         let mut families = Vec::new();
         let mut pointer_width = None;
+        let mut pointer_type_width = None;
         let mut endian = None;
         let mut panic = None;
         for line in print_cfg.lines() {
@@ -497,6 +511,9 @@ fn new(config: &Config) -> TargetCfg {
                     "target_abi" => abi = Some(value),
                     "target_family" => families.push(value.to_string()),
                     "target_pointer_width" => pointer_width = Some(value.parse().unwrap()),
+                    "target_pointer_type_width" => {
+                        pointer_type_width = Some(value.parse().unwrap())
+                    }
                     "target_endian" => {
                         endian = Some(match value {
                             "little" => Endian::Little,
@@ -522,6 +539,7 @@ fn new(config: &Config) -> TargetCfg {
             abi: abi.unwrap().to_string(),
             families,
             pointer_width: pointer_width.unwrap(),
+            pointer_type_width: pointer_type_width.or(pointer_width).unwrap(),
             endian: endian.unwrap(),
             panic: panic.unwrap(),
         }
@@ -692,6 +692,7 @@ fn parse_cfg_name_directive(&self, line: &str, prefix: &str) -> ParsedNameDirect
             name == self.channel ||                             // channel
             (self.target != self.host && name == "cross-compile") ||
             (name == "endian-big" && self.is_big_endian()) ||
+            (name == "cheri" && self.is_cheri_purecap()) ||
             (self.remote_test_client.is_some() && name == "remote") ||
             match self.compare_mode {
                 Some(CompareMode::Polonius) => name == "compare-mode-polonius",
@@ -965,6 +966,8 @@ pub fn make_test_description<R: Read>(
         ignore |= !has_shadow_call_stack
             && config.parse_name_directive(ln, "needs-sanitizer-shadow-call-stack");
         ignore |= !config.can_unwind() && config.parse_name_directive(ln, "needs-unwind");
+        ignore |= !(config.is_cheri_purecap() && config.can_run_on_target())
+            && config.parse_name_directive(ln, "needs-run-on-purecap");
         ignore |= config.target == "wasm32-unknown-unknown"
             && config.parse_name_directive(ln, directives::CHECK_RUN_RESULTS);
         ignore |= config.debugger == Some(Debugger::Cdb) && ignore_cdb(config, ln);
@@ -456,3 +456,13 @@ fn families() {
         assert!(!check_ignore(&config, &format!("// ignore-{other}")));
     }
 }
+
+#[test]
+fn cheri() {
+    let mut config = config();
+    config.target = "x86_64-unknown-linux-gnu".to_string();
+    assert!(!config.is_cheri_purecap());
+    assert!(check_ignore(&config, "// only-cheri"));
+    assert!(!check_ignore(&config, "// ignore-cheri"));
+    assert!(check_ignore(&config, "// needs-run-on-purecap"));
+}
@@ -421,6 +421,16 @@ fn main() {
             eprintln!("WARNING: `-Zmiri-tag-raw-pointers` has no effect; it is enabled by default");
         } else if arg == "-Zmiri-strict-provenance" {
             miri_config.provenance_mode = ProvenanceMode::Strict;
+        } else if arg == "-Zmiri-cheri" {
+            miri_config.cheri = true;
+        } else if arg == "-Zmiri-cheri-bounds-rounding" {
+            miri_config.cheri = true;
+            miri_config.cheri_bounds_rounding = true;
+        } else if arg == "-Zmiri-cheri-revocation" {
+            miri_config.cheri = true;
+            miri_config.cheri_revocation = true;
+        } else if arg == "-Zmiri-strict-capability" {
+            miri_config.strict_capability = true;
         } else if arg == "-Zmiri-permissive-provenance" {
             miri_config.provenance_mode = ProvenanceMode::Permissive;
         } else if arg == "-Zmiri-mute-stdout-stderr" {
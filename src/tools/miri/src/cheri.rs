@@ -0,0 +1,258 @@
+//! Sealed and revoked capabilities for `-Zmiri-cheri`.
+//!
+//! Sealing a pointer gives it a fresh tag, which is recorded here together with the tag the
+//! pointer had before. Memory accesses through a pointer with a sealed tag are a capability
+//! fault, and unsealing it gives it back its old tag, so that Stacked Borrows sees the pointer
+//! it was sealed from again. The sealed tag itself is never pushed onto a borrow stack, so
+//! references can't be made from a sealed pointer either; that is a capability fault too,
+//! rather than a Stacked Borrows violation.
+//!
+//! With `-Zmiri-cheri-revocation`, heap allocations are recorded as revoked when they are freed,
+//! like the revocation sweeps of CheriBSD clear the tag of every capability to freed memory
+//! before it is reused. Miri never reuses addresses, so accesses through such a capability
+//! already fail; they are reported as capability faults, and the tag of the capability reads
+//! as cleared.
+
+use std::cell::RefCell;
+
+use log::trace;
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+
+use crate::*;
+
+/// The object type that `llvm.cheri.cap.type.get` returns for a sealed entry capability. This
+/// is the "RB" sentry type of Morello.
+const SENTRY_OTYPE: u64 = 1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Seal {
+    /// Sealed with an object type by `llvm.cheri.cap.seal`.
+    ObjectType(u64),
+    /// A sealed entry capability, made by `llvm.cheri.cap.seal.entry`.
+    Entry,
+}
+
+impl Seal {
+    pub fn otype(self) -> u64 {
+        match self {
+            Seal::ObjectType(otype) => otype,
+            Seal::Entry => SENTRY_OTYPE,
+        }
+    }
+}
+
+pub type GlobalState = RefCell<GlobalStateInner>;
+
+#[derive(Clone, Debug)]
+pub struct GlobalStateInner {
+    /// The seal of each sealed tag, and the tag of the pointer it was sealed from.
+    sealed: FxHashMap<SbTag, (Seal, SbTag)>,
+    /// The next tag to give a sealed pointer when Stacked Borrows is disabled, and every other
+    /// pointer has the same tag.
+    next_tag: u64,
+    /// The live heap allocations, whose capabilities are revoked when they are freed.
+    revocable: FxHashSet<AllocId>,
+    /// The heap allocations that were freed, and whose capabilities are revoked.
+    revoked: FxHashSet<AllocId>,
+}
+
+impl VisitTags for GlobalStateInner {
+    fn visit_tags(&self, visit: &mut dyn FnMut(SbTag)) {
+        // The pointers that were sealed can still be used again once they are unsealed.
+        for &(_seal, tag) in self.sealed.values() {
+            visit(tag);
+        }
+    }
+}
+
+impl GlobalStateInner {
+    pub fn new() -> Self {
+        // Without Stacked Borrows every pointer has tag 1.
+        GlobalStateInner {
+            sealed: FxHashMap::default(),
+            next_tag: 2,
+            revocable: FxHashSet::default(),
+            revoked: FxHashSet::default(),
+        }
+    }
+
+    /// Records a new allocation, whose capabilities are revoked when it is freed if it is on
+    /// the heap.
+    pub fn new_allocation(&mut self, alloc_id: AllocId, kind: MemoryKind<MiriMemoryKind>) {
+        if let MemoryKind::Machine(
+            MiriMemoryKind::Rust | MiriMemoryKind::C | MiriMemoryKind::WinHeap,
+        ) = kind
+        {
+            self.revocable.insert(alloc_id);
+        }
+    }
+
+    /// Revokes the capabilities to `alloc_id`, which is freed, if it is on the heap.
+    pub fn deallocate(&mut self, alloc_id: AllocId) {
+        if self.revocable.remove(&alloc_id) {
+            self.revoked.insert(alloc_id);
+        }
+    }
+
+    /// Returns whether the capabilities to `alloc_id` are revoked.
+    pub fn is_revoked(&self, alloc_id: AllocId) -> bool {
+        self.revoked.contains(&alloc_id)
+    }
+
+    /// Returns the seal of the pointer with the given provenance, if it is sealed.
+    pub fn seal(&self, prov: ProvenanceExtra) -> Option<Seal> {
+        match prov {
+            ProvenanceExtra::Concrete(tag) => self.sealed.get(&tag).map(|&(seal, _)| seal),
+            ProvenanceExtra::Wildcard => None,
+        }
+    }
+
+    /// Forgets the seals of the tags that are not in `live_tags`, since the pointers that had
+    /// them are gone.
+    pub fn remove_unreachable_tags(&mut self, live_tags: &FxHashSet<SbTag>) {
+        self.sealed.retain(|tag, _| live_tags.contains(tag));
+    }
+
+    /// Checks that a memory access through a pointer with the given provenance is not made
+    /// through a sealed capability.
+    pub fn before_memory_access<'tcx>(&self, prov: ProvenanceExtra) -> InterpResult<'tcx> {
+        match self.seal(prov) {
+            None => Ok(()),
+            Some(seal) => throw_machine_stop!(TerminationInfo::CheriFault {
+                msg: "memory access through a sealed capability".to_string(),
+                fault: sealed_fault(seal),
+            }),
+        }
+    }
+}
+
+/// Describes why a capability with the given seal can't be used.
+fn sealed_fault(seal: Seal) -> String {
+    match seal {
+        Seal::ObjectType(otype) => format!("the capability is sealed with object type {otype:#x}"),
+        Seal::Entry =>
+            "the capability is a sealed entry capability, which can only be branched to"
+                .to_string(),
+    }
+}
+
+impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+pub trait EvalContextExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    /// Returns the seal of `ptr`, if it is sealed.
+    fn cheri_seal_of(&self, ptr: Pointer<Option<Provenance>>) -> Option<Seal> {
+        let this = self.eval_context_ref();
+        match ptr.provenance {
+            Some(Provenance::Concrete { sb, .. }) =>
+                this.machine.cheri_state.borrow().seal(ProvenanceExtra::Concrete(sb)),
+            Some(Provenance::Wildcard) | None => None,
+        }
+    }
+
+    /// Seals `ptr` with the object type given by the address of `auth`. `ptr` must be tagged and
+    /// unsealed, and so must `auth`; otherwise the result is untagged. Permissions are not
+    /// modelled, so any such `auth` is an authority to seal with its address.
+    fn cheri_seal(
+        &mut self,
+        ptr: Pointer<Option<Provenance>>,
+        auth: Pointer<Option<Provenance>>,
+    ) -> Pointer<Option<Provenance>> {
+        let this = self.eval_context_mut();
+        match authority(this, auth) {
+            Some(otype) => seal_with(this, ptr, Seal::ObjectType(otype)),
+            None => Pointer::new(None, ptr.into_parts().1),
+        }
+    }
+
+    /// Turns `ptr` into a sealed entry capability. `ptr` must be tagged and unsealed; otherwise
+    /// the result is untagged.
+    fn cheri_seal_entry(
+        &mut self,
+        ptr: Pointer<Option<Provenance>>,
+    ) -> Pointer<Option<Provenance>> {
+        let this = self.eval_context_mut();
+        seal_with(this, ptr, Seal::Entry)
+    }
+
+    /// Unseals `ptr`, which must be sealed with the object type given by the address of `auth`,
+    /// a tagged and unsealed capability; otherwise the result is untagged.
+    fn cheri_unseal(
+        &mut self,
+        ptr: Pointer<Option<Provenance>>,
+        auth: Pointer<Option<Provenance>>,
+    ) -> Pointer<Option<Provenance>> {
+        let this = self.eval_context_mut();
+        let otype = authority(this, auth);
+        let (prov, addr) = ptr.into_parts();
+        let Some(Provenance::Concrete { alloc_id, sb }) = prov else {
+            return Pointer::new(None, addr);
+        };
+        match this.machine.cheri_state.get_mut().sealed.get(&sb) {
+            Some(&(Seal::ObjectType(sealed_with), tag)) if Some(sealed_with) == otype => {
+                trace!("cheri_unseal: unsealing {sb:?} as {tag:?}");
+                Pointer::new(Some(Provenance::Concrete { alloc_id, sb: tag }), addr)
+            }
+            _ => Pointer::new(None, addr),
+        }
+    }
+
+    /// Checks that `ptr`, which a reference is made from, is not sealed. Setting the bounds of
+    /// the new reference needs an unsealed capability.
+    fn cheri_check_reborrow(&self, ptr: Pointer<Option<Provenance>>) -> InterpResult<'tcx> {
+        let this = self.eval_context_ref();
+        match this.cheri_seal_of(ptr) {
+            Some(seal) => throw_machine_stop!(TerminationInfo::CheriFault {
+                msg: "reference made from a sealed capability".to_string(),
+                fault: sealed_fault(seal),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that the function pointer `fn_ptr` that is called is not sealed with an object
+    /// type. Unsealed code capabilities and sealed entry capabilities can be branched to.
+    fn cheri_check_indirect_call(&self, fn_ptr: Pointer<Option<Provenance>>) -> InterpResult<'tcx> {
+        let this = self.eval_context_ref();
+        match this.cheri_seal_of(fn_ptr) {
+            Some(seal @ Seal::ObjectType(_)) => throw_machine_stop!(TerminationInfo::CheriFault {
+                msg: "call through a capability sealed with an object type".to_string(),
+                fault: sealed_fault(seal),
+            }),
+            Some(Seal::Entry) | None => Ok(()),
+        }
+    }
+}
+
+/// Returns the object type that `auth` is the authority for, i.e. its address, if it is tagged
+/// and unsealed.
+fn authority(ecx: &MiriInterpCx<'_, '_>, auth: Pointer<Option<Provenance>>) -> Option<u64> {
+    let (prov, addr) = auth.into_parts();
+    (prov.is_some() && ecx.cheri_seal_of(auth).is_none()).then(|| addr.bytes())
+}
+
+/// Gives `ptr` a fresh tag that is sealed with `seal`. `ptr` must be tagged and unsealed;
+/// otherwise the result is untagged.
+fn seal_with(
+    ecx: &mut MiriInterpCx<'_, '_>,
+    ptr: Pointer<Option<Provenance>>,
+    seal: Seal,
+) -> Pointer<Option<Provenance>> {
+    let (prov, addr) = ptr.into_parts();
+    let Some(Provenance::Concrete { alloc_id, sb }) = prov else {
+        return Pointer::new(None, addr);
+    };
+    if ecx.cheri_seal_of(ptr).is_some() {
+        return Pointer::new(None, addr);
+    }
+    let tag = if let Some(stacked_borrows) = &ecx.machine.stacked_borrows {
+        stacked_borrows.borrow_mut().new_ptr()
+    } else {
+        let seals = ecx.machine.cheri_state.get_mut();
+        let tag = SbTag::new(seals.next_tag).unwrap();
+        seals.next_tag += 1;
+        tag
+    };
+    trace!("cheri_seal: sealing {sb:?} as {tag:?} with {seal:?}");
+    ecx.machine.cheri_state.get_mut().sealed.insert(tag, (seal, sb));
+    Pointer::new(Some(Provenance::Concrete { alloc_id, sb: tag }), addr)
+}
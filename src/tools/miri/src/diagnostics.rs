@@ -20,6 +20,16 @@ pub enum TerminationInfo {
         history: Option<TagHistory>,
     },
     Int2PtrWithStrictProvenance,
+    /// An int2ptr cast that recovers the provenance of a pointer that was exposed at `exposed`,
+    /// with `-Zmiri-strict-capability`.
+    Int2PtrWithStrictCapability {
+        exposed: SpanData,
+    },
+    /// A use of a capability that the hardware refuses, with `-Zmiri-cheri`.
+    CheriFault {
+        msg: String,
+        fault: String,
+    },
     Deadlock,
     MultipleSymbolDefinitions {
         link_name: Symbol,
@@ -46,7 +56,13 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                     f,
                     "integer-to-pointer casts and `ptr::from_exposed_addr` are not supported with `-Zmiri-strict-provenance`"
                 ),
+            Int2PtrWithStrictCapability { .. } =>
+                write!(
+                    f,
+                    "integer-to-pointer cast recovers the provenance of an exposed pointer, which is not supported with `-Zmiri-strict-capability`"
+                ),
             StackedBorrowsUb { msg, .. } => write!(f, "{msg}"),
+            CheriFault { msg, .. } => write!(f, "{msg}"),
             Deadlock => write!(f, "the evaluated program deadlocked"),
             MultipleSymbolDefinitions { link_name, .. } =>
                 write!(f, "multiple definitions of symbol `{link_name}`"),
@@ -162,9 +178,11 @@ pub fn report_error<'tcx, 'mir>(
             let title = match info {
                 Exit(code) => return Some(*code),
                 Abort(_) => Some("abnormal termination"),
-                UnsupportedInIsolation(_) | Int2PtrWithStrictProvenance =>
-                    Some("unsupported operation"),
+                UnsupportedInIsolation(_)
+                | Int2PtrWithStrictProvenance
+                | Int2PtrWithStrictCapability { .. } => Some("unsupported operation"),
                 StackedBorrowsUb { .. } => Some("Undefined Behavior"),
+                CheriFault { .. } => Some("CHERI capability fault"),
                 Deadlock => Some("deadlock"),
                 MultipleSymbolDefinitions { .. } | SymbolShimClashing { .. } => None,
             };
@@ -200,12 +218,24 @@ pub fn report_error<'tcx, 'mir>(
                     ],
                 SymbolShimClashing { link_name, span } =>
                     vec![(Some(*span), format!("the `{link_name}` symbol is defined here"))],
+                CheriFault { fault, .. } =>
+                    cheri_fault_helps(fault),
+                Int2PtrWithStrictCapability { exposed } =>
+                    vec![
+                        (None, format!("on a purecap target the result is untagged, since a capability can't be rebuilt from its address")),
+                        (None, format!("use Strict Provenance APIs (https://doc.rust-lang.org/nightly/std/ptr/index.html#strict-provenance, https://crates.io/crates/sptr) instead")),
+                        (Some(*exposed), format!("the address was exposed here")),
+                    ],
                 Int2PtrWithStrictProvenance =>
                     vec![(None, format!("use Strict Provenance APIs (https://doc.rust-lang.org/nightly/std/ptr/index.html#strict-provenance, https://crates.io/crates/sptr) instead"))],
                 _ => vec![],
             };
             (title, helps)
         }
+        UndefinedBehavior(ub) if ecx.machine.cheri && cheri_fault(&ecx.machine, ub).is_some() => {
+            let fault = cheri_fault(&ecx.machine, ub).unwrap();
+            (Some("CHERI capability fault"), cheri_fault_helps(fault))
+        }
         _ => {
             #[rustfmt::skip]
             let title = match e.kind() {
@@ -245,6 +275,17 @@ pub fn report_error<'tcx, 'mir>(
                         (None, format!("this usually indicates that your program performed an invalid operation and caused Undefined Behavior")),
                         (None, format!("but due to `-Zmiri-symbolic-alignment-check`, alignment errors can also be false positives")),
                     ],
+                // Accesses out of the bounds of their capability were reported as a fault above,
+                // so this one is within its rounded bounds.
+                UndefinedBehavior(UndefinedBehaviorInfo::PointerOutOfBounds {
+                    alloc_size,
+                    msg: CheckInAllocMsg::DerefTest | CheckInAllocMsg::MemoryAccessTest,
+                    ..
+                }) if ecx.machine.cheri_bounds_rounding =>
+                    vec![
+                        (None, format!("this indicates a bug in the program: it performed an invalid operation, and caused Undefined Behavior")),
+                        (None, format!("the bounds of the capability used for this access are rounded up to {} bytes, so it doesn't trap on CHERI hardware", ecx.machine.cheri_bounds(*alloc_size).0)),
+                    ],
                 UndefinedBehavior(_) =>
                     vec![
                         (None, format!("this indicates a bug in the program: it performed an invalid operation, and caused Undefined Behavior")),
@@ -303,6 +344,43 @@ pub fn report_error<'tcx, 'mir>(
     None
 }
 
+/// Returns why the access that failed with `ub` faults on CHERI hardware, or `None` if it doesn't
+/// (or isn't an access at all).
+fn cheri_fault(machine: &MiriMachine<'_, '_>, ub: &UndefinedBehaviorInfo) -> Option<&'static str> {
+    use CheckInAllocMsg::*;
+    use UndefinedBehaviorInfo::*;
+    match ub {
+        DanglingIntPointer(_, DerefTest | MemoryAccessTest) =>
+            Some("the capability used for this access is untagged"),
+        PointerOutOfBounds {
+            alloc_size, ptr_offset, ptr_size, msg: DerefTest | MemoryAccessTest, ..
+        } => {
+            // With `-Zmiri-cheri-bounds-rounding` the bounds may extend past the allocation.
+            let (length, _) = machine.cheri_bounds(*alloc_size);
+            let within_bounds = u64::try_from(*ptr_offset)
+                .map_or(false, |offset| offset + ptr_size.bytes() <= length);
+            (!within_bounds)
+                .then_some("this access is outside of the bounds of the capability used for it")
+        }
+        WriteToReadOnly(_) =>
+            Some("the capability used for this access doesn't have the store permission"),
+        PointerUseAfterFree(alloc_id) if machine.cheri_state.borrow().is_revoked(*alloc_id) =>
+            Some("the capability used for this access was revoked when its allocation was freed"),
+        DerefFunctionPointer(_) =>
+            Some("the capability used for this access is a sealed entry capability of a function"),
+        _ => None,
+    }
+}
+
+/// The help messages of a CHERI capability fault, which traps because of `fault`.
+#[rustfmt::skip]
+fn cheri_fault_helps(fault: &str) -> Vec<(Option<SpanData>, String)> {
+    vec![
+        (None, format!("{fault}, so it traps on CHERI hardware")),
+        (None, format!("on a purecap target the program is killed with `SIGPROT` here; pointers behave like capabilities because of `-Zmiri-cheri`")),
+    ]
+}
+
 /// Report an error or note (depending on the `error` argument) with the given stacktrace.
 /// Also emits a full stacktrace of the interpreter stack.
 /// We want to present a multi-line span message for some errors. Diagnostics do not support this
@@ -118,6 +118,14 @@ pub struct MiriConfig {
     pub backtrace_style: BacktraceStyle,
     /// Which provenance to use for int2ptr casts
     pub provenance_mode: ProvenanceMode,
+    /// Whether pointers behave like CHERI capabilities.
+    pub cheri: bool,
+    /// Whether capability bounds are rounded to be representable, like on CHERI hardware.
+    pub cheri_bounds_rounding: bool,
+    /// Whether capabilities to heap allocations are revoked when they are freed.
+    pub cheri_revocation: bool,
+    /// Whether int2ptr casts that recover the provenance of an exposed pointer are an error.
+    pub strict_capability: bool,
     /// Whether to ignore any output by the program. This is helpful when debugging miri
     /// as its messages don't get intermingled with the program messages.
     pub mute_stdout_stderr: bool,
@@ -160,6 +168,10 @@ fn default() -> MiriConfig {
             panic_on_unsupported: false,
             backtrace_style: BacktraceStyle::Short,
             provenance_mode: ProvenanceMode::Default,
+            cheri: false,
+            cheri_bounds_rounding: false,
+            cheri_revocation: false,
+            strict_capability: false,
             mute_stdout_stderr: false,
             preemption_rate: 0.01, // 1%
             report_progress: None,
@@ -37,6 +37,8 @@ pub struct GlobalStateInner {
     /// Whether an allocation has been exposed or not. This cannot be put
     /// into `AllocExtra` for the same reason as `base_addr`.
     exposed: FxHashSet<AllocId>,
+    /// Where each exposed allocation was first exposed, for `-Zmiri-strict-capability`.
+    exposed_at: FxHashMap<AllocId, Span>,
     /// This is used as a memory address when a new pointer is casted to an integer. It
     /// is always larger than any address that was previously made part of a block.
     next_base_addr: u64,
@@ -56,6 +58,7 @@ pub fn new(config: &MiriConfig) -> Self {
             int_to_ptr_map: Vec::default(),
             base_addr: FxHashMap::default(),
             exposed: FxHashSet::default(),
+            exposed_at: FxHashMap::default(),
             next_base_addr: STACK_ADDR,
             provenance_mode: config.provenance_mode,
         }
@@ -107,11 +110,18 @@ pub fn expose_ptr(
         alloc_id: AllocId,
         sb: SbTag,
     ) -> InterpResult<'tcx> {
+        let span = ecx.machine.strict_capability.then(|| ecx.machine.current_span().get());
         let global_state = ecx.machine.intptrcast.get_mut();
-        // In strict mode, we don't need this, so we can save some cycles by not tracking it.
-        if global_state.provenance_mode != ProvenanceMode::Strict {
+        // In strict and CHERI mode, we don't need this, so we can save some cycles by not tracking
+        // it. `-Zmiri-strict-capability` still needs it to report casts of exposed addresses.
+        if global_state.provenance_mode != ProvenanceMode::Strict
+            && (!ecx.machine.cheri || ecx.machine.strict_capability)
+        {
             trace!("Exposing allocation id {alloc_id:?}");
             global_state.exposed.insert(alloc_id);
+            if let Some(span) = span {
+                global_state.exposed_at.entry(alloc_id).or_insert(span);
+            }
             if ecx.machine.stacked_borrows.is_some() {
                 ecx.expose_tag(alloc_id, sb)?;
             }
@@ -135,6 +145,23 @@ pub fn ptr_from_addr_cast(
     ) -> InterpResult<'tcx, Pointer<Option<Provenance>>> {
         trace!("Casting {:#x} to a pointer", addr);
 
+        if ecx.machine.strict_capability
+            && ecx.machine.intptrcast.borrow().provenance_mode != ProvenanceMode::Strict
+        {
+            if let Some(alloc_id) = Self::alloc_id_from_addr(ecx, addr) {
+                let exposed = ecx.machine.intptrcast.borrow().exposed_at[&alloc_id].data();
+                throw_machine_stop!(TerminationInfo::Int2PtrWithStrictCapability { exposed });
+            }
+            // There is no provenance to recover, so like on CHERI hardware the result is
+            // untagged.
+            return Ok(Pointer::new(None, Size::from_bytes(addr)));
+        }
+
+        if ecx.machine.cheri {
+            // A capability can't be rebuilt from its address, the result is untagged.
+            return Ok(Pointer::new(None, Size::from_bytes(addr)));
+        }
+
         let global_state = ecx.machine.intptrcast.borrow();
 
         match global_state.provenance_mode {
@@ -172,6 +199,10 @@ fn alloc_base_addr(ecx: &MiriInterpCx<'mir, 'tcx>, alloc_id: AllocId) -> u64 {
                 // There is nothing wrong with a raw pointer being cast to an integer only after
                 // it became dangling.  Hence we allow dead allocations.
                 let (size, align, _kind) = ecx.get_alloc_info(alloc_id);
+                // With `-Zmiri-cheri-bounds-rounding` the allocation is aligned and padded so that
+                // the rounded bounds of its capability cover nothing else, as CHERI allocators do.
+                let (length, mask) = ecx.machine.cheri_bounds(size);
+                let align = max(align.bytes(), (!mask & ecx.machine_usize_max()) + 1);
 
                 // This allocation does not have a base address yet, pick one.
                 // Leave some space to the previous allocation, to give it some chance to be less aligned.
@@ -182,14 +213,14 @@ fn alloc_base_addr(ecx: &MiriInterpCx<'mir, 'tcx>, alloc_id: AllocId) -> u64 {
                 };
                 // From next_base_addr + slack, round up to adjust for alignment.
                 let base_addr = global_state.next_base_addr.checked_add(slack).unwrap();
-                let base_addr = Self::align_addr(base_addr, align.bytes());
+                let base_addr = Self::align_addr(base_addr, align);
                 entry.insert(base_addr);
                 trace!(
                     "Assigning base address {:#x} to allocation {:?} (size: {}, align: {}, slack: {})",
                     base_addr,
                     alloc_id,
                     size.bytes(),
-                    align.bytes(),
+                    align,
                     slack,
                 );
 
@@ -197,7 +228,7 @@ fn alloc_base_addr(ecx: &MiriInterpCx<'mir, 'tcx>, alloc_id: AllocId) -> u64 {
                 // of at least 1 to avoid two allocations having the same base address.
                 // (The logic in `alloc_id_from_addr` assumes unique addresses, and different
                 // function/vtable pointers need to be distinguishable!)
-                global_state.next_base_addr = base_addr.checked_add(max(size.bytes(), 1)).unwrap();
+                global_state.next_base_addr = base_addr.checked_add(max(length, 1)).unwrap();
                 // Given that `next_base_addr` increases in each allocation, pushing the
                 // corresponding tuple keeps `int_to_ptr_map` sorted
                 global_state.int_to_ptr_map.push((base_addr, alloc_id));
@@ -53,6 +53,7 @@
 extern crate rustc_span;
 extern crate rustc_target;
 
+mod cheri;
 mod clock;
 mod concurrency;
 mod diagnostics;
@@ -84,6 +85,7 @@
 pub use crate::shims::tls::{EvalContextExt as _, TlsData};
 pub use crate::shims::EvalContextExt as _;
 
+pub use crate::cheri::{EvalContextExt as _, Seal};
 pub use crate::clock::{Clock, Instant};
 pub use crate::concurrency::{
     data_race::{AtomicFenceOrd, AtomicReadOrd, AtomicRwOrd, AtomicWriteOrd, EvalContextExt as _},
@@ -36,6 +36,11 @@
 pub const STACK_ADDR: u64 = 32 * PAGE_SIZE; // not really about the "stack", but where we start assigning integer addresses to allocations
 pub const STACK_SIZE: u64 = 16 * PAGE_SIZE; // whatever
 
+/// The width of the bottom bound mantissa that `-Zmiri-cheri-bounds-rounding` rounds capability
+/// bounds for. This is the one of the 128-bit capabilities of CHERI-RISC-V; Morello's is wider, so
+/// its bounds are rounded less.
+pub const CHERI_MANTISSA_WIDTH: u32 = 14;
+
 /// Extra data stored with each stack frame
 pub struct FrameData<'tcx> {
     /// Extra data for Stacked Borrows.
@@ -355,6 +360,9 @@ pub struct MiriMachine<'mir, 'tcx> {
     /// Ptr-int-cast module global data.
     pub intptrcast: intptrcast::GlobalState,
 
+    /// Sealed and revoked capabilities, see `-Zmiri-cheri`.
+    pub cheri_state: cheri::GlobalState,
+
     /// Environment variables set by `setenv`.
     /// Miri does not expose env vars from the host to the emulated program.
     pub(crate) env_vars: EnvVars<'tcx>,
@@ -433,6 +441,21 @@ pub struct MiriMachine<'mir, 'tcx> {
     /// Controls whether alignment of memory accesses is being checked.
     pub(crate) check_alignment: AlignmentCheck,
 
+    /// Whether pointers behave like CHERI capabilities, see `-Zmiri-cheri`.
+    pub(crate) cheri: bool,
+
+    /// Whether capability bounds are rounded to be representable, see
+    /// `-Zmiri-cheri-bounds-rounding`.
+    pub(crate) cheri_bounds_rounding: bool,
+
+    /// Whether capabilities to heap allocations are revoked when they are freed, see
+    /// `-Zmiri-cheri-revocation`.
+    pub(crate) cheri_revocation: bool,
+
+    /// Whether int2ptr casts that recover the provenance of an exposed pointer are an error, see
+    /// `-Zmiri-strict-capability`.
+    pub(crate) strict_capability: bool,
+
     /// Failure rate of compare_exchange_weak, between 0.0 and 1.0
     pub(crate) cmpxchg_weak_failure_rate: f64,
 
@@ -486,6 +509,7 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             stacked_borrows,
             data_race,
             intptrcast: RefCell::new(intptrcast::GlobalStateInner::new(config)),
+            cheri_state: RefCell::new(cheri::GlobalStateInner::new()),
             // `env_vars` depends on a full interpreter so we cannot properly initialize it yet.
             env_vars: EnvVars::default(),
             argc: None,
@@ -510,6 +534,10 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             rng: RefCell::new(rng),
             tracked_alloc_ids: config.tracked_alloc_ids.clone(),
             check_alignment: config.check_alignment,
+            cheri: config.cheri,
+            cheri_bounds_rounding: config.cheri_bounds_rounding,
+            cheri_revocation: config.cheri_revocation,
+            strict_capability: config.strict_capability,
             cmpxchg_weak_failure_rate: config.cmpxchg_weak_failure_rate,
             mute_stdout_stderr: config.mute_stdout_stderr,
             weak_memory: config.weak_memory_emulation,
@@ -644,6 +672,18 @@ pub(crate) fn is_local(&self, frame: &FrameInfo<'_>) -> bool {
         let def_id = frame.instance.def_id();
         def_id.is_local() || self.local_crates.contains(&def_id.krate)
     }
+
+    /// Returns the length of the bounds of a capability for an allocation of `size`, and the mask
+    /// that the base of the allocation must be aligned to for them. Unless
+    /// `-Zmiri-cheri-bounds-rounding` is set, the bounds are exact.
+    pub(crate) fn cheri_bounds(&self, size: Size) -> (u64, u64) {
+        let max = self.tcx.machine_usize_max();
+        if self.cheri_bounds_rounding {
+            cheri_concentrate_bounds(size.bytes(), CHERI_MANTISSA_WIDTH, max)
+        } else {
+            (size.bytes(), max)
+        }
+    }
 }
 
 impl VisitTags for MiriMachine<'_, '_> {
@@ -661,6 +701,7 @@ fn visit_tags(&self, visit: &mut dyn FnMut(SbTag)) {
             stacked_borrows,
             data_race,
             intptrcast,
+            cheri_state,
             file_handler,
             tcx: _,
             isolated_op: _,
@@ -678,6 +719,10 @@ fn visit_tags(&self, visit: &mut dyn FnMut(SbTag)) {
             rng: _,
             tracked_alloc_ids: _,
             check_alignment: _,
+            cheri: _,
+            cheri_bounds_rounding: _,
+            cheri_revocation: _,
+            strict_capability: _,
             cmpxchg_weak_failure_rate: _,
             mute_stdout_stderr: _,
             weak_memory: _,
@@ -698,6 +743,7 @@ fn visit_tags(&self, visit: &mut dyn FnMut(SbTag)) {
         data_race.visit_tags(visit);
         stacked_borrows.visit_tags(visit);
         intptrcast.visit_tags(visit);
+        cheri_state.visit_tags(visit);
         argc.visit_tags(visit);
         argv.visit_tags(visit);
         cmd_line.visit_tags(visit);
@@ -894,6 +940,9 @@ fn adjust_allocation<'b>(
                 kind,
             ));
         }
+        if ecx.machine.cheri_revocation {
+            ecx.machine.cheri_state.borrow_mut().new_allocation(id, kind);
+        }
 
         let alloc = alloc.into_owned();
         let stacks = ecx.machine.stacked_borrows.as_ref().map(|stacked_borrows| {
@@ -1004,6 +1053,9 @@ fn before_memory_read(
         (alloc_id, prov_extra): (AllocId, Self::ProvenanceExtra),
         range: AllocRange,
     ) -> InterpResult<'tcx> {
+        if machine.cheri {
+            machine.cheri_state.borrow().before_memory_access(prov_extra)?;
+        }
         if let Some(data_race) = &alloc_extra.data_race {
             data_race.read(
                 alloc_id,
@@ -1036,6 +1088,9 @@ fn before_memory_write(
         (alloc_id, prov_extra): (AllocId, Self::ProvenanceExtra),
         range: AllocRange,
     ) -> InterpResult<'tcx> {
+        if machine.cheri {
+            machine.cheri_state.borrow().before_memory_access(prov_extra)?;
+        }
         if let Some(data_race) = &mut alloc_extra.data_race {
             data_race.write(
                 alloc_id,
@@ -1071,6 +1126,12 @@ fn before_memory_deallocation(
         if machine.tracked_alloc_ids.contains(&alloc_id) {
             machine.emit_diagnostic(NonHaltingDiagnostic::FreedAlloc(alloc_id));
         }
+        if machine.cheri {
+            machine.cheri_state.borrow().before_memory_access(prove_extra)?;
+        }
+        if machine.cheri_revocation {
+            machine.cheri_state.get_mut().deallocate(alloc_id);
+        }
         if let Some(data_race) = &mut alloc_extra.data_race {
             data_race.deallocate(
                 alloc_id,
@@ -1174,6 +1235,13 @@ fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx>
         Ok(())
     }
 
+    fn before_indirect_call(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        fn_ptr: Pointer<Option<Self::Provenance>>,
+    ) -> InterpResult<'tcx> {
+        if ecx.machine.cheri { ecx.cheri_check_indirect_call(fn_ptr) } else { Ok(()) }
+    }
+
     #[inline(always)]
     fn after_stack_push(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
         if ecx.machine.stacked_borrows.is_some() { ecx.retag_return_place() } else { Ok(()) }
@@ -818,6 +818,49 @@ fn emulate_foreign_item_by_name(
                 }
             }
 
+            // CHERI capability shims, only with `-Zmiri-cheri`
+            "llvm.cheri.cap.tag.get" if this.machine.cheri => {
+                let [cap] = this.check_shim(abi, Abi::Unadjusted, link_name, args)?;
+                let cap = this.read_pointer(cap)?;
+                // The revocation sweep clears the tag of capabilities to freed memory.
+                let revoked = this.ptr_try_get_alloc_id(cap).map_or(false, |(alloc_id, _, _)| {
+                    this.machine.cheri_state.borrow().is_revoked(alloc_id)
+                });
+                this.write_scalar(Scalar::from_bool(cap.provenance.is_some() && !revoked), dest)?;
+            }
+            "llvm.cheri.cap.sealed.get" if this.machine.cheri => {
+                let [cap] = this.check_shim(abi, Abi::Unadjusted, link_name, args)?;
+                let cap = this.read_pointer(cap)?;
+                this.write_scalar(Scalar::from_bool(this.cheri_seal_of(cap).is_some()), dest)?;
+            }
+            "llvm.cheri.cap.type.get.i64" if this.machine.cheri => {
+                let [cap] = this.check_shim(abi, Abi::Unadjusted, link_name, args)?;
+                let cap = this.read_pointer(cap)?;
+                let otype =
+                    this.cheri_seal_of(cap).map_or(-1, |seal| i64::try_from(seal.otype()).unwrap());
+                this.write_scalar(Scalar::from_machine_isize(otype, this), dest)?;
+            }
+            "llvm.cheri.cap.seal" if this.machine.cheri => {
+                let [cap, auth] = this.check_shim(abi, Abi::Unadjusted, link_name, args)?;
+                let cap = this.read_pointer(cap)?;
+                let auth = this.read_pointer(auth)?;
+                let sealed = this.cheri_seal(cap, auth);
+                this.write_pointer(sealed, dest)?;
+            }
+            "llvm.cheri.cap.unseal" if this.machine.cheri => {
+                let [cap, auth] = this.check_shim(abi, Abi::Unadjusted, link_name, args)?;
+                let cap = this.read_pointer(cap)?;
+                let auth = this.read_pointer(auth)?;
+                let unsealed = this.cheri_unseal(cap, auth);
+                this.write_pointer(unsealed, dest)?;
+            }
+            "llvm.cheri.cap.seal.entry" if this.machine.cheri => {
+                let [cap] = this.check_shim(abi, Abi::Unadjusted, link_name, args)?;
+                let cap = this.read_pointer(cap)?;
+                let sentry = this.cheri_seal_entry(cap);
+                this.write_pointer(sentry, dest)?;
+            }
+
             // Platform-specific shims
             _ => match this.tcx.sess.target.os.as_ref() {
                 target if target_os_is_unix(target) => return shims::unix::foreign_items::EvalContextExt::emulate_foreign_item_by_name(this, link_name, abi, args, dest),
@@ -11,6 +11,7 @@
     mir,
     ty::{self, FloatTy, Ty},
 };
+use rustc_span::sym;
 use rustc_target::abi::{Integer, Size};
 
 use crate::*;
@@ -30,6 +31,26 @@ fn call_intrinsic(
     ) -> InterpResult<'tcx> {
         let this = self.eval_context_mut();
 
+        // With `-Zmiri-cheri` pointers have bounds even on targets without capabilities, which the
+        // core engine doesn't know about.
+        if this.machine.cheri && this.tcx.item_name(instance.def_id()) == sym::cheri_cap_length_get
+        {
+            let [ptr] = check_arg_count(args)?;
+            let ptr = this.read_pointer(ptr)?;
+            let len = match this.ptr_try_get_alloc_id(ptr) {
+                Ok((alloc_id, _, _)) => {
+                    // The bounds of a capability cover the allocation it was derived from.
+                    let (size, _align, _kind) = this.get_alloc_info(alloc_id);
+                    this.machine.cheri_bounds(size).0
+                }
+                // An untagged capability isn't narrowed.
+                Err(_) => this.machine_usize_max(),
+            };
+            this.write_scalar(Scalar::from_machine_usize(len, this), dest)?;
+            this.go_to_block(ret.unwrap());
+            return Ok(());
+        }
+
         // See if the core engine can handle this intrinsic.
         if this.emulate_intrinsic(instance, args, dest, ret)? {
             return Ok(());
@@ -198,7 +198,7 @@ pub fn new(
     }
 
     /// Generates a new pointer tag. Remember to also check track_pointer_tags and log its creation!
-    fn new_ptr(&mut self) -> SbTag {
+    pub fn new_ptr(&mut self) -> SbTag {
         let id = self.next_ptr_tag;
         self.next_ptr_tag = SbTag(NonZeroU64::new(id.0.get() + 1).unwrap());
         id
@@ -941,6 +941,12 @@ fn retag_reference(
             None => return Ok(val.clone()),
         };
 
+        // A sealed pointer's tag is not in any borrow stack, and reborrowing it is a capability
+        // fault anyway.
+        if this.machine.cheri {
+            this.cheri_check_reborrow(place.ptr)?;
+        }
+
         // Compute new borrow.
         let new_tag = this.machine.stacked_borrows.as_mut().unwrap().get_mut().new_ptr();
 
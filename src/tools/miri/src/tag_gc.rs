@@ -180,5 +180,8 @@ fn remove_unreachable_tags(&mut self, tags: FxHashSet<SbTag>) {
                     .remove_unreachable_tags(&tags);
             }
         });
+        if this.machine.cheri {
+            this.machine.cheri_state.get_mut().remove_unreachable_tags(&tags);
+        }
     }
 }
@@ -0,0 +1,7 @@
+//@compile-flags: -Zmiri-cheri
+
+fn main() {
+    let v: Vec<u8> = vec![1, 2];
+    let x = unsafe { *v.as_ptr().wrapping_add(2) }; //~ ERROR: CHERI capability fault
+    panic!("this should never print: {}", x);
+}
@@ -0,0 +1,11 @@
+//@compile-flags: -Zmiri-cheri-revocation
+
+fn main() {
+    let p = {
+        let b = Box::new(42);
+        &*b as *const i32
+    };
+    // Freeing the box revoked the capability.
+    let x = unsafe { *p }; //~ ERROR: CHERI capability fault
+    panic!("this should never print: {}", x);
+}
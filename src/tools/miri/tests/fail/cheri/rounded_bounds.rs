@@ -0,0 +1,8 @@
+//@compile-flags: -Zmiri-cheri-bounds-rounding
+
+fn main() {
+    let v: Vec<u8> = vec![0; 10001];
+    // The bounds are rounded up to 10016 bytes, so this doesn't trap on hardware.
+    let x = unsafe { *v.as_ptr().wrapping_add(10001) }; //~ ERROR: out-of-bounds
+    panic!("this should never print: {}", x);
+}
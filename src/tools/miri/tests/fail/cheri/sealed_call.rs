@@ -0,0 +1,17 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(abi_unadjusted, link_llvm_intrinsics)]
+
+extern "unadjusted" {
+    #[link_name = "llvm.cheri.cap.seal"]
+    fn cheri_seal(cap: *const u8, auth: *const u8) -> *const u8;
+}
+
+fn f() {}
+
+fn main() {
+    let key = [0u8; 4];
+    let sealed = unsafe { cheri_seal(f as *const u8, key.as_ptr()) };
+    let sealed: fn() = unsafe { std::mem::transmute(sealed) };
+    // Only sealed entry capabilities can be called without unsealing them first.
+    sealed(); //~ ERROR: CHERI capability fault
+}
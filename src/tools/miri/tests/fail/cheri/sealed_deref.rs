@@ -0,0 +1,15 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(abi_unadjusted, link_llvm_intrinsics)]
+
+extern "unadjusted" {
+    #[link_name = "llvm.cheri.cap.seal"]
+    fn cheri_seal(cap: *const u8, auth: *const u8) -> *const u8;
+}
+
+fn main() {
+    let x: u8 = 3;
+    let key = [0u8; 4];
+    let sealed = unsafe { cheri_seal(&x, key.as_ptr()) };
+    // A sealed capability has to be unsealed before it can be dereferenced.
+    let _val = unsafe { *sealed }; //~ ERROR: CHERI capability fault
+}
@@ -0,0 +1,10 @@
+//@compile-flags: -Zmiri-strict-capability
+#![feature(strict_provenance)]
+
+fn main() {
+    let x: i32 = 3;
+    let addr = (&x as *const i32).expose_addr();
+    // Casting an address that was never exposed is fine, there is no provenance to recover.
+    let _ptr = std::ptr::from_exposed_addr::<i32>(8);
+    let _ptr = std::ptr::from_exposed_addr::<i32>(addr); //~ ERROR: recovers the provenance of an exposed pointer
+}
@@ -0,0 +1,9 @@
+//@compile-flags: -Zmiri-cheri -Zmiri-strict-capability
+#![feature(strict_provenance)]
+
+fn main() {
+    let x: i32 = 3;
+    let addr = (&x as *const i32).expose_addr();
+    // `-Zmiri-cheri` alone would make this an untagged pointer.
+    let _ptr = std::ptr::from_exposed_addr::<i32>(addr); //~ ERROR: recovers the provenance of an exposed pointer
+}
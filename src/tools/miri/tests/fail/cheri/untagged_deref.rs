@@ -0,0 +1,10 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(strict_provenance)]
+
+fn main() {
+    let x: i32 = 3;
+    let addr = (&x as *const i32).expose_addr();
+    // A capability can't be rebuilt from its address.
+    let ptr = std::ptr::from_exposed_addr::<i32>(addr);
+    assert_eq!(unsafe { *ptr }, 3); //~ ERROR: CHERI capability fault
+}
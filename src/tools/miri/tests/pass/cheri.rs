@@ -0,0 +1,25 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(core_intrinsics, strict_provenance)]
+
+use std::intrinsics::cheri_cap_length_get;
+
+/// Pointers derived with the strict provenance APIs keep their capability.
+fn with_addr_roundtrip() {
+    let x = [1u8, 2, 3, 4];
+    let ptr = x.as_ptr();
+    let addr = ptr.addr() + 2;
+    assert_eq!(unsafe { *ptr.with_addr(addr) }, 3);
+}
+
+/// The bounds cover the allocation the pointer was derived from.
+fn bounds() {
+    let v = vec![0u64; 5];
+    assert_eq!(cheri_cap_length_get(v.as_ptr()), 40);
+    assert_eq!(cheri_cap_length_get(v.as_ptr().wrapping_add(3)), 40);
+    assert_eq!(cheri_cap_length_get(std::ptr::invalid::<u8>(8)), usize::MAX);
+}
+
+fn main() {
+    with_addr_roundtrip();
+    bounds();
+}
@@ -0,0 +1,15 @@
+//@compile-flags: -Zmiri-cheri-bounds-rounding
+#![feature(core_intrinsics, strict_provenance)]
+
+use std::intrinsics::cheri_cap_length_get;
+
+fn main() {
+    // Small allocations have exact bounds.
+    let small = vec![0u8; 100];
+    assert_eq!(cheri_cap_length_get(small.as_ptr()), 100);
+
+    // Larger ones are rounded up, and their base is aligned for that.
+    let large = vec![0u8; 10001];
+    assert_eq!(cheri_cap_length_get(large.as_ptr()), 10016);
+    assert_eq!(large.as_ptr().addr() % 16, 0);
+}
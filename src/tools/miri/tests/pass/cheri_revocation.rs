@@ -0,0 +1,29 @@
+//@compile-flags: -Zmiri-cheri-revocation
+#![feature(abi_unadjusted, link_llvm_intrinsics)]
+
+extern "unadjusted" {
+    #[link_name = "llvm.cheri.cap.tag.get"]
+    fn cheri_tag_get(cap: *const u8) -> bool;
+}
+
+/// Freeing a heap allocation clears the tag of the capabilities to it.
+fn heap() {
+    let p = Box::into_raw(Box::new(42u8));
+    assert!(unsafe { cheri_tag_get(p) });
+    drop(unsafe { Box::from_raw(p) });
+    assert!(!unsafe { cheri_tag_get(p) });
+}
+
+/// Stack allocations are not revoked.
+fn stack() {
+    let p = {
+        let x = 42u8;
+        &x as *const u8
+    };
+    assert!(unsafe { cheri_tag_get(p) });
+}
+
+fn main() {
+    heap();
+    stack();
+}
@@ -0,0 +1,64 @@
+//@compile-flags: -Zmiri-cheri
+#![feature(abi_unadjusted, link_llvm_intrinsics)]
+
+extern "unadjusted" {
+    #[link_name = "llvm.cheri.cap.tag.get"]
+    fn cheri_tag_get(cap: *const u8) -> bool;
+    #[link_name = "llvm.cheri.cap.sealed.get"]
+    fn cheri_sealed_get(cap: *const u8) -> bool;
+    #[link_name = "llvm.cheri.cap.type.get.i64"]
+    fn cheri_type_get(cap: *const u8) -> isize;
+    #[link_name = "llvm.cheri.cap.seal"]
+    fn cheri_seal(cap: *const u8, auth: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.unseal"]
+    fn cheri_unseal(cap: *const u8, auth: *const u8) -> *const u8;
+    #[link_name = "llvm.cheri.cap.seal.entry"]
+    fn cheri_seal_entry(cap: *const u8) -> *const u8;
+}
+
+/// A sealed pointer can be used again after unsealing it with the same object type.
+fn seal_unseal() {
+    let x: u8 = 3;
+    let key = [0u8; 4];
+    unsafe {
+        let sealed = cheri_seal(&x, key.as_ptr());
+        assert!(cheri_tag_get(sealed));
+        assert!(cheri_sealed_get(sealed));
+        assert_eq!(cheri_type_get(sealed), key.as_ptr() as isize);
+        assert_eq!(cheri_type_get(&x), -1);
+
+        let unsealed = cheri_unseal(sealed, key.as_ptr());
+        assert!(!cheri_sealed_get(unsealed));
+        assert_eq!(*unsealed, 3);
+    }
+}
+
+/// Unsealing with another object type, or sealing twice, gives an untagged capability.
+fn wrong_otype() {
+    let x: u8 = 3;
+    let key = [0u8; 4];
+    unsafe {
+        let sealed = cheri_seal(&x, key.as_ptr());
+        assert!(!cheri_tag_get(cheri_unseal(sealed, key.as_ptr().add(1))));
+        assert!(!cheri_tag_get(cheri_seal(sealed, key.as_ptr())));
+    }
+}
+
+/// Sealed entry capabilities can be called.
+fn sentry() {
+    fn f() -> i32 {
+        42
+    }
+    unsafe {
+        let sentry = cheri_seal_entry(f as *const u8);
+        assert!(cheri_sealed_get(sentry));
+        let sentry: fn() -> i32 = std::mem::transmute(sentry);
+        assert_eq!(sentry(), 42);
+    }
+}
+
+fn main() {
+    seal_unseal();
+    wrong_otype();
+    sentry();
+}
@@ -17,6 +17,22 @@
 
 const REMOTE_ADDR_ENV: &str = "TEST_DEVICE_ADDR";
 const DEFAULT_ADDR: &str = "127.0.0.1:12345";
+const TIMEOUT_ENV: &str = "TEST_DEVICE_TIMEOUT";
+const FIRMWARE_ENV: &str = "TEST_DEVICE_FIRMWARE";
+
+/// CheriBSD on CHERI-RISC-V, built from a target specification file of this name.
+const CHERI_RISCV_TARGET: &str = "riscv64-unknown-freebsd-purecap";
+
+/// Signal delivered by CheriBSD when a process takes a capability fault, e.g.
+/// an out-of-bounds access or a dereference of an untagged capability.
+const SIGPROT: i32 = 34;
+
+/// Exit code reported when a test does not finish within `TEST_DEVICE_TIMEOUT`.
+const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Exit code reported when a test is killed by `SIGPROT`, as a shell would report it, so that
+/// capability faults can be told apart from other crashes, which exit with 3.
+const CAPABILITY_FAULT_EXIT_CODE: i32 = 128 + SIGPROT;
 
 macro_rules! t {
     ($e:expr) => {
@@ -113,6 +129,9 @@ fn prepare_rootfs(target: &str, rootfs: &Path, server: &Path, rootfs_img: &Path)
             prepare_rootfs_cpio(rootfs, rootfs_img)
         }
         "riscv64gc-unknown-linux-gnu" => prepare_rootfs_ext4(rootfs, rootfs_img),
+        "morello-unknown-freebsd-purecap" | CHERI_RISCV_TARGET => {
+            prepare_rootfs_ffs(target, rootfs, rootfs_img)
+        }
         _ => panic!("{} is not supported", target),
     }
 }
@@ -164,6 +183,44 @@ fn prepare_rootfs_ext4(rootfs: &Path, rootfs_img: &Path) {
     assert!(t!(mkfs_child.wait()).success());
 }
 
+fn prepare_rootfs_ffs(target: &str, rootfs: &Path, rootfs_img: &Path) {
+    // CheriBSD runs `/etc/rc.local` once the network is up; start the server from there, after
+    // whatever the rootfs already does in it.
+    let rc_local = rootfs.join("etc/rc.local");
+    let mut script = fs::read_to_string(&rc_local).unwrap_or_default();
+    if !script.lines().any(|line| line == "/testd &") {
+        if !script.is_empty() && !script.ends_with('\n') {
+            script.push('\n');
+        }
+        script.push_str("/testd &\n");
+        t!(fs::write(&rc_local, script));
+    }
+
+    // QEMU loads the CHERI-RISC-V kernel itself, so the filesystem is the whole disk there.
+    // Morello boots from EDK2, which needs an EFI system partition next to it.
+    let rootfs_ufs = if target == CHERI_RISCV_TARGET {
+        rootfs_img.to_owned()
+    } else {
+        rootfs_img.with_extension("ufs")
+    };
+    let mut makefs = Command::new("makefs");
+    makefs.arg("-t").arg("ffs").arg("-B").arg("le").arg("-s").arg("4g");
+    makefs.arg(&rootfs_ufs).arg(rootfs);
+    let mut makefs_child = t!(makefs.spawn());
+    assert!(t!(makefs_child.wait()).success());
+    if target == CHERI_RISCV_TARGET {
+        return;
+    }
+
+    let mut mkimg = Command::new("mkimg");
+    mkimg.arg("-s").arg("gpt");
+    mkimg.arg("-p").arg(format!("efi:={}", rootfs.join("boot/boot1.efifat").display()));
+    mkimg.arg("-p").arg(format!("freebsd-ufs:={}", rootfs_ufs.display()));
+    mkimg.arg("-o").arg(rootfs_img);
+    let mut mkimg_child = t!(mkimg.spawn());
+    assert!(t!(mkimg_child.wait()).success());
+}
+
 fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path) {
     let rootfs_img = &tmpdir.join("rootfs.img");
     prepare_rootfs(target, rootfs, server, rootfs_img);
@@ -235,10 +292,58 @@ fn start_qemu_emulator(target: &str, rootfs: &Path, server: &Path, tmpdir: &Path
                 .arg(&format!("file={},format=raw,id=hd0", &rootfs_img.to_string_lossy()));
             t!(cmd.spawn());
         }
+        "morello-unknown-freebsd-purecap" => {
+            let mut cmd = Command::new("qemu-system-morello");
+            cmd.arg("-nographic")
+                .arg("-machine")
+                .arg("virt,gic-version=3")
+                .arg("-cpu")
+                .arg("morello")
+                .arg("-m")
+                .arg("2048")
+                .arg("-bios")
+                .arg(firmware("/tmp/edk2-aarch64-code.fd"))
+                .arg("-netdev")
+                .arg("user,id=net0,hostfwd=tcp::12345-:12345")
+                .arg("-device")
+                .arg("virtio-net-pci,netdev=net0")
+                .arg("-device")
+                .arg("virtio-blk-pci,drive=hd0")
+                .arg("-drive")
+                .arg(&format!("if=none,file={},format=raw,id=hd0", &rootfs_img.to_string_lossy()));
+            t!(cmd.spawn());
+        }
+        CHERI_RISCV_TARGET => {
+            let mut cmd = Command::new("qemu-system-riscv64cheri");
+            cmd.arg("-nographic")
+                .arg("-machine")
+                .arg("virt")
+                .arg("-m")
+                .arg("2048")
+                .arg("-bios")
+                .arg(firmware("/tmp/fw_jump.elf"))
+                .arg("-kernel")
+                .arg(rootfs.join("boot/kernel/kernel"))
+                .arg("-netdev")
+                .arg("user,id=net0,hostfwd=tcp::12345-:12345")
+                .arg("-device")
+                .arg("virtio-net-device,netdev=net0")
+                .arg("-device")
+                .arg("virtio-blk-device,drive=hd0")
+                .arg("-drive")
+                .arg(&format!("if=none,file={},format=raw,id=hd0", &rootfs_img.to_string_lossy()));
+            t!(cmd.spawn());
+        }
         _ => panic!("cannot start emulator for: {}", target),
     }
 }
 
+/// Returns the firmware to boot the emulator with: the one named by `TEST_DEVICE_FIRMWARE`, or
+/// `default` if it is not set.
+fn firmware(default: &str) -> PathBuf {
+    env::var_os(FIRMWARE_ENV).map_or_else(|| PathBuf::from(default), PathBuf::from)
+}
+
 fn push(path: &Path) {
     let device_address = env::var(REMOTE_ADDR_ENV).unwrap_or(DEFAULT_ADDR.to_string());
     let client = t!(TcpStream::connect(device_address));
@@ -304,10 +409,14 @@ fn run(support_lib_count: usize, exe: String, all_args: Vec<String>) {
     let mut stderr_done = false;
     let mut stdout_done = false;
     let mut client = t!(client.into_inner());
+    if let Ok(secs) = env::var(TIMEOUT_ENV) {
+        let secs = secs.parse().expect("TEST_DEVICE_TIMEOUT must be a number of seconds");
+        t!(client.set_read_timeout(Some(Duration::from_secs(secs))));
+    }
     let mut stdout = io::stdout();
     let mut stderr = io::stderr();
     while !stdout_done || !stderr_done {
-        t!(client.read_exact(&mut header));
+        read_or_time_out(&mut client, &mut header, &exe);
         let amt = ((header[1] as u64) << 24)
             | ((header[2] as u64) << 16)
             | ((header[3] as u64) << 8)
@@ -331,19 +440,33 @@ fn run(support_lib_count: usize, exe: String, all_args: Vec<String>) {
 
     // Finally, read out the exit status
     let mut status = [0; 5];
-    t!(client.read_exact(&mut status));
+    read_or_time_out(&mut client, &mut status, &exe);
     let code = ((status[1] as i32) << 24)
         | ((status[2] as i32) << 16)
         | ((status[3] as i32) << 8)
         | ((status[4] as i32) << 0);
     if status[0] == 0 {
         std::process::exit(code);
+    } else if code == SIGPROT {
+        println!("died due to a capability fault (signal {})", code);
+        std::process::exit(CAPABILITY_FAULT_EXIT_CODE);
     } else {
         println!("died due to signal {}", code);
         std::process::exit(3);
     }
 }
 
+fn read_or_time_out(client: &mut TcpStream, buf: &mut [u8], exe: &str) {
+    match client.read_exact(buf) {
+        Ok(()) => {}
+        Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => {
+            println!("timed out waiting for {:?}", exe);
+            std::process::exit(TIMEOUT_EXIT_CODE);
+        }
+        Err(e) => panic!("reading from the remote device failed with {}", e),
+    }
+}
+
 fn send(path: &Path, dst: &mut dyn Write) {
     t!(dst.write_all(path.file_name().unwrap().to_str().unwrap().as_bytes()));
     t!(dst.write_all(&[0]));
@@ -371,6 +494,14 @@ fn help() {
 the <server>. Otherwise qemu emulates the target using a rootfs image created in
 <tmpdir> and generated from <rootfs> plus the <server> executable.
 If {1} is set in the environment, this step is skipped.
+For morello-unknown-freebsd-purecap, <rootfs> is a CheriBSD rootfs which is
+packed into a GPT disk image with `makefs` and `mkimg` and booted in
+qemu-system-morello with the EDK2 firmware.
+For {6}, <rootfs> is a CheriBSD rootfs which is packed into
+a disk image with `makefs` and whose kernel is booted in qemu-system-riscv64cheri
+with the OpenSBI firmware.
+The firmware is /tmp/edk2-aarch64-code.fd or /tmp/fw_jump.elf respectively,
+unless {5} is set to another one.
 
 Pushing a path to a running emulator:
 
@@ -383,10 +514,18 @@ fn help() {
 First the target emulator/adb session is connected to as for pushing files. Next
 the <file> and any specified support libs are pushed to the target. Finally, the
 <file> is executed in the emulator, preserving the current environment.
-That command's status code is returned.
+That command's status code is returned, or 3 if it is killed by a signal. A
+command killed by a capability fault (SIGPROT) exits with {7} instead. If {3}
+is set, a command that produces no output for that many seconds is abandoned
+with exit code {4}.
 ",
         env::args().next().unwrap(),
         REMOTE_ADDR_ENV,
-        DEFAULT_ADDR
+        DEFAULT_ADDR,
+        TIMEOUT_ENV,
+        TIMEOUT_EXIT_CODE,
+        FIRMWARE_ENV,
+        CHERI_RISCV_TARGET,
+        CAPABILITY_FAULT_EXIT_CODE
     );
 }